@@ -7,9 +7,11 @@
 pub mod bmc; // requires file in this directory with the name 'bmc.rs'
 pub mod formula_logic;
 pub mod proof;
+pub mod witness;
 
 // ************************************************************************************************
 // re-exports of structs in these modules to simplify paths for other imports
 // ************************************************************************************************
 
 pub use bmc::BMC;
+pub use witness::AigerWitness;
@@ -6,6 +6,7 @@
 
 pub mod ic3_stateful_solver;
 pub mod ic3_stateless_solver;
+pub mod multi_property;
 pub mod pdr;
 pub mod proof_result;
 
@@ -15,6 +16,7 @@ pub mod proof_result;
 
 pub use ic3_stateful_solver::IC3Stateful;
 pub use ic3_stateless_solver::IC3Stateless;
+pub use multi_property::MultiPropertyProver;
 pub use pdr::PDR;
 pub use proof_result::ProofResult;
 
@@ -0,0 +1,143 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use super::{FiniteStateTransitionSystemProver, ProofResult};
+use crate::{
+    formulas::CNF,
+    models::{AndInverterGraph, FiniteStateTransitionSystem},
+};
+use std::{collections::HashMap, marker::PhantomData};
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that proves every property of an AndInverterGraph separately, using a prover of
+/// type `T` for each FiniteStateTransitionSystem it creates.
+///
+/// Work is reused across properties in three ways: properties with the same literal are
+/// proved once, constant properties are decided without a prover, and properties are first
+/// proved together, where a proof for a group is a proof for each of its properties, and
+/// only a group that has a counter example is split in two and proved again.
+pub struct MultiPropertyProver<T: FiniteStateTransitionSystemProver> {
+    verbose: bool,
+    prover: PhantomData<T>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl<T: FiniteStateTransitionSystemProver> MultiPropertyProver<T> {
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    pub fn new(verbose: bool) -> Self {
+        Self {
+            verbose,
+            prover: PhantomData,
+        }
+    }
+
+    /// Function that proves or refutes each property of an AndInverterGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the MultiPropertyProver desired.
+    /// * `aig` - the AndInverterGraph desired.
+    /// * `assume_output_is_bad` - whether the outputs are properties as well.
+    ///
+    /// # Returns
+    ///
+    /// A map from the index of every property, which is its position in
+    /// `FiniteStateTransitionSystem::get_property_literals_of_aig`, to its ProofResult.
+    /// The invariant of a proof can be shared by a group of properties that were proved
+    /// together, and constant properties that are never violated have an empty invariant.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::algorithms::proof::{IC3Stateful, MultiPropertyProver, ProofResult};
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use rust_formal_verification::solvers::sat::stateful::CaDiCalSolver;
+    /// // a latch that stays 0, a bad state literal for it and one for the input.
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 1 1 0 0 2\n2\n4 4\n4\n2\n");
+    /// let results = MultiPropertyProver::<IC3Stateful<CaDiCalSolver>>::new(false).prove(&aig, false);
+    /// assert!(matches!(results[&0], ProofResult::Proof { .. }));
+    /// assert!(matches!(results[&1], ProofResult::CTX { .. }));
+    /// ```
+    pub fn prove(
+        &self,
+        aig: &AndInverterGraph,
+        assume_output_is_bad: bool,
+    ) -> HashMap<usize, ProofResult> {
+        let property_literals =
+            FiniteStateTransitionSystem::get_property_literals_of_aig(aig, assume_output_is_bad);
+        let mut results = HashMap::new();
+
+        // properties with the same literal share one result, so only the first is proved.
+        let mut first_property_of_literal: HashMap<usize, usize> = HashMap::new();
+        let mut properties_to_prove = Vec::new();
+        for (index, literal) in property_literals.iter().enumerate() {
+            match literal {
+                0 => {
+                    results.insert(
+                        index,
+                        ProofResult::Proof {
+                            invariant: CNF::new(),
+                        },
+                    );
+                }
+                1 => {
+                    results.insert(index, ProofResult::CTX { depth: 0 });
+                }
+                _ => {
+                    let first = first_property_of_literal.entry(literal.to_owned());
+                    if *first.or_insert(index) == index {
+                        properties_to_prove.push(index);
+                    }
+                }
+            }
+        }
+
+        let mut groups_to_prove = vec![properties_to_prove];
+        while let Some(group) = groups_to_prove.pop() {
+            if group.is_empty() {
+                continue;
+            }
+            if self.verbose {
+                println!("Multi property prover - proving properties {group:?}");
+            }
+            let fin_state = FiniteStateTransitionSystem::from_aig_for_properties(
+                aig,
+                assume_output_is_bad,
+                &group,
+            );
+            let result = T::new(&fin_state).prove();
+            match result {
+                ProofResult::Proof { .. } => {
+                    for index in group {
+                        results.insert(index, result.to_owned());
+                    }
+                }
+                ProofResult::CTX { .. } if group.len() == 1 => {
+                    results.insert(group[0], result);
+                }
+                ProofResult::CTX { .. } => {
+                    let (first_half, second_half) = group.split_at(group.len() / 2);
+                    groups_to_prove.push(second_half.to_vec());
+                    groups_to_prove.push(first_half.to_vec());
+                }
+            }
+        }
+
+        for (index, literal) in property_literals.iter().enumerate() {
+            if let Some(first) = first_property_of_literal.get(literal) {
+                let result = results[first].to_owned();
+                results.insert(index, result);
+            }
+        }
+        results
+    }
+}
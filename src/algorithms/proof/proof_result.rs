@@ -8,7 +8,7 @@ use crate::formulas::{literal::VariableType, CNF};
 // struct
 // ************************************************************************************************
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum ProofResult {
     Proof { invariant: CNF },
     CTX { depth: VariableType },
@@ -0,0 +1,549 @@
+//! witnesses (counter examples) in the format used by AIGER and the hardware model checking
+//! competition, see http://fmv.jku.at/aiger/
+
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::{
+    algorithms::bmc::BMCResult,
+    formulas::literal::VariableType,
+    models::{
+        and_inverter_graph::{AigParseError, AigSimulationError, AigTraceMap},
+        btor2::Btor2Word,
+        AndInverterGraph, Btor2Model, FiniteStateTransitionSystem,
+    },
+    solvers::sat::Assignment,
+};
+use std::{collections::HashMap, fmt, fs};
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that describes a witness, which is a trace of an AndInverterGraph that reaches a
+/// state in which some bad state literals are true.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AigerWitness {
+    // the positions of the bad state literals that the witness violates.
+    property_indices: Vec<usize>,
+    // None is an unknown (x) value of an uninitialized latch.
+    initial_latches: Vec<Option<bool>>,
+    // the values of the inputs in every cycle.
+    inputs: Vec<Vec<bool>>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AigerWitness {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn error(line: usize, reason: String) -> AigParseError {
+        AigParseError::InvalidLine { line, reason }
+    }
+
+    fn parse_bits(
+        line: &str,
+        line_number_from_1: usize,
+    ) -> Result<Vec<Option<bool>>, AigParseError> {
+        line.chars()
+            .map(|c| match c {
+                '0' => Ok(Some(false)),
+                '1' => Ok(Some(true)),
+                'x' => Ok(None),
+                _ => Err(Self::error(
+                    line_number_from_1,
+                    format!("Unexpected character '{c}' in witness."),
+                )),
+            })
+            .collect()
+    }
+
+    fn parse_property_line(
+        line: &str,
+        line_number_from_1: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        line.split_whitespace()
+            .map(|property| {
+                property
+                    .strip_prefix('b')
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .ok_or_else(|| {
+                        Self::error(
+                            line_number_from_1,
+                            format!("Property '{property}' should be b<index>."),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    fn bits_to_string(bits: impl Iterator<Item = Option<bool>>) -> String {
+        bits.map(|bit| match bit {
+            Some(false) => '0',
+            Some(true) => '1',
+            None => 'x',
+        })
+        .collect()
+    }
+
+    fn get_simulation_inputs(&self, aig: &AndInverterGraph) -> Vec<HashMap<usize, bool>> {
+        let input_literals = aig.get_input_information();
+        self.inputs
+            .iter()
+            .map(|values| {
+                input_literals
+                    .iter()
+                    .zip(values.iter())
+                    .map(|(literal, value)| (literal >> 1, value.to_owned()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn get_simulation_initial_latches(&self, aig: &AndInverterGraph) -> HashMap<usize, bool> {
+        // unknown values of uninitialized latches are taken to be 0.
+        aig.get_latch_information()
+            .iter()
+            .zip(self.initial_latches.iter())
+            .filter(|((latch, _, reset), _)| reset == latch)
+            .map(|((latch, _, _), value)| (latch >> 1, value.unwrap_or(false)))
+            .collect()
+    }
+
+    fn get_btor2_word_lines(words: &[Btor2Word], values: &[Option<bool>]) -> Vec<String> {
+        words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                let value = word.get_value_string(values);
+                match word.get_symbol() {
+                    "" => format!("{index} {value}"),
+                    symbol => format!("{index} {value} {symbol}"),
+                }
+            })
+            .collect()
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that creates a witness from an assignment that was found by BMC, see
+    /// `try_from_assignment`.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig` - the AndInverterGraph that was checked.
+    /// * `fin_state` - the FiniteStateTransitionSystem that was created from `aig`.
+    /// * `assignment` - the assignment of the counter example.
+    /// * `depth` - the depth of the counter example, the witness has `depth + 1` cycles.
+    pub fn from_assignment(
+        aig: &AndInverterGraph,
+        fin_state: &FiniteStateTransitionSystem,
+        assignment: &Assignment,
+        depth: VariableType,
+    ) -> Self {
+        Self::try_from_assignment(aig, fin_state, assignment, depth)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that creates a witness from an assignment that was found by BMC, or reports
+    /// why the witness could not be simulated on the AndInverterGraph.
+    /// The variables of the assignment are those of the FiniteStateTransitionSystem, with
+    /// one set of tags for each cycle.
+    /// The witness violates the bad state literals that are true in its last cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig` - the AndInverterGraph that was checked.
+    /// * `fin_state` - the FiniteStateTransitionSystem that was created from `aig`.
+    /// * `assignment` - the assignment of the counter example.
+    /// * `depth` - the depth of the counter example, the witness has `depth + 1` cycles.
+    pub fn try_from_assignment(
+        aig: &AndInverterGraph,
+        fin_state: &FiniteStateTransitionSystem,
+        assignment: &Assignment,
+        depth: VariableType,
+    ) -> Result<Self, AigSimulationError> {
+        let max_literal_number = fin_state.get_max_literal_number();
+        let get_value = |literal: usize, cycle: VariableType| {
+            let variable: VariableType = (literal >> 1).try_into().unwrap();
+            assignment.get_value(&(variable + max_literal_number * cycle))
+        };
+
+        let initial_latches: Vec<Option<bool>> = aig
+            .get_latch_information()
+            .iter()
+            .map(|(latch, _, reset)| match reset {
+                0 => Some(false),
+                1 => Some(true),
+                _ => get_value(latch.to_owned(), 0),
+            })
+            .collect();
+        let input_literals = aig.get_input_information();
+        let inputs: Vec<Vec<bool>> = (0..(depth + 1))
+            .map(|cycle| {
+                input_literals
+                    .iter()
+                    .map(|i| get_value(i.to_owned(), cycle).unwrap_or(false))
+                    .collect()
+            })
+            .collect();
+
+        let mut witness = Self {
+            property_indices: Vec::new(),
+            initial_latches,
+            inputs,
+        };
+        let result = aig.try_simulate(
+            &witness.get_simulation_inputs(aig),
+            &witness.get_simulation_initial_latches(aig),
+        )?;
+        let last_cycle = result.get_number_of_cycles() - 1;
+        witness.property_indices = (0..aig.get_bad_information().len())
+            .filter(|index| result.get_bad_value(last_cycle, index.to_owned()))
+            .collect();
+        Ok(witness)
+    }
+
+    /// Function that creates a witness from the result of BMC, or None if BMC did not find a
+    /// counter example.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig` - the AndInverterGraph that was checked.
+    /// * `fin_state` - the FiniteStateTransitionSystem that was created from `aig`.
+    /// * `bmc_result` - the result of BMC.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::algorithms::{witness::AigerWitness, BMC};
+    /// use rust_formal_verification::models::{AndInverterGraph, FiniteStateTransitionSystem};
+    /// use rust_formal_verification::solvers::sat::stateless::CaDiCalSolver;
+    /// use std::time::Duration;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter_with_bad_assertion.aig");
+    /// let fsts = FiniteStateTransitionSystem::from_aig(&aig, false);
+    /// let bmc_result = BMC::<CaDiCalSolver>::new(false).search(&fsts, 10, Duration::from_secs(10));
+    /// let witness = AigerWitness::from_bmc_result(&aig, &fsts, &bmc_result).unwrap();
+    /// assert_eq!(witness.to_string(), "1\nb0\n000\n\n\n\n\n.\n");
+    /// assert!(witness.check(&aig));
+    /// ```
+    pub fn from_bmc_result(
+        aig: &AndInverterGraph,
+        fin_state: &FiniteStateTransitionSystem,
+        bmc_result: &BMCResult,
+    ) -> Option<Self> {
+        match bmc_result {
+            BMCResult::NoCTX { depth_reached: _ } => None,
+            BMCResult::CTX { assignment, depth } => Some(Self::from_assignment(
+                aig,
+                fin_state,
+                assignment,
+                depth.to_owned(),
+            )),
+        }
+    }
+
+    /// Function that maps a witness of an AndInverterGraph that was created by a
+    /// transformation, like phase abstraction or temporal decomposition, back to a witness of
+    /// the original graph, using the trace map that the transformation returned.
+    /// The trace of the original graph ends in the first cycle in which some bad state
+    /// literal is true while the invariant constraints held so far, and the witness violates
+    /// the bad state literals that are true in that cycle. The result is None if there is no
+    /// such cycle, which only happens if the witness does not violate the transformed graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - the AndInverterGraph that was transformed.
+    /// * `trace_map` - the trace map that the transformation returned.
+    /// * `witness` - a witness of the transformed graph.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::algorithms::witness::AigerWitness;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // two latches that shift an input, the second one is bad.
+    /// let aig = AndInverterGraph::from_aag_str("aag 3 1 2 0 0 1\n2\n4 2\n6 4\n6\n");
+    /// let (unfolded, trace_map) = aig.unfold(2);
+    /// // the input is 1 in the first phase, so the second latch is 1 after 2 cycles.
+    /// let witness = AigerWitness::try_from_cex_str("1\nb0\n00\n10\n00\n.\n").unwrap();
+    /// assert!(witness.check(&unfolded));
+    /// let original = AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+    /// assert_eq!(original.to_string(), "1\nb0\n00\n1\n0\n0\n.\n");
+    /// assert!(original.check(&aig));
+    /// ```
+    pub fn from_transformed_witness(
+        original: &AndInverterGraph,
+        trace_map: &AigTraceMap,
+        witness: &AigerWitness,
+    ) -> Option<Self> {
+        let (initial_latches, inputs) =
+            trace_map.map_trace(&witness.initial_latches, &witness.inputs);
+        let mut result = Self {
+            property_indices: Vec::new(),
+            initial_latches,
+            inputs,
+        };
+        let simulation = original
+            .try_simulate(
+                &result.get_simulation_inputs(original),
+                &result.get_simulation_initial_latches(original),
+            )
+            .ok()?;
+        let number_of_bad = original.get_bad_information().len();
+        let number_of_constraints = original.get_constraints_information().len();
+        let last_cycle = (0..simulation.get_number_of_cycles())
+            .take_while(|cycle| {
+                (0..number_of_constraints)
+                    .all(|index| simulation.get_constraint_value(cycle.to_owned(), index))
+            })
+            .find(|cycle| {
+                (0..number_of_bad).any(|index| simulation.get_bad_value(cycle.to_owned(), index))
+            })?;
+        result.inputs.truncate(last_cycle + 1);
+        result.property_indices = (0..number_of_bad)
+            .filter(|index| simulation.get_bad_value(last_cycle, index.to_owned()))
+            .collect();
+        Some(result)
+    }
+
+    /// Function that reads a witness from the content of a '.cex' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `cex` - the content of the '.cex' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::algorithms::witness::AigerWitness;
+    /// let witness = AigerWitness::try_from_cex_str("1\nb1\n0x\n1\n0\n.\n").unwrap();
+    /// assert_eq!(witness.get_property_indices(), &[1]);
+    /// assert_eq!(witness.get_initial_latches(), &[Some(false), None]);
+    /// assert_eq!(witness.get_inputs(), &[vec![true], vec![false]]);
+    /// assert!(AigerWitness::try_from_cex_str("0\nb0\n.\n").is_err());
+    /// ```
+    pub fn try_from_cex_str(cex: &str) -> Result<Self, AigParseError> {
+        // comments start with 'c' and may appear before the witness.
+        let mut lines = cex
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.starts_with('c'));
+        // missing lines are reported at the line after the end of the witness.
+        let end_line = cex.lines().count() + 1;
+        let mut next_line = |reason: &str| {
+            lines
+                .next()
+                .ok_or_else(|| Self::error(end_line, reason.to_string()))
+        };
+
+        let (status_line_number, status) = next_line("Witness is empty.")?;
+        if status != "1" {
+            return Err(Self::error(
+                status_line_number,
+                format!("Witness status should be 1, found '{status}'."),
+            ));
+        }
+        let (property_line_number, properties) = next_line("Witness has no property line.")?;
+        let property_indices = Self::parse_property_line(properties, property_line_number)?;
+        let (latch_line_number, latches) = next_line("Witness has no initial state line.")?;
+        let initial_latches = Self::parse_bits(latches, latch_line_number)?;
+
+        let mut inputs = Vec::new();
+        for (line_number, line) in lines.by_ref() {
+            if line == "." {
+                return Ok(Self {
+                    property_indices,
+                    initial_latches,
+                    inputs,
+                });
+            }
+            // unknown input values are taken to be 0.
+            let values = Self::parse_bits(line, line_number)?;
+            inputs.push(values.iter().map(|v| v.unwrap_or(false)).collect());
+        }
+        Err(Self::error(
+            end_line,
+            String::from("Witness does not end with '.'."),
+        ))
+    }
+
+    /// Function that reads a witness from a '.cex' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.cex' file.
+    pub fn try_from_cex_path(file_path: &str) -> Result<Self, AigParseError> {
+        let cex = fs::read_to_string(file_path).map_err(|e| AigParseError::UnreadableFile {
+            path: file_path.to_string(),
+            reason: e.to_string(),
+        })?;
+        Self::try_from_cex_str(&cex)
+    }
+
+    /// Function that writes the witness into a '.cex' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path of the '.cex' file to write.
+    pub fn write_cex_path(&self, file_path: &str) {
+        fs::write(file_path, self.to_string())
+            .unwrap_or_else(|_| panic!("Unable to write the '.cex' file {file_path}"));
+    }
+
+    /// Function that gives the witness in the BTOR2 witness format, where the initial values
+    /// of the states and the values of the inputs in every cycle are shown per word.
+    /// The witness should be of the AndInverterGraph of the model, the inputs that the model
+    /// added for states without a next state are not shown.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the witness.
+    /// * `model` - the Btor2Model whose AndInverterGraph the witness is of.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::algorithms::witness::AigerWitness;
+    /// use rust_formal_verification::models::Btor2Model;
+    /// // a 2 bit state that gets the input, bad when it is 2.
+    /// let btor2 = "1 sort bitvec 2\n2 input 1 in\n3 state 1 s\n4 zero 1\n5 init 1 3 4\n6 next 1 3 2\n7 constd 1 2\n8 sort bitvec 1\n9 eq 8 3 7\n10 bad 9\n";
+    /// let model = Btor2Model::from_btor2_str(btor2);
+    /// let witness = AigerWitness::try_from_cex_str("1\nb0\n00\n01\n00\n.\n").unwrap();
+    /// assert!(witness.check(model.get_aig()));
+    /// assert_eq!(
+    ///     witness.get_btor2_witness_string(&model),
+    ///     "sat\nb0\n#0\n0 00 s\n@0\n0 10 in\n@1\n0 00 in\n.\n"
+    /// );
+    /// ```
+    pub fn get_btor2_witness_string(&self, model: &Btor2Model) -> String {
+        let properties: Vec<String> = self
+            .property_indices
+            .iter()
+            .map(|index| format!("b{index}"))
+            .collect();
+        let mut lines = vec![
+            String::from("sat"),
+            properties.join(" "),
+            String::from("#0"),
+        ];
+        lines.append(&mut Self::get_btor2_word_lines(
+            model.get_state_words(),
+            &self.initial_latches,
+        ));
+        for (cycle, values) in self.inputs.iter().enumerate() {
+            let values: Vec<Option<bool>> = values.iter().map(|v| Some(v.to_owned())).collect();
+            lines.push(format!("@{cycle}"));
+            lines.append(&mut Self::get_btor2_word_lines(
+                model.get_input_words(),
+                &values,
+            ));
+        }
+        lines.push(String::from("."));
+        lines.join("\n") + "\n"
+    }
+
+    pub fn get_property_indices(&self) -> &[usize] {
+        &self.property_indices
+    }
+
+    pub fn get_initial_latches(&self) -> &[Option<bool>] {
+        &self.initial_latches
+    }
+
+    pub fn get_inputs(&self) -> &[Vec<bool>] {
+        &self.inputs
+    }
+
+    /// Function that replays the witness on an AndInverterGraph using simulation, and checks
+    /// that every property of the witness is violated in the last cycle, while the invariant
+    /// constraints hold in all cycles.
+    /// Only uninitialized latches may have unknown values, which are taken to be 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the witness.
+    /// * `aig` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::algorithms::witness::AigerWitness;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter_with_2_bad_assertions.aig");
+    /// let witness = AigerWitness::try_from_cex_str("1\nb0\n000\n\n\n\n.\n").unwrap();
+    /// assert!(witness.check(&aig));
+    /// let too_short = AigerWitness::try_from_cex_str("1\nb0\n000\n\n\n.\n").unwrap();
+    /// assert!(!too_short.check(&aig));
+    /// ```
+    pub fn check(&self, aig: &AndInverterGraph) -> bool {
+        let number_of_bad = aig.get_bad_information().len();
+        let number_of_inputs = aig.get_input_information().len();
+        if self.inputs.is_empty()
+            || self.property_indices.is_empty()
+            || self.property_indices.iter().any(|i| i >= &number_of_bad)
+            || self.initial_latches.len() != aig.get_latch_information().len()
+            || self.inputs.iter().any(|i| i.len() != number_of_inputs)
+        {
+            return false;
+        }
+
+        let inputs = self.get_simulation_inputs(aig);
+        let initial_latches = self.get_simulation_initial_latches(aig);
+        let result = match aig.try_simulate(&inputs, &initial_latches) {
+            Ok(r) => r,
+            Err(_) => return false,
+        };
+        let does_initial_state_match = aig
+            .get_latch_information()
+            .iter()
+            .zip(self.initial_latches.iter())
+            .enumerate()
+            .all(|(index, ((latch, _, reset), value))| match value {
+                Some(v) => result.get_latch_value(0, index) == *v,
+                None => reset >> 1 == latch >> 1,
+            });
+        let do_constraints_hold = (0..result.get_number_of_cycles()).all(|cycle| {
+            (0..aig.get_constraints_information().len())
+                .all(|index| result.get_constraint_value(cycle, index))
+        });
+        let last_cycle = result.get_number_of_cycles() - 1;
+        let are_properties_violated = self
+            .property_indices
+            .iter()
+            .all(|index| result.get_bad_value(last_cycle, index.to_owned()));
+        does_initial_state_match && do_constraints_hold && are_properties_violated
+    }
+}
+
+// ************************************************************************************************
+// printing
+// ************************************************************************************************
+
+impl fmt::Display for AigerWitness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "1")?;
+        let properties: Vec<String> = self
+            .property_indices
+            .iter()
+            .map(|index| format!("b{index}"))
+            .collect();
+        writeln!(f, "{}", properties.join(" "))?;
+        writeln!(
+            f,
+            "{}",
+            Self::bits_to_string(self.initial_latches.iter().copied())
+        )?;
+        for values in self.inputs.iter() {
+            writeln!(
+                f,
+                "{}",
+                Self::bits_to_string(values.iter().map(|v| Some(v.to_owned())))
+            )?;
+        }
+        writeln!(f, ".")
+    }
+}
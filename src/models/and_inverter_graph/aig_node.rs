@@ -38,6 +38,7 @@ pub struct AIGNode {
     output_symbol: String,
     bad_symbol: String,
     constraint_symbol: String,
+    fairness_symbol: String,
 }
 
 // ************************************************************************************************
@@ -61,6 +62,7 @@ impl AIGNode {
             output_symbol: String::from(""),
             bad_symbol: String::from(""),
             constraint_symbol: String::from(""),
+            fairness_symbol: String::from(""),
         }
     }
 
@@ -160,4 +162,12 @@ impl AIGNode {
     pub fn get_constraint_symbol(&self) -> &str {
         self.constraint_symbol.as_str()
     }
+
+    pub fn set_fairness_symbol(&mut self, symbol: &str) {
+        self.fairness_symbol = symbol.to_string();
+    }
+
+    pub fn get_fairness_symbol(&self) -> &str {
+        self.fairness_symbol.as_str()
+    }
 }
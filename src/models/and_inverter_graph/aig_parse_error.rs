@@ -0,0 +1,51 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use std::error::Error;
+use std::fmt;
+
+// ************************************************************************************************
+// enum
+// ************************************************************************************************
+
+/// Reason for failing to create an AndInverterGraph from an '.aig', '.aag', '.blif', '.btor2'
+/// or structural Verilog file, or a witness from a '.cex' file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AigParseError {
+    /// The file could not be read at all.
+    UnreadableFile { path: String, reason: String },
+    /// Some line of the file (counted from 1) does not follow the format.
+    InvalidLine { line: usize, reason: String },
+    /// Some byte of the file (counted from 0) does not follow the format, this is used for the
+    /// binary parts of '.aig' files and for what comes after them.
+    InvalidBytes { byte_offset: usize, reason: String },
+    /// The file was read but the resulting graph is inconsistent.
+    InvalidStructure { reason: String },
+}
+
+// ************************************************************************************************
+// printing
+// ************************************************************************************************
+
+impl fmt::Display for AigParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AigParseError::UnreadableFile { path, reason } => {
+                write!(f, "Unable to read the file {path}: {reason}")
+            }
+            AigParseError::InvalidLine { line, reason } => write!(f, "Line {line}: {reason}"),
+            AigParseError::InvalidBytes {
+                byte_offset,
+                reason,
+            } => write!(f, "Byte {byte_offset}: {reason}"),
+            AigParseError::InvalidStructure { reason } => write!(f, "Invalid AIG: {reason}"),
+        }
+    }
+}
+
+// ************************************************************************************************
+// error
+// ************************************************************************************************
+
+impl Error for AigParseError {}
@@ -0,0 +1,61 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use std::error::Error;
+use std::fmt;
+
+// ************************************************************************************************
+// enum
+// ************************************************************************************************
+
+/// Reason for failing to simulate an AndInverterGraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AigSimulationError {
+    /// No inputs were given, so there is not even a first cycle to simulate.
+    EmptyInputs,
+    /// The inputs of some cycle (counted from 0) are not exactly the input variables.
+    WrongInputVariables { cycle: usize },
+    /// The initial value of an uninitialized latch was not given.
+    MissingInitialLatch { variable: usize },
+    /// An initial value was given to a variable that is not a latch.
+    NotALatch { variable: usize },
+}
+
+// ************************************************************************************************
+// printing
+// ************************************************************************************************
+
+impl fmt::Display for AigSimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AigSimulationError::EmptyInputs => {
+                write!(f, "Inputs cannot be empty to start simulation.")
+            }
+            AigSimulationError::WrongInputVariables { cycle } => {
+                write!(
+                    f,
+                    "Inputs of cycle {cycle} must contain exactly the input variables."
+                )
+            }
+            AigSimulationError::MissingInitialLatch { variable } => {
+                write!(
+                    f,
+                    "Initial value of latch {variable} is unknown, it must be provided."
+                )
+            }
+            AigSimulationError::NotALatch { variable } => {
+                write!(
+                    f,
+                    "Initial value was provided for variable {variable} which is not a latch."
+                )
+            }
+        }
+    }
+}
+
+// ************************************************************************************************
+// error
+// ************************************************************************************************
+
+impl Error for AigSimulationError {}
@@ -0,0 +1,148 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::{AigBuilder, AndInverterGraph};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the number of and gates and roots that use every variable.
+    fn get_number_of_references(&self) -> Vec<usize> {
+        let mut references = vec![0; self.nodes.len()];
+        for node in self.nodes.iter() {
+            if node.get_type() == AIGNodeType::And {
+                references[node.get_and_rhs0() >> 1] += 1;
+                references[node.get_and_rhs1() >> 1] += 1;
+            }
+        }
+        for root in self.get_combinational_roots() {
+            references[root >> 1] += 1;
+        }
+        references
+    }
+
+    /// the literals that the and gate is a conjunction of, when and gates that are only used
+    /// by it are expanded. A conjunction with a literal and its negation has the single
+    /// leaf 0, and the constant 1 is not a leaf.
+    fn get_supergate_leaves(&self, variable: usize, references: &[usize]) -> Vec<usize> {
+        let node = &self.nodes[variable];
+        let mut leaves = Vec::new();
+        let mut literals_to_visit = vec![node.get_and_rhs0(), node.get_and_rhs1()];
+        while let Some(literal) = literals_to_visit.pop() {
+            let node = &self.nodes[literal >> 1];
+            if literal & 1 == 0
+                && node.get_type() == AIGNodeType::And
+                && references[literal >> 1] == 1
+            {
+                literals_to_visit.push(node.get_and_rhs0());
+                literals_to_visit.push(node.get_and_rhs1());
+            } else if literal != 1 {
+                leaves.push(literal);
+            }
+        }
+        leaves.sort();
+        leaves.dedup();
+        // a literal and its negation are next to each other after sorting.
+        if leaves.first() == Some(&0) || leaves.windows(2).any(|w| w[0] ^ 1 == w[1]) {
+            return vec![0];
+        }
+        leaves
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that balances an AndInverterGraph to reduce its logic depth, the largest
+    /// number of and gates on a path from an input or a latch to a latch or a property.
+    /// Every and gate that is used elsewhere or negated is the root of a large conjunction
+    /// that includes the and gates only it uses. The inputs of every conjunction are paired
+    /// starting from the ones with the lowest depth, which gives a tree of the least depth.
+    /// Only the combinational logic changes, so the inputs, latches and their initial
+    /// values stay the same, and so does the sequential behavior. The symbols of inputs,
+    /// latches, outputs, bad state literals and invariant constraints are kept. Panics if
+    /// the graph has justice properties or fairness constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // ((w & x) & y) & z, with a depth of 3.
+    /// let aig = AndInverterGraph::from_aag_str("aag 7 4 0 1 3\n2\n4\n6\n8\n14\n10 4 2\n12 10 6\n14 12 8\n");
+    /// // (w & x) & (y & z), with a depth of 2.
+    /// assert_eq!(
+    ///     aig.balance().get_aag_string(),
+    ///     "aag 7 4 0 1 3\n2\n4\n6\n8\n14\n10 4 2\n12 8 6\n14 12 10\n"
+    /// );
+    /// ```
+    pub fn balance(&self) -> AndInverterGraph {
+        self.assert_no_justice_or_fairness("Balancing");
+        let references = self.get_number_of_references();
+        let mut supergate_leaves: Vec<Option<Vec<usize>>> = vec![None; self.nodes.len()];
+        let mut variables_to_visit: Vec<usize> = self
+            .get_combinational_roots()
+            .iter()
+            .map(|l| l >> 1)
+            .collect();
+        while let Some(variable) = variables_to_visit.pop() {
+            if self.nodes[variable].get_type() == AIGNodeType::And
+                && supergate_leaves[variable].is_none()
+            {
+                let leaves = self.get_supergate_leaves(variable, &references);
+                variables_to_visit.extend(leaves.iter().map(|l| l >> 1));
+                supergate_leaves[variable] = Some(leaves);
+            }
+        }
+
+        let mut builder = AigBuilder::new();
+        let mut literal_map = self.get_literal_map_with_copies(&mut builder, |_| true);
+        // the depth of every variable of the builder, inputs and latches have depth 0.
+        let mut depths: HashMap<usize, usize> = HashMap::new();
+        for (variable, leaves) in supergate_leaves.iter().enumerate() {
+            let leaves = match leaves {
+                Some(leaves) => leaves,
+                None => continue,
+            };
+            let mut queue: BinaryHeap<Reverse<(usize, usize)>> = leaves
+                .iter()
+                .map(|leaf| {
+                    let literal = Self::map_literal(&literal_map, leaf.to_owned());
+                    let depth = depths.get(&(literal >> 1)).copied().unwrap_or(0);
+                    Reverse((depth, literal))
+                })
+                .collect();
+            while queue.len() > 1 {
+                let Reverse((a_depth, a)) = queue.pop().unwrap();
+                let Reverse((b_depth, b)) = queue.pop().unwrap();
+                let literal = builder.add_and(a, b);
+                let depth = match literal {
+                    0 | 1 => 0,
+                    _ => *depths
+                        .entry(literal >> 1)
+                        .or_insert(a_depth.max(b_depth) + 1),
+                };
+                queue.push(Reverse((depth, literal)));
+            }
+            literal_map[variable] = queue.pop().map_or(1, |Reverse((_, literal))| literal);
+        }
+        self.set_next_of_copied_latches(&mut builder, &literal_map, |_| true);
+
+        self.add_copies_of_outputs_bad_and_constraints(&mut builder, |_, literal| {
+            Self::map_literal(&literal_map, literal)
+        });
+        builder.build()
+    }
+}
@@ -0,0 +1,353 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::AndInverterGraph;
+use rand::Rng;
+use std::collections::HashMap;
+
+// ************************************************************************************************
+// AIG bit parallel simulation result
+// ************************************************************************************************
+
+/// Struct that holds the result of simulating an AndInverterGraph on many input patterns at
+/// once.
+///
+/// Each pattern is called a lane, every signal has `number_of_words` words of 64 bits per
+/// cycle, and lane `k` is bit `k % 64` of word `k / 64`.
+pub struct AIGBitParallelSimulationResult {
+    number_of_words: usize,
+    // these are ordered by cycle, then by the position of the signal, then by word.
+    inputs: Vec<Vec<u64>>,
+    initial_latches: Vec<u64>,
+    outputs: Vec<Vec<u64>>,
+    bad: Vec<Vec<u64>>,
+    constraints: Vec<Vec<u64>>,
+}
+
+impl AIGBitParallelSimulationResult {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_words<'a>(&self, words: &'a [u64], index: usize) -> &'a [u64] {
+        let start = index * self.number_of_words;
+        &words[start..(start + self.number_of_words)]
+    }
+
+    fn get_lane(words: &[u64], lane: usize) -> bool {
+        (words[lane >> 6] >> (lane & 63)) & 1 == 1
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that returns the number of cycles that were simulated.
+    pub fn get_number_of_cycles(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// Function that returns the number of patterns that were simulated in parallel.
+    pub fn get_number_of_lanes(&self) -> usize {
+        self.number_of_words * 64
+    }
+
+    /// Function that returns the words of an input at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the input.
+    pub fn get_input_words(&self, cycle: usize, index: usize) -> &[u64] {
+        self.get_words(&self.inputs[cycle], index)
+    }
+
+    /// Function that returns the words of a latch at cycle 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - the position of the latch.
+    pub fn get_initial_latch_words(&self, index: usize) -> &[u64] {
+        self.get_words(&self.initial_latches, index)
+    }
+
+    /// Function that returns the words of an output at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the output.
+    pub fn get_output_words(&self, cycle: usize, index: usize) -> &[u64] {
+        self.get_words(&self.outputs[cycle], index)
+    }
+
+    /// Function that returns the words of a bad state literal at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the bad state literal.
+    pub fn get_bad_words(&self, cycle: usize, index: usize) -> &[u64] {
+        self.get_words(&self.bad[cycle], index)
+    }
+
+    /// Function that returns the words of an invariant constraint at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the invariant constraint.
+    pub fn get_constraint_words(&self, cycle: usize, index: usize) -> &[u64] {
+        self.get_words(&self.constraints[cycle], index)
+    }
+
+    /// Function that returns the value of an input at some cycle in some lane.
+    pub fn get_input_value(&self, cycle: usize, index: usize, lane: usize) -> bool {
+        Self::get_lane(self.get_input_words(cycle, index), lane)
+    }
+
+    /// Function that returns the value of a latch at cycle 0 in some lane.
+    pub fn get_initial_latch_value(&self, index: usize, lane: usize) -> bool {
+        Self::get_lane(self.get_initial_latch_words(index), lane)
+    }
+
+    /// Function that returns the value of an output at some cycle in some lane.
+    pub fn get_output_value(&self, cycle: usize, index: usize, lane: usize) -> bool {
+        Self::get_lane(self.get_output_words(cycle, index), lane)
+    }
+
+    /// Function that returns the value of a bad state literal at some cycle in some lane.
+    pub fn get_bad_value(&self, cycle: usize, index: usize, lane: usize) -> bool {
+        Self::get_lane(self.get_bad_words(cycle, index), lane)
+    }
+
+    /// Function that returns the value of an invariant constraint at some cycle in some lane.
+    pub fn get_constraint_value(&self, cycle: usize, index: usize, lane: usize) -> bool {
+        Self::get_lane(self.get_constraint_words(cycle, index), lane)
+    }
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    pub(super) fn get_literal_words(
+        values: &[u64],
+        literal: usize,
+        number_of_words: usize,
+        word: usize,
+    ) -> u64 {
+        let value = values[(literal >> 1) * number_of_words + word];
+        if (literal & 1) == 0 {
+            value
+        } else {
+            !value
+        }
+    }
+
+    fn get_words_of_literals(
+        values: &[u64],
+        literals: &[usize],
+        number_of_words: usize,
+    ) -> Vec<u64> {
+        let mut result = Vec::with_capacity(literals.len() * number_of_words);
+        for literal in literals {
+            for word in 0..number_of_words {
+                result.push(Self::get_literal_words(
+                    values,
+                    *literal,
+                    number_of_words,
+                    word,
+                ));
+            }
+        }
+        result
+    }
+
+    fn get_initial_latch_words(
+        &self,
+        initial_latches: &HashMap<usize, Vec<u64>>,
+        number_of_words: usize,
+    ) -> Vec<u64> {
+        let mut result = Vec::with_capacity(self.latches.len() * number_of_words);
+        for latch_index in self.latches.iter() {
+            match initial_latches.get(latch_index) {
+                Some(words) => {
+                    assert_eq!(words.len(), number_of_words, "Wrong number of latch words.");
+                    result.extend(words.iter());
+                }
+                None => {
+                    let reset = self.nodes[latch_index.to_owned()].get_latch_reset();
+                    assert!(
+                        reset <= 1,
+                        "Initial value of latch {latch_index} is unknown, it must be provided."
+                    );
+                    let word = if reset == 1 { u64::MAX } else { 0 };
+                    result.resize(result.len() + number_of_words, word);
+                }
+            }
+        }
+        result
+    }
+
+    // ********************************************************************************************
+    // aig bit parallel simulation
+    // ********************************************************************************************
+
+    /// Function that simulates the AndInverterGraph on `64 * number_of_words` input patterns
+    /// at once, every signal is represented by words of 64 bits, one bit per pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `inputs` - for each cycle, the words of each input in the order of the inputs, one
+    ///   after the other, so input `i` is at `[i * number_of_words..(i + 1) * number_of_words]`.
+    /// * `initial_latches` - maps the variable number of a latch to its words in cycle 0,
+    ///   latches that are not in the map start at their reset value.
+    /// * `number_of_words` - the number of 64 bit words per signal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// // a latch that becomes 1 once the input is 1, bad when the latch is 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 3 1 1 0 1 1\n2\n4 7\n4\n6 5 3\n");
+    /// // lane 0 has input 1 in cycle 0, lane 1 has it in cycle 1, lane 2 never has it.
+    /// let inputs = vec![vec![0b001], vec![0b010], vec![0b000]];
+    /// let result = aig.simulate_bit_parallel(&inputs, &HashMap::new(), 1);
+    /// assert_eq!(result.get_bad_words(0, 0), &[0b000]);
+    /// assert_eq!(result.get_bad_words(1, 0), &[0b001]);
+    /// assert_eq!(result.get_bad_words(2, 0), &[0b011]);
+    /// assert!(result.get_bad_value(2, 0, 1));
+    /// ```
+    pub fn simulate_bit_parallel(
+        &self,
+        inputs: &[Vec<u64>],
+        initial_latches: &HashMap<usize, Vec<u64>>,
+        number_of_words: usize,
+    ) -> AIGBitParallelSimulationResult {
+        assert!(number_of_words > 0, "There must be at least one word.");
+        for cycle_inputs in inputs {
+            assert_eq!(
+                cycle_inputs.len(),
+                self.inputs.len() * number_of_words,
+                "Wrong number of input words."
+            );
+        }
+        for latch_var in initial_latches.keys() {
+            let node_type = self.nodes.get(latch_var.to_owned()).map(|n| n.get_type());
+            assert_eq!(
+                node_type,
+                Some(AIGNodeType::Latch),
+                "Variable {latch_var} is not a latch."
+            );
+        }
+
+        let mut result = AIGBitParallelSimulationResult {
+            number_of_words,
+            inputs: inputs.to_vec(),
+            initial_latches: self.get_initial_latch_words(initial_latches, number_of_words),
+            outputs: Vec::with_capacity(inputs.len()),
+            bad: Vec::with_capacity(inputs.len()),
+            constraints: Vec::with_capacity(inputs.len()),
+        };
+
+        let mut latch_words = result.initial_latches.to_owned();
+        let mut values: Vec<u64> = vec![0; self.nodes.len() * number_of_words];
+        for cycle_inputs in inputs {
+            for (position, input_index) in self.inputs.iter().enumerate() {
+                let source = &cycle_inputs[position * number_of_words..][..number_of_words];
+                values[input_index * number_of_words..][..number_of_words].copy_from_slice(source);
+            }
+            for (position, latch_index) in self.latches.iter().enumerate() {
+                let source = &latch_words[position * number_of_words..][..number_of_words];
+                values[latch_index * number_of_words..][..number_of_words].copy_from_slice(source);
+            }
+            for (variable, node) in self.nodes.iter().enumerate() {
+                if node.get_type() == AIGNodeType::And {
+                    let rhs0 = node.get_and_rhs0();
+                    let rhs1 = node.get_and_rhs1();
+                    for word in 0..number_of_words {
+                        values[variable * number_of_words + word] =
+                            Self::get_literal_words(&values, rhs0, number_of_words, word)
+                                & Self::get_literal_words(&values, rhs1, number_of_words, word);
+                    }
+                }
+            }
+
+            result.outputs.push(Self::get_words_of_literals(
+                &values,
+                &self.outputs,
+                number_of_words,
+            ));
+            result.bad.push(Self::get_words_of_literals(
+                &values,
+                &self.bad,
+                number_of_words,
+            ));
+            result.constraints.push(Self::get_words_of_literals(
+                &values,
+                &self.constraints,
+                number_of_words,
+            ));
+
+            let next_literals: Vec<usize> = self
+                .latches
+                .iter()
+                .map(|l| self.nodes[l.to_owned()].get_latch_input())
+                .collect();
+            latch_words = Self::get_words_of_literals(&values, &next_literals, number_of_words);
+        }
+        result
+    }
+
+    /// Function that simulates the AndInverterGraph on `64 * number_of_words` random input
+    /// patterns at once. Uninitialized latches get random initial values.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `number_of_cycles` - the number of cycles to simulate.
+    /// * `number_of_words` - the number of 64 bit words per signal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter_with_bad_assertion.aig");
+    /// let result = aig.simulate_bit_parallel_randomly(5, 2);
+    /// assert_eq!(result.get_number_of_cycles(), 5);
+    /// assert_eq!(result.get_number_of_lanes(), 128);
+    /// // the counter has no inputs, so every lane reaches the bad state in cycle 3.
+    /// assert_eq!(result.get_bad_words(2, 0), &[0, 0]);
+    /// assert_eq!(result.get_bad_words(3, 0), &[u64::MAX, u64::MAX]);
+    /// ```
+    pub fn simulate_bit_parallel_randomly(
+        &self,
+        number_of_cycles: usize,
+        number_of_words: usize,
+    ) -> AIGBitParallelSimulationResult {
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<Vec<u64>> = (0..number_of_cycles)
+            .map(|_| {
+                (0..(self.inputs.len() * number_of_words))
+                    .map(|_| rng.gen())
+                    .collect()
+            })
+            .collect();
+        let mut initial_latches = HashMap::new();
+        for latch_index in self.latches.iter() {
+            if self.nodes[latch_index.to_owned()].get_latch_reset() > 1 {
+                let words: Vec<u64> = (0..number_of_words).map(|_| rng.gen()).collect();
+                initial_latches.insert(latch_index.to_owned(), words);
+            }
+        }
+        self.simulate_bit_parallel(&inputs, &initial_latches, number_of_words)
+    }
+}
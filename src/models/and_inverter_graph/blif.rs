@@ -0,0 +1,655 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigParseError, AndInverterGraph};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// a '.names' command of a BLIF file, the rows are the cubes of the cover.
+struct BlifCover {
+    inputs: Vec<String>,
+    output: String,
+    rows: Vec<String>,
+    // true if the rows describe when the output is 1, false if they describe when it is 0.
+    is_on_set: bool,
+    line: usize,
+}
+
+/// a '.latch' command of a BLIF file.
+struct BlifLatch {
+    next: String,
+    output: String,
+    reset: Option<bool>,
+    line: usize,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // blif writing helper functions
+    // ********************************************************************************************
+
+    /// BLIF names cannot contain white spaces, and '#' and '\' have a special meaning, so
+    /// these are replaced. Names that were already used get a numbered suffix.
+    fn get_unique_blif_name(
+        symbol: &str,
+        default_name: String,
+        used_names: &mut HashSet<String>,
+    ) -> String {
+        let mut name: String = symbol
+            .chars()
+            .map(|c| {
+                if c.is_whitespace() || c == '#' || c == '\\' {
+                    '_'
+                } else {
+                    c
+                }
+            })
+            .collect();
+        if name.is_empty() {
+            name = default_name;
+        }
+        let mut unique_name = name.clone();
+        let mut suffix = 1;
+        while !used_names.insert(unique_name.clone()) {
+            unique_name = format!("{name}_{suffix}");
+            suffix += 1;
+        }
+        unique_name
+    }
+
+    /// a cover with a single input that copies or inverts the literal into the desired name.
+    fn get_blif_buffer_lines(variable_names: &[String], literal: usize, to: &str) -> Vec<String> {
+        let value = if (literal & 1) == 1 { '0' } else { '1' };
+        vec![
+            format!(".names {} {to}", variable_names[literal >> 1]),
+            format!("{value} 1"),
+        ]
+    }
+
+    // ********************************************************************************************
+    // blif reading helper functions
+    // ********************************************************************************************
+
+    /// splits the file into lines of words, removing comments and joining lines that end with
+    /// '\', each line comes with the number of the line it started at (counted from 1).
+    fn get_blif_lines(blif: &str) -> Vec<(usize, Vec<String>)> {
+        let mut lines: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut is_continued = false;
+        for (index, line) in blif.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim_end();
+            let (line, continues) = match line.strip_suffix('\\') {
+                Some(line) => (line, true),
+                None => (line, false),
+            };
+            let words: Vec<String> = line.split_whitespace().map(String::from).collect();
+            if is_continued {
+                lines.last_mut().unwrap().1.extend(words);
+            } else if !words.is_empty() || continues {
+                lines.push((index + 1, words));
+            }
+            is_continued = continues;
+        }
+        lines.retain(|(_, words)| !words.is_empty());
+        lines
+    }
+
+    fn read_blif_latch(words: &[String], line: usize) -> Result<BlifLatch, AigParseError> {
+        // .latch <input> <output> [<type> <control>] [<init-val>]
+        let reset_word = match words.len() {
+            3 | 5 => None,
+            4 => Some(&words[3]),
+            6 => Some(&words[5]),
+            _ => {
+                return Err(AigParseError::InvalidLine {
+                    line,
+                    reason: String::from(
+                        "Latch should be: input output [type control] [init-val].",
+                    ),
+                })
+            }
+        };
+        let reset = match reset_word.map(|w| w.as_str()) {
+            Some("0") => Some(false),
+            Some("1") => Some(true),
+            // 2 means don't care and 3 means unknown.
+            Some("2") | Some("3") | None => None,
+            Some(word) => {
+                return Err(AigParseError::InvalidLine {
+                    line,
+                    reason: format!("Initial value of latch should be 0, 1, 2 or 3, found {word}."),
+                })
+            }
+        };
+        Ok(BlifLatch {
+            next: words[1].to_owned(),
+            output: words[2].to_owned(),
+            reset,
+            line,
+        })
+    }
+
+    fn read_blif_cover_row(
+        cover: &mut BlifCover,
+        words: &[String],
+        line: usize,
+    ) -> Result<(), AigParseError> {
+        let (plane, value) = match (cover.inputs.len(), words) {
+            (0, [value]) => ("", value),
+            (_, [plane, value]) => (plane.as_str(), value),
+            _ => {
+                return Err(AigParseError::InvalidLine {
+                    line,
+                    reason: String::from("Cube should have an input plane and an output value."),
+                })
+            }
+        };
+        if plane.len() != cover.inputs.len() || plane.chars().any(|c| !"01-".contains(c)) {
+            return Err(AigParseError::InvalidLine {
+                line,
+                reason: format!(
+                    "Input plane should have {} characters that are 0, 1 or -.",
+                    cover.inputs.len()
+                ),
+            });
+        }
+        let is_on_set = match value.as_str() {
+            "1" => true,
+            "0" => false,
+            _ => {
+                return Err(AigParseError::InvalidLine {
+                    line,
+                    reason: String::from("Output value of cube should be 0 or 1."),
+                })
+            }
+        };
+        if !cover.rows.is_empty() && cover.is_on_set != is_on_set {
+            return Err(AigParseError::InvalidLine {
+                line,
+                reason: String::from("All cubes of a cover should have the same output value."),
+            });
+        }
+        cover.is_on_set = is_on_set;
+        cover.rows.push(plane.to_string());
+        Ok(())
+    }
+
+    fn check_blif_signal_is_new(
+        defined_signals: &mut HashSet<String>,
+        signal: &str,
+        line: usize,
+    ) -> Result<(), AigParseError> {
+        if defined_signals.insert(signal.to_string()) {
+            Ok(())
+        } else {
+            Err(AigParseError::InvalidLine {
+                line,
+                reason: format!("Signal {signal} is defined more than once."),
+            })
+        }
+    }
+
+    fn get_literal_of_blif_cover(
+        builder: &mut AigBuilder,
+        literal_of_signal: &HashMap<String, usize>,
+        cover: &BlifCover,
+    ) -> usize {
+        // the cover is the disjunction of its cubes, computed as the negation of the
+        // conjunction of the negated cubes.
+        let mut none_of_the_cubes = 1;
+        for row in cover.rows.iter() {
+            let mut cube = 1;
+            for (c, input) in row.chars().zip(cover.inputs.iter()) {
+                let literal = literal_of_signal[input];
+                match c {
+                    '1' => cube = builder.add_and(cube, literal),
+                    '0' => cube = builder.add_and(cube, literal ^ 1),
+                    _ => {}
+                }
+            }
+            none_of_the_cubes = builder.add_and(none_of_the_cubes, cube ^ 1);
+        }
+        if cover.is_on_set {
+            none_of_the_cubes ^ 1
+        } else {
+            none_of_the_cubes
+        }
+    }
+
+    /// finds the literal of a signal, creating the gates of every cover it depends on.
+    /// Covers are visited with an explicit stack since netlists can be very deep.
+    fn get_literal_of_blif_signal(
+        builder: &mut AigBuilder,
+        literal_of_signal: &mut HashMap<String, usize>,
+        covers: &[BlifCover],
+        cover_of_signal: &HashMap<String, usize>,
+        signal: &str,
+        line: usize,
+    ) -> Result<usize, AigParseError> {
+        let undefined_signal_error = |signal: &str, line: usize| AigParseError::InvalidLine {
+            line,
+            reason: format!("Signal {signal} is never defined."),
+        };
+        if let Some(literal) = literal_of_signal.get(signal) {
+            return Ok(literal.to_owned());
+        }
+        let first_cover = cover_of_signal
+            .get(signal)
+            .ok_or_else(|| undefined_signal_error(signal, line))?;
+
+        // covers whose inputs are being computed, reaching one of them again is a loop.
+        let mut is_in_progress = vec![false; covers.len()];
+        let mut covers_to_visit = vec![(first_cover.to_owned(), false)];
+        while let Some((index, are_inputs_ready)) = covers_to_visit.pop() {
+            let cover = &covers[index];
+            if literal_of_signal.contains_key(&cover.output) {
+                continue;
+            }
+            if are_inputs_ready {
+                let literal = Self::get_literal_of_blif_cover(builder, literal_of_signal, cover);
+                literal_of_signal.insert(cover.output.to_owned(), literal);
+                is_in_progress[index] = false;
+                continue;
+            }
+            if is_in_progress[index] {
+                return Err(AigParseError::InvalidLine {
+                    line: cover.line,
+                    reason: format!("Signal {} depends on itself.", cover.output),
+                });
+            }
+            is_in_progress[index] = true;
+            covers_to_visit.push((index, true));
+            for input in cover.inputs.iter() {
+                if !literal_of_signal.contains_key(input) {
+                    let input_cover = cover_of_signal
+                        .get(input)
+                        .ok_or_else(|| undefined_signal_error(input, cover.line))?;
+                    covers_to_visit.push((input_cover.to_owned(), false));
+                }
+            }
+        }
+        Ok(literal_of_signal[signal])
+    }
+
+    fn from_blif_lines(lines: &[(usize, Vec<String>)]) -> Result<AndInverterGraph, AigParseError> {
+        let mut inputs: Vec<(usize, String)> = Vec::new();
+        let mut outputs: Vec<(usize, String)> = Vec::new();
+        let mut latches: Vec<BlifLatch> = Vec::new();
+        let mut covers: Vec<BlifCover> = Vec::new();
+        let mut is_reading_cover = false;
+
+        for (line, words) in lines.iter() {
+            let line = line.to_owned();
+            let is_command = words[0].starts_with('.');
+            is_reading_cover = is_reading_cover && !is_command;
+            match words[0].as_str() {
+                ".model" | ".clock" => {}
+                ".inputs" => inputs.extend(words[1..].iter().map(|w| (line, w.to_owned()))),
+                ".outputs" => outputs.extend(words[1..].iter().map(|w| (line, w.to_owned()))),
+                ".latch" => latches.push(Self::read_blif_latch(words, line)?),
+                ".names" => {
+                    if words.len() < 2 {
+                        return Err(AigParseError::InvalidLine {
+                            line,
+                            reason: String::from("Cover should have an output."),
+                        });
+                    }
+                    covers.push(BlifCover {
+                        inputs: words[1..(words.len() - 1)].to_vec(),
+                        output: words[words.len() - 1].to_owned(),
+                        rows: Vec::new(),
+                        is_on_set: true,
+                        line,
+                    });
+                    is_reading_cover = true;
+                }
+                ".end" => break,
+                _ if is_command => {
+                    return Err(AigParseError::InvalidLine {
+                        line,
+                        reason: format!("Unsupported BLIF command {}.", words[0]),
+                    })
+                }
+                _ if is_reading_cover => {
+                    Self::read_blif_cover_row(covers.last_mut().unwrap(), words, line)?
+                }
+                _ => {
+                    return Err(AigParseError::InvalidLine {
+                        line,
+                        reason: String::from("Cube is not part of any cover."),
+                    })
+                }
+            }
+        }
+
+        let mut builder = AigBuilder::new();
+        let mut defined_signals: HashSet<String> = HashSet::new();
+        let mut literal_of_signal: HashMap<String, usize> = HashMap::new();
+        for (line, input) in inputs.iter() {
+            Self::check_blif_signal_is_new(&mut defined_signals, input, line.to_owned())?;
+            let literal = builder.add_input();
+            builder.set_input_symbol(literal, input);
+            literal_of_signal.insert(input.to_owned(), literal);
+        }
+        for latch in latches.iter() {
+            Self::check_blif_signal_is_new(&mut defined_signals, &latch.output, latch.line)?;
+            let literal = builder.add_latch(0, latch.reset);
+            builder.set_latch_symbol(literal, &latch.output);
+            literal_of_signal.insert(latch.output.to_owned(), literal);
+        }
+        let mut cover_of_signal: HashMap<String, usize> = HashMap::new();
+        for (index, cover) in covers.iter().enumerate() {
+            Self::check_blif_signal_is_new(&mut defined_signals, &cover.output, cover.line)?;
+            cover_of_signal.insert(cover.output.to_owned(), index);
+        }
+
+        for latch in latches.iter() {
+            let next = Self::get_literal_of_blif_signal(
+                &mut builder,
+                &mut literal_of_signal,
+                &covers,
+                &cover_of_signal,
+                &latch.next,
+                latch.line,
+            )?;
+            builder.set_latch_next(literal_of_signal[&latch.output], next);
+        }
+        for (index, (line, output)) in outputs.iter().enumerate() {
+            let literal = Self::get_literal_of_blif_signal(
+                &mut builder,
+                &mut literal_of_signal,
+                &covers,
+                &cover_of_signal,
+                output,
+                line.to_owned(),
+            )?;
+            builder.add_output(literal);
+            builder.set_output_symbol(index, output);
+        }
+        Ok(builder.build())
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that converts the AndInverterGraph into the Berkeley Logic Interchange Format.
+    /// Inputs and latches keep their symbols as names when they have one, and and gates become
+    /// covers with two inputs.
+    /// BLIF has no bad state literals, so they are written as outputs after the outputs of the
+    /// AndInverterGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired for conversion.
+    ///
+    /// # Panics
+    ///
+    /// If the AndInverterGraph has invariant constraints, justice properties or fairness
+    /// constraints, since BLIF cannot describe them.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that becomes 1 once the input is 1, bad when the latch is 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 3 1 1 0 1 1\n2\n4 7\n4\n6 5 3\ni0 x\n");
+    /// assert_eq!(
+    ///     aig.get_blif_string(),
+    ///     ".model aig\n.inputs x\n.outputs b0\n.latch l0_next l0 0\n.names l0 x n3\n00 1\n.names n3 l0_next\n0 1\n.names l0 b0\n1 1\n.end\n"
+    /// );
+    /// ```
+    pub fn get_blif_string(&self) -> String {
+        assert!(
+            self.constraints.is_empty() && self.justice.is_empty() && self.fairness.is_empty(),
+            "BLIF cannot describe invariant constraints, justice properties or fairness constraints."
+        );
+        let mut used_names: HashSet<String> = HashSet::new();
+        let mut variable_names: Vec<String> = vec![String::new(); self.nodes.len()];
+
+        // names of inputs, latches, outputs and bad state literals are chosen first so they
+        // keep their symbols.
+        for (index, input_index) in self.inputs.iter().enumerate() {
+            let symbol = self.nodes[input_index.to_owned()].get_input_symbol();
+            variable_names[input_index.to_owned()] =
+                Self::get_unique_blif_name(symbol, format!("i{index}"), &mut used_names);
+        }
+        for (index, latch_index) in self.latches.iter().enumerate() {
+            let symbol = self.nodes[latch_index.to_owned()].get_latch_symbol();
+            variable_names[latch_index.to_owned()] =
+                Self::get_unique_blif_name(symbol, format!("l{index}"), &mut used_names);
+        }
+        let mut output_names: Vec<String> = Vec::new();
+        for (index, literal) in self.outputs.iter().enumerate() {
+            let symbol = self.nodes[literal >> 1].get_output_symbol();
+            output_names.push(Self::get_unique_blif_name(
+                symbol,
+                format!("o{index}"),
+                &mut used_names,
+            ));
+        }
+        for (index, literal) in self.bad.iter().enumerate() {
+            let symbol = self.nodes[literal >> 1].get_bad_symbol();
+            output_names.push(Self::get_unique_blif_name(
+                symbol,
+                format!("b{index}"),
+                &mut used_names,
+            ));
+        }
+        variable_names[0] = Self::get_unique_blif_name("", String::from("c0"), &mut used_names);
+        for and_index in self.ands.iter() {
+            variable_names[and_index.to_owned()] =
+                Self::get_unique_blif_name("", format!("n{and_index}"), &mut used_names);
+        }
+
+        let mut lines = vec![String::from(".model aig")];
+        if !self.inputs.is_empty() {
+            let names: Vec<&str> = self
+                .inputs
+                .iter()
+                .map(|i| &variable_names[*i][..])
+                .collect();
+            lines.push(format!(".inputs {}", names.join(" ")));
+        }
+        if !output_names.is_empty() {
+            lines.push(format!(".outputs {}", output_names.join(" ")));
+        }
+
+        // latches can only take a signal as is, so other literals need a cover of their own.
+        let mut latch_next_covers: Vec<String> = Vec::new();
+        for latch_index in self.latches.iter() {
+            let node = &self.nodes[latch_index.to_owned()];
+            let next = node.get_latch_input();
+            let next_name = if (next & 1) == 0 && next > 1 {
+                variable_names[next >> 1].to_owned()
+            } else {
+                let name = format!("{}_next", variable_names[latch_index.to_owned()]);
+                let name = Self::get_unique_blif_name("", name, &mut used_names);
+                latch_next_covers.append(&mut Self::get_blif_buffer_lines(
+                    &variable_names,
+                    next,
+                    &name,
+                ));
+                name
+            };
+            let reset = match node.get_latch_reset() {
+                0 => "0",
+                1 => "1",
+                _ => "3",
+            };
+            lines.push(format!(
+                ".latch {next_name} {} {reset}",
+                variable_names[latch_index.to_owned()]
+            ));
+        }
+
+        let roots: Vec<usize> = self
+            .outputs
+            .iter()
+            .chain(self.bad.iter())
+            .copied()
+            .collect();
+        let and_inputs = self.ands.iter().flat_map(|a| {
+            let node = &self.nodes[a.to_owned()];
+            [node.get_and_rhs0(), node.get_and_rhs1()]
+        });
+        let latch_inputs = self
+            .latches
+            .iter()
+            .map(|l| self.nodes[*l].get_latch_input());
+        let is_constant_used = roots
+            .iter()
+            .copied()
+            .chain(and_inputs)
+            .chain(latch_inputs)
+            .any(|literal| literal < 2);
+        if is_constant_used {
+            // a cover without cubes is the constant 0.
+            lines.push(format!(".names {}", variable_names[0]));
+        }
+        let value = |literal: usize| if (literal & 1) == 1 { '0' } else { '1' };
+        for and_index in self.ands.iter() {
+            let node = &self.nodes[and_index.to_owned()];
+            let (rhs0, rhs1) = (node.get_and_rhs0(), node.get_and_rhs1());
+            lines.push(format!(
+                ".names {} {} {}",
+                variable_names[rhs0 >> 1],
+                variable_names[rhs1 >> 1],
+                variable_names[and_index.to_owned()]
+            ));
+            lines.push(format!("{}{} 1", value(rhs0), value(rhs1)));
+        }
+        lines.append(&mut latch_next_covers);
+        for (literal, name) in roots.iter().zip(output_names.iter()) {
+            lines.append(&mut Self::get_blif_buffer_lines(
+                &variable_names,
+                literal.to_owned(),
+                name,
+            ));
+        }
+        lines.push(String::from(".end"));
+        lines.join("\n") + "\n"
+    }
+
+    /// Function that writes an AndInverterGraph into a '.blif' file, see `get_blif_string`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired for conversion.
+    /// * `file_path` - the path of the '.blif' file to write.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter.aig");
+    /// let file_path = std::env::temp_dir().join("counter_copy.blif");
+    /// let file_path = file_path.to_str().unwrap();
+    /// aig.write_blif_path(file_path);
+    /// let copy = AndInverterGraph::from_blif_path(file_path);
+    /// assert_eq!(aig.get_latch_information(), copy.get_latch_information());
+    /// ```
+    pub fn write_blif_path(&self, file_path: &str) {
+        fs::write(file_path, self.get_blif_string())
+            .unwrap_or_else(|_| panic!("Unable to write the '.blif' file {file_path}"));
+    }
+
+    /// Function that takes path to '.blif' file and creates a corresponding AndInverterGraph
+    /// object, see `from_blif_str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.blif' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter.aig");
+    /// let file_path = std::env::temp_dir().join("counter_from_path.blif");
+    /// let file_path = file_path.to_str().unwrap();
+    /// aig.write_blif_path(file_path);
+    /// let copy = AndInverterGraph::from_blif_path(file_path);
+    /// assert_eq!(aig.get_output_information().len(), copy.get_output_information().len());
+    /// ```
+    pub fn from_blif_path(file_path: &str) -> AndInverterGraph {
+        Self::try_from_blif_path(file_path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes path to '.blif' file and creates a corresponding AndInverterGraph
+    /// object, or reports why the file is not a valid '.blif' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.blif' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let missing = AndInverterGraph::try_from_blif_path("tests/examples/ours/missing.blif");
+    /// assert!(matches!(missing, Err(AigParseError::UnreadableFile { .. })));
+    /// ```
+    pub fn try_from_blif_path(file_path: &str) -> Result<AndInverterGraph, AigParseError> {
+        let file_as_vec_of_bytes = Self::read_file(file_path)?;
+        let blif = String::from_utf8_lossy(&file_as_vec_of_bytes);
+        Self::try_from_blif_str(&blif)
+    }
+
+    /// Function that takes the content of a '.blif' file and creates a corresponding
+    /// AndInverterGraph object.
+    /// Only the first model is read, and it may only use '.inputs', '.outputs', '.latch' and
+    /// '.names'. Every cover is converted into and gates, and latches that are initialized to
+    /// 2 or 3 (don't care or unknown) become uninitialized latches.
+    /// The outputs of the model become outputs of the AndInverterGraph, so they should be
+    /// considered bad when creating a FiniteStateTransitionSystem.
+    /// Inputs, latches and outputs get their BLIF names as symbols.
+    ///
+    /// # Arguments
+    ///
+    /// * `blif` - the content of the '.blif' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that becomes 1 once the input is 1, the output is 1 when the latch is 1.
+    /// let blif = ".model sticky\n.inputs x\n.outputs err\n.latch next s 0\n.names s x next\n1- 1\n-1 1\n.names s err\n1 1\n.end\n";
+    /// let aig = AndInverterGraph::from_blif_str(blif);
+    /// assert_eq!(
+    ///     aig.get_aag_string(),
+    ///     "aag 3 1 1 1 1\n2\n4 7\n4\n6 5 3\ni0 x\nl0 s\no0 err\n"
+    /// );
+    /// ```
+    pub fn from_blif_str(blif: &str) -> AndInverterGraph {
+        Self::try_from_blif_str(blif).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes the content of a '.blif' file and creates a corresponding
+    /// AndInverterGraph object, or reports why the content is not a valid '.blif' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `blif` - the content of the '.blif' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::try_from_blif_str(".model m\n.outputs o\n.names a o\n1 1\n");
+    /// assert_eq!(
+    ///     aig.err(),
+    ///     Some(AigParseError::InvalidLine {
+    ///         line: 3,
+    ///         reason: String::from("Signal a is never defined.")
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_blif_str(blif: &str) -> Result<AndInverterGraph, AigParseError> {
+        Self::from_blif_lines(&Self::get_blif_lines(blif))
+    }
+}
@@ -0,0 +1,774 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::{AIGNode, AIGNodeType};
+use crate::models::and_inverter_graph::{AigTraceMap, AndInverterGraph};
+use std::cmp::{max, min};
+use std::collections::HashMap;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that allows creating an AndInverterGraph without reading a file.
+///
+/// Literals follow the AIGER convention, literal 0 is the constant false, literal 1 is the
+/// constant true, and for every other literal `l`, `l ^ 1` is its negation.
+/// And gates are structurally hashed and constants are folded, so adding the same and gate
+/// twice returns the same literal.
+#[derive(Default, Clone)]
+pub struct AigBuilder {
+    // variable numbers in the order they were created, variable 0 is the constant.
+    number_of_variables: usize,
+    inputs: Vec<usize>,
+    latches: Vec<(usize, usize, Option<bool>)>,
+    ands: Vec<(usize, usize, usize)>,
+
+    // these contain literals.
+    outputs: Vec<usize>,
+    bad: Vec<usize>,
+    constraints: Vec<usize>,
+
+    // maps the inputs of an and gate (bigger literal first) to the literal of the gate.
+    and_gate_of_inputs: HashMap<(usize, usize), usize>,
+    // maps the variable of a latch to its position in the latches vector.
+    latch_position_of_variable: HashMap<usize, usize>,
+
+    // symbols of inputs and latches by variable, the rest are by position.
+    input_symbols: HashMap<usize, String>,
+    latch_symbols: HashMap<usize, String>,
+    output_symbols: Vec<String>,
+    bad_symbols: Vec<String>,
+    constraint_symbols: Vec<String>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AigBuilder {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn new_variable(&mut self) -> usize {
+        self.number_of_variables += 1;
+        self.number_of_variables
+    }
+
+    fn check_literal(&self, literal: usize) {
+        assert!(
+            (literal >> 1) <= self.number_of_variables,
+            "Literal {literal} was not created by this builder."
+        );
+    }
+
+    fn get_latch_position(&self, latch: usize) -> usize {
+        assert_eq!(latch & 1, 0, "Latch literal {latch} should not be negated.");
+        self.latch_position_of_variable
+            .get(&(latch >> 1))
+            .unwrap_or_else(|| panic!("Literal {latch} is not a latch."))
+            .to_owned()
+    }
+
+    /// AIGER requires the inputs to be the first variables, then the latches, and then the
+    /// and gates, this gives the new variable number of each variable.
+    fn get_variable_mapping(&self) -> Vec<usize> {
+        let mut variable_map = vec![0; self.number_of_variables + 1];
+        let latch_variables = self.latches.iter().map(|(variable, _, _)| variable);
+        let and_variables = self.ands.iter().map(|(variable, _, _)| variable);
+        let variables_in_order = self
+            .inputs
+            .iter()
+            .chain(latch_variables)
+            .chain(and_variables);
+        for (index, variable) in variables_in_order.enumerate() {
+            variable_map[variable.to_owned()] = index + 1;
+        }
+        variable_map
+    }
+
+    /// the literal that a literal of the builder gets in the graph returned by `build`.
+    pub(super) fn get_literal_in_built_graph(&self, literal: usize) -> usize {
+        self.check_literal(literal);
+        (self.get_variable_mapping()[literal >> 1] << 1) | (literal & 1)
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that creates an empty AigBuilder.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let aig = AigBuilder::new().build();
+    /// assert_eq!("aag 0 0 0 0 0\n", aig.get_aag_string());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Function that adds a new input and returns its literal.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// assert_eq!(2, builder.add_input());
+    /// assert_eq!(4, builder.add_input());
+    /// ```
+    pub fn add_input(&mut self) -> usize {
+        let variable = self.new_variable();
+        self.inputs.push(variable);
+        variable << 1
+    }
+
+    /// Function that adds a new latch and returns its literal.
+    /// Since the next state of a latch usually depends on the latch itself, it can be
+    /// changed later using `set_latch_next`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `next` - the literal that the latch gets in the next cycle.
+    /// * `reset` - the initial value of the latch, None if the latch is uninitialized.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let latch = builder.add_latch(0, Some(true));
+    /// builder.set_latch_next(latch, latch ^ 1);
+    /// let aig = builder.build();
+    /// assert_eq!(vec![(2, 3, 1)], aig.get_latch_information());
+    /// ```
+    pub fn add_latch(&mut self, next: usize, reset: Option<bool>) -> usize {
+        self.check_literal(next);
+        let variable = self.new_variable();
+        self.latch_position_of_variable
+            .insert(variable, self.latches.len());
+        self.latches.push((variable, next, reset));
+        variable << 1
+    }
+
+    /// Function that changes the next state of a latch that was already added.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `latch` - the literal returned by `add_latch`.
+    /// * `next` - the literal that the latch gets in the next cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let input = builder.add_input();
+    /// let latch = builder.add_latch(0, Some(false));
+    /// builder.set_latch_next(latch, input);
+    /// assert_eq!(vec![(4, 2, 0)], builder.build().get_latch_information());
+    /// ```
+    pub fn set_latch_next(&mut self, latch: usize, next: usize) {
+        self.check_literal(next);
+        let position = self.get_latch_position(latch);
+        self.latches[position].1 = next;
+    }
+
+    /// Function that changes the initial value of a latch that was already added.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `latch` - the literal returned by `add_latch`.
+    /// * `reset` - the initial value of the latch, None if the latch is uninitialized.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let latch = builder.add_latch(0, None);
+    /// builder.set_latch_reset(latch, Some(true));
+    /// assert_eq!(vec![(2, 0, 1)], builder.build().get_latch_information());
+    /// ```
+    pub fn set_latch_reset(&mut self, latch: usize, reset: Option<bool>) {
+        let position = self.get_latch_position(latch);
+        self.latches[position].2 = reset;
+    }
+
+    /// Function that returns the literal of the conjunction of two literals.
+    /// No gate is added if the result is a constant, one of the inputs, or an and gate that
+    /// already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `a` - the literal of the first input.
+    /// * `b` - the literal of the second input.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// let y = builder.add_input();
+    /// let x_and_y = builder.add_and(x, y);
+    /// assert_eq!(x_and_y, builder.add_and(y, x));
+    /// assert_eq!(x, builder.add_and(x, 1));
+    /// assert_eq!(0, builder.add_and(x, x ^ 1));
+    /// builder.add_output(x_and_y);
+    /// assert_eq!(1, builder.build().get_and_information_in_cone_of_influence(&[6]).len());
+    /// ```
+    pub fn add_and(&mut self, a: usize, b: usize) -> usize {
+        self.check_literal(a);
+        self.check_literal(b);
+        let (rhs0, rhs1) = (max(a, b), min(a, b));
+        if rhs1 == 0 || rhs0 == (rhs1 ^ 1) {
+            return 0;
+        } else if rhs1 == 1 || rhs0 == rhs1 {
+            return rhs0;
+        }
+
+        if let Some(literal) = self.and_gate_of_inputs.get(&(rhs0, rhs1)) {
+            return literal.to_owned();
+        }
+        let variable = self.new_variable();
+        self.ands.push((variable, rhs0, rhs1));
+        self.and_gate_of_inputs.insert((rhs0, rhs1), variable << 1);
+        variable << 1
+    }
+
+    /// Function that returns the literal of the disjunction of two literals, using a single
+    /// and gate with inverted inputs and output.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `a` - the literal of the first input.
+    /// * `b` - the literal of the second input.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// let y = builder.add_input();
+    /// assert_eq!(builder.add_and(x ^ 1, y ^ 1) ^ 1, builder.add_or(x, y));
+    /// assert_eq!(1, builder.add_or(x, x ^ 1));
+    /// ```
+    pub fn add_or(&mut self, a: usize, b: usize) -> usize {
+        self.add_and(a ^ 1, b ^ 1) ^ 1
+    }
+
+    /// Function that returns the literal of the exclusive or of two literals.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `a` - the literal of the first input.
+    /// * `b` - the literal of the second input.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// let y = builder.add_input();
+    /// assert_eq!(builder.add_xor(x, y), builder.add_xor(y, x));
+    /// assert_eq!(x ^ 1, builder.add_xor(x, 1));
+    /// assert_eq!(0, builder.add_xor(y, y));
+    /// ```
+    pub fn add_xor(&mut self, a: usize, b: usize) -> usize {
+        let only_a = self.add_and(a, b ^ 1);
+        let only_b = self.add_and(a ^ 1, b);
+        self.add_or(only_a, only_b)
+    }
+
+    /// Function that returns the literal that is `then_literal` when `condition` is true and
+    /// `else_literal` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `condition` - the literal that selects the result.
+    /// * `then_literal` - the result when the condition is true.
+    /// * `else_literal` - the result when the condition is false.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let c = builder.add_input();
+    /// let x = builder.add_input();
+    /// assert_eq!(x, builder.add_mux(1, x, c));
+    /// assert_eq!(x, builder.add_mux(c, x, x));
+    /// assert_eq!(c, builder.add_mux(c, 1, 0));
+    /// ```
+    pub fn add_mux(&mut self, condition: usize, then_literal: usize, else_literal: usize) -> usize {
+        if then_literal == else_literal {
+            return then_literal;
+        }
+        let when_true = self.add_and(condition, then_literal);
+        let when_false = self.add_and(condition ^ 1, else_literal);
+        self.add_or(when_true, when_false)
+    }
+
+    /// Function that adds an output to the AIG.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `literal` - the literal of the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// builder.add_output(x ^ 1);
+    /// assert_eq!(vec![3], builder.build().get_output_information());
+    /// ```
+    pub fn add_output(&mut self, literal: usize) {
+        self.check_literal(literal);
+        self.outputs.push(literal);
+        self.output_symbols.push(String::from(""));
+    }
+
+    /// Function that adds a bad state literal to the AIG.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `literal` - the literal that should never be true.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// builder.add_bad(x);
+    /// assert_eq!(vec![2], builder.build().get_bad_information());
+    /// ```
+    pub fn add_bad(&mut self, literal: usize) {
+        self.check_literal(literal);
+        self.bad.push(literal);
+        self.bad_symbols.push(String::from(""));
+    }
+
+    /// Function that adds an invariant constraint to the AIG.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `literal` - the literal that is assumed to be true in every cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// builder.add_constraint(x);
+    /// assert_eq!(vec![2], builder.build().get_constraints_information());
+    /// ```
+    pub fn add_constraint(&mut self, literal: usize) {
+        self.check_literal(literal);
+        self.constraints.push(literal);
+        self.constraint_symbols.push(String::from(""));
+    }
+
+    /// Function that gives a name to an input, the name appears in the symbol table.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `input` - the literal returned by `add_input`.
+    /// * `symbol` - the name of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// builder.set_input_symbol(x, "enable");
+    /// assert_eq!("aag 1 1 0 0 0\n2\ni0 enable\n", builder.build().get_aag_string());
+    /// ```
+    pub fn set_input_symbol(&mut self, input: usize, symbol: &str) {
+        assert_eq!(input & 1, 0, "Input literal {input} should not be negated.");
+        assert!(
+            self.inputs.binary_search(&(input >> 1)).is_ok(),
+            "Literal {input} is not an input."
+        );
+        self.input_symbols.insert(input >> 1, symbol.to_string());
+    }
+
+    /// Function that gives a name to a latch, the name appears in the symbol table.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `latch` - the literal returned by `add_latch`.
+    /// * `symbol` - the name of the latch.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let latch = builder.add_latch(0, Some(false));
+    /// builder.set_latch_symbol(latch, "state");
+    /// assert_eq!("aag 1 0 1 0 0\n2 0\nl0 state\n", builder.build().get_aag_string());
+    /// ```
+    pub fn set_latch_symbol(&mut self, latch: usize, symbol: &str) {
+        self.get_latch_position(latch);
+        self.latch_symbols.insert(latch >> 1, symbol.to_string());
+    }
+
+    /// Function that gives a name to an output, the name appears in the symbol table.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `index` - the position of the output, in the order they were added.
+    /// * `symbol` - the name of the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// builder.add_output(1);
+    /// builder.set_output_symbol(0, "always");
+    /// assert_eq!("aag 0 0 0 1 0\n1\no0 always\n", builder.build().get_aag_string());
+    /// ```
+    pub fn set_output_symbol(&mut self, index: usize, symbol: &str) {
+        self.output_symbols[index] = symbol.to_string();
+    }
+
+    /// Function that gives a name to a bad state literal, the name appears in the symbol table.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `index` - the position of the bad state literal, in the order they were added.
+    /// * `symbol` - the name of the bad state literal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// builder.add_bad(x);
+    /// builder.set_bad_symbol(0, "error");
+    /// assert_eq!("aag 1 1 0 0 0 1\n2\n2\nb0 error\n", builder.build().get_aag_string());
+    /// ```
+    pub fn set_bad_symbol(&mut self, index: usize, symbol: &str) {
+        self.bad_symbols[index] = symbol.to_string();
+    }
+
+    /// Function that gives a name to an invariant constraint, the name appears in the symbol
+    /// table.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - the AigBuilder desired.
+    /// * `index` - the position of the constraint, in the order they were added.
+    /// * `symbol` - the name of the constraint.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let x = builder.add_input();
+    /// builder.add_constraint(x);
+    /// builder.set_constraint_symbol(0, "assume");
+    /// assert_eq!("aag 1 1 0 0 0 0 1\n2\n2\nc0 assume\n", builder.build().get_aag_string());
+    /// ```
+    pub fn set_constraint_symbol(&mut self, index: usize, symbol: &str) {
+        self.constraint_symbols[index] = symbol.to_string();
+    }
+
+    /// Function that creates the AndInverterGraph.
+    /// The variables are renumbered such that inputs come first, then latches, and then the
+    /// and gates in the order they were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AigBuilder desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigBuilder;
+    /// let mut builder = AigBuilder::new();
+    /// let latch = builder.add_latch(0, Some(false));
+    /// let x = builder.add_input();
+    /// let next = builder.add_and(latch ^ 1, x);
+    /// builder.set_latch_next(latch, next);
+    /// builder.add_bad(latch);
+    /// assert_eq!("aag 3 1 1 0 1 1\n2\n4 6\n4\n6 5 2\n", builder.build().get_aag_string());
+    /// ```
+    pub fn build(&self) -> AndInverterGraph {
+        let variable_map = self.get_variable_mapping();
+        let map_literal = |lit: usize| (variable_map[lit >> 1] << 1) | (lit & 1);
+
+        let mut aig = AndInverterGraph::new();
+        aig.maximum_variable_index = self.number_of_variables;
+        aig.number_of_inputs = self.inputs.len();
+        aig.number_of_latches = self.latches.len();
+        aig.number_of_outputs = self.outputs.len();
+        aig.number_of_and_gates = self.ands.len();
+        aig.number_of_bad_state_constraints = self.bad.len();
+        aig.number_of_invariant_constraints = self.constraints.len();
+        aig.number_of_justice_constraints = 0;
+        aig.number_of_fairness_constraints = 0;
+
+        aig.nodes = Vec::with_capacity(self.number_of_variables + 1);
+        aig.nodes.push(AIGNode::new(0, AIGNodeType::ConstantZero));
+        for variable in self.inputs.iter() {
+            let mut node = AIGNode::new(aig.nodes.len() << 1, AIGNodeType::Input);
+            if let Some(symbol) = self.input_symbols.get(variable) {
+                node.set_input_symbol(symbol);
+            }
+            aig.inputs.push(aig.nodes.len());
+            aig.nodes.push(node);
+        }
+        for (variable, next, reset) in self.latches.iter() {
+            let literal = aig.nodes.len() << 1;
+            let mut node = AIGNode::new(literal, AIGNodeType::Latch);
+            if let Some(symbol) = self.latch_symbols.get(variable) {
+                node.set_latch_symbol(symbol);
+            }
+            node.set_input_of_latch(map_literal(next.to_owned()));
+            node.set_reset_of_latch(match reset {
+                Some(value) => usize::from(value.to_owned()),
+                None => literal,
+            });
+            aig.latches.push(aig.nodes.len());
+            aig.nodes.push(node);
+        }
+        for (_, rhs0, rhs1) in self.ands.iter() {
+            let mut node = AIGNode::new(aig.nodes.len() << 1, AIGNodeType::And);
+            let rhs0 = map_literal(rhs0.to_owned());
+            let rhs1 = map_literal(rhs1.to_owned());
+            node.set_rhs0_of_and(max(rhs0, rhs1));
+            node.set_rhs1_of_and(min(rhs0, rhs1));
+            aig.ands.push(aig.nodes.len());
+            aig.nodes.push(node);
+        }
+
+        aig.outputs = self.outputs.iter().map(|l| map_literal(*l)).collect();
+        aig.bad = self.bad.iter().map(|l| map_literal(*l)).collect();
+        aig.constraints = self.constraints.iter().map(|l| map_literal(*l)).collect();
+        for (literal, symbol) in aig.outputs.iter().zip(self.output_symbols.iter()) {
+            if !symbol.is_empty() {
+                aig.nodes[literal >> 1].set_output_symbol(symbol);
+            }
+        }
+        for (literal, symbol) in aig.bad.iter().zip(self.bad_symbols.iter()) {
+            if !symbol.is_empty() {
+                aig.nodes[literal >> 1].set_bad_symbol(symbol);
+            }
+        }
+        for (literal, symbol) in aig.constraints.iter().zip(self.constraint_symbols.iter()) {
+            if !symbol.is_empty() {
+                aig.nodes[literal >> 1].set_constraint_symbol(symbol);
+            }
+        }
+
+        debug_assert!(aig.check_aig().is_ok());
+        aig
+    }
+}
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// panics if the graph has justice properties or fairness constraints, since the
+    /// transformation named `transformation` cannot keep them.
+    pub(super) fn assert_no_justice_or_fairness(&self, transformation: &str) {
+        assert!(
+            self.justice.is_empty() && self.fairness.is_empty(),
+            "{transformation} does not support justice properties or fairness constraints."
+        );
+    }
+
+    /// the literal of the copy of a literal, given the literal of the copy of every variable.
+    pub(super) fn map_literal(literal_map: &[usize], lit: usize) -> usize {
+        literal_map[lit >> 1] ^ (lit & 1)
+    }
+
+    /// copies the and gates of the graph to the builder, given the literals of its inputs
+    /// and latches by position, and returns the literal of every variable of the graph.
+    pub(super) fn get_literals_of_copy(
+        &self,
+        builder: &mut AigBuilder,
+        input_literals: &[usize],
+        latch_literals: &[usize],
+    ) -> Vec<usize> {
+        let mut literal_map = vec![0; self.nodes.len()];
+        for (input_index, literal) in self.inputs.iter().zip(input_literals.iter()) {
+            literal_map[input_index.to_owned()] = literal.to_owned();
+        }
+        for (latch_index, literal) in self.latches.iter().zip(latch_literals.iter()) {
+            literal_map[latch_index.to_owned()] = literal.to_owned();
+        }
+        self.add_copies_of_and_gates(builder, &mut literal_map, |_| true);
+        literal_map
+    }
+
+    /// the initial value of a latch of the graph, None if it is uninitialized.
+    pub(super) fn get_latch_initial_value(&self, latch_index: usize) -> Option<bool> {
+        match self.nodes[latch_index].get_latch_reset() {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    /// adds a latch with the symbol of a latch of the graph to the builder, its next state
+    /// is set later.
+    pub(super) fn add_copy_of_latch(
+        &self,
+        builder: &mut AigBuilder,
+        latch_index: usize,
+        reset: Option<bool>,
+    ) -> usize {
+        let latch = builder.add_latch(0, reset);
+        let symbol = self.nodes[latch_index].get_latch_symbol();
+        if !symbol.is_empty() {
+            builder.set_latch_symbol(latch, symbol);
+        }
+        latch
+    }
+
+    /// adds the latches of the graph to the builder with their initial values and symbols,
+    /// their next states are set later.
+    pub(super) fn add_copies_of_latches(
+        &self,
+        builder: &mut AigBuilder,
+        trace_map: &mut AigTraceMap,
+    ) -> Vec<usize> {
+        self.latches
+            .iter()
+            .enumerate()
+            .map(|(position, latch_index)| {
+                let reset = self.get_latch_initial_value(latch_index.to_owned());
+                trace_map.add_initial_latch_from_latch(reset, position);
+                self.add_copy_of_latch(builder, latch_index.to_owned(), reset)
+            })
+            .collect()
+    }
+
+    /// adds a copy of the inputs of the graph to the builder, the symbols get a suffix.
+    pub(super) fn add_copies_of_inputs(
+        &self,
+        builder: &mut AigBuilder,
+        suffix: &str,
+    ) -> Vec<usize> {
+        self.inputs
+            .iter()
+            .map(|input_index| {
+                let input = builder.add_input();
+                let symbol = self.nodes[input_index.to_owned()].get_input_symbol();
+                if !symbol.is_empty() {
+                    builder.set_input_symbol(input, &format!("{symbol}{suffix}"));
+                }
+                input
+            })
+            .collect()
+    }
+
+    /// adds the inputs and latches of the graph that are kept to the builder with their
+    /// initial values and symbols, and returns the literal of every variable of the graph,
+    /// which is 0 for the variables that are not copied.
+    pub(super) fn get_literal_map_with_copies(
+        &self,
+        builder: &mut AigBuilder,
+        is_kept: impl Fn(usize) -> bool,
+    ) -> Vec<usize> {
+        let mut literal_map = vec![0; self.nodes.len()];
+        for input_index in self.inputs.iter().filter(|i| is_kept(**i)) {
+            let input = builder.add_input();
+            let symbol = self.nodes[input_index.to_owned()].get_input_symbol();
+            if !symbol.is_empty() {
+                builder.set_input_symbol(input, symbol);
+            }
+            literal_map[input_index.to_owned()] = input;
+        }
+        for latch_index in self.latches.iter().filter(|i| is_kept(**i)) {
+            let reset = self.get_latch_initial_value(latch_index.to_owned());
+            literal_map[latch_index.to_owned()] =
+                self.add_copy_of_latch(builder, latch_index.to_owned(), reset);
+        }
+        literal_map
+    }
+
+    /// adds the and gates of the graph that are kept to the builder, their inputs must
+    /// already be in the literal map.
+    pub(super) fn add_copies_of_and_gates(
+        &self,
+        builder: &mut AigBuilder,
+        literal_map: &mut [usize],
+        is_kept: impl Fn(usize) -> bool,
+    ) {
+        for (variable, node) in self.nodes.iter().enumerate() {
+            if is_kept(variable) && node.get_type() == AIGNodeType::And {
+                let rhs0 = Self::map_literal(literal_map, node.get_and_rhs0());
+                let rhs1 = Self::map_literal(literal_map, node.get_and_rhs1());
+                literal_map[variable] = builder.add_and(rhs0, rhs1);
+            }
+        }
+    }
+
+    /// sets the next state of the latches of the graph that are kept, using the literal map.
+    pub(super) fn set_next_of_copied_latches(
+        &self,
+        builder: &mut AigBuilder,
+        literal_map: &[usize],
+        is_kept: impl Fn(usize) -> bool,
+    ) {
+        for latch_index in self.latches.iter().filter(|i| is_kept(**i)) {
+            let next = self.nodes[latch_index.to_owned()].get_latch_input();
+            let next = Self::map_literal(literal_map, next);
+            builder.set_latch_next(literal_map[latch_index.to_owned()], next);
+        }
+    }
+
+    /// adds the outputs, bad state literals and invariant constraints of the graph to the
+    /// builder with their symbols, their literals are given by `map_literal`.
+    pub(super) fn add_copies_of_outputs_bad_and_constraints(
+        &self,
+        builder: &mut AigBuilder,
+        mut map_literal: impl FnMut(&mut AigBuilder, usize) -> usize,
+    ) {
+        for (index, output_literal) in self.outputs.iter().enumerate() {
+            let output = map_literal(builder, output_literal.to_owned());
+            builder.add_output(output);
+            let symbol = self.nodes[output_literal >> 1].get_output_symbol();
+            if !symbol.is_empty() {
+                builder.set_output_symbol(index, symbol);
+            }
+        }
+        for (index, bad_literal) in self.bad.iter().enumerate() {
+            let bad = map_literal(builder, bad_literal.to_owned());
+            builder.add_bad(bad);
+            let symbol = self.nodes[bad_literal >> 1].get_bad_symbol();
+            if !symbol.is_empty() {
+                builder.set_bad_symbol(index, symbol);
+            }
+        }
+        for (index, constraint_literal) in self.constraints.iter().enumerate() {
+            let constraint = map_literal(builder, constraint_literal.to_owned());
+            builder.add_constraint(constraint);
+            let symbol = self.nodes[constraint_literal >> 1].get_constraint_symbol();
+            if !symbol.is_empty() {
+                builder.set_constraint_symbol(index, symbol);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::{AigBuilder, AndInverterGraph};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// marks every variable that can affect the desired literals in any cycle, meaning that
+    /// and gates are followed to their inputs and latches are followed to their next state.
+    pub(super) fn get_variables_in_sequential_cone_of_influence(
+        &self,
+        desired_literals: &[usize],
+    ) -> Vec<bool> {
+        let mut is_in_cone = vec![false; self.nodes.len()];
+        let mut variables_to_visit: Vec<usize> = desired_literals.iter().map(|l| l >> 1).collect();
+        while let Some(variable) = variables_to_visit.pop() {
+            if is_in_cone[variable] {
+                continue;
+            }
+            is_in_cone[variable] = true;
+            let node = &self.nodes[variable];
+            match node.get_type() {
+                AIGNodeType::ConstantZero => {}
+                AIGNodeType::Input => {}
+                AIGNodeType::Latch => {
+                    // the reset is either a constant or the latch itself.
+                    variables_to_visit.push(node.get_latch_input() >> 1);
+                }
+                AIGNodeType::And => {
+                    variables_to_visit.push(node.get_and_rhs0() >> 1);
+                    variables_to_visit.push(node.get_and_rhs1() >> 1);
+                }
+            }
+        }
+        is_in_cone
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that creates a new AndInverterGraph that contains only the inputs, latches
+    /// and and gates that can affect the selected bad state literals or the invariant
+    /// constraints.
+    /// All of the invariant constraints are kept since they restrict every property.
+    /// Outputs, justice properties and fairness constraints are not kept.
+    /// The variables are renumbered and the symbols of the remaining nodes are preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `property_indices` - the positions of the desired bad state literals.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // two independent latches, each one with its own bad state literal.
+    /// let aag = "aag 3 1 2 0 0 2\n2\n4 2\n6 7\n4\n6\ni0 x\nl1 toggle\nb1 toggle is 1\n";
+    /// let aig = AndInverterGraph::from_aag_str(aag);
+    /// let reduced = aig.coi_reduce(&[1]);
+    /// assert_eq!("aag 1 0 1 0 0 1\n2 3\n2\nl0 toggle\nb0 toggle is 1\n", reduced.get_aag_string());
+    /// ```
+    pub fn coi_reduce(&self, property_indices: &[usize]) -> AndInverterGraph {
+        let mut desired_literals: Vec<usize> = property_indices
+            .iter()
+            .map(|i| {
+                assert!(i < &self.bad.len(), "There is no bad state literal {i}.");
+                self.bad[i.to_owned()]
+            })
+            .collect();
+        desired_literals.extend(self.constraints.iter());
+        let is_in_cone = self.get_variables_in_sequential_cone_of_influence(&desired_literals);
+
+        let mut builder = AigBuilder::new();
+        let is_kept = |variable: usize| is_in_cone[variable];
+        // maps each variable in the cone to its literal in the builder.
+        let mut literal_map = self.get_literal_map_with_copies(&mut builder, is_kept);
+        self.add_copies_of_and_gates(&mut builder, &mut literal_map, is_kept);
+        self.set_next_of_copied_latches(&mut builder, &literal_map, is_kept);
+
+        for (index, i) in property_indices.iter().enumerate() {
+            let bad_literal = self.bad[i.to_owned()];
+            builder.add_bad(Self::map_literal(&literal_map, bad_literal));
+            let symbol = self.nodes[bad_literal >> 1].get_bad_symbol();
+            if !symbol.is_empty() {
+                builder.set_bad_symbol(index, symbol);
+            }
+        }
+        for (index, constraint_literal) in self.constraints.iter().enumerate() {
+            builder.add_constraint(Self::map_literal(
+                &literal_map,
+                constraint_literal.to_owned(),
+            ));
+            let symbol = self.nodes[constraint_literal >> 1].get_constraint_symbol();
+            if !symbol.is_empty() {
+                builder.set_constraint_symbol(index, symbol);
+            }
+        }
+        builder.build()
+    }
+}
@@ -3,7 +3,7 @@
 // ************************************************************************************************
 
 use crate::models::and_inverter_graph::aig_node::{AIGNode, AIGNodeType};
-use crate::models::and_inverter_graph::AndInverterGraph;
+use crate::models::and_inverter_graph::{AigParseError, AndInverterGraph};
 use std::fs;
 
 // ************************************************************************************************
@@ -32,8 +32,8 @@ impl AndInverterGraph {
         result
     }
 
-    // Function is private to not allow accidental creation of some random AIG.
-    fn new() -> Self {
+    // Function is private to not allow accidental creation of some random AIG, AigBuilder uses it.
+    pub(super) fn new() -> Self {
         Self {
             /// these fields must be changed later, set them to max to notice if there is a bug
             maximum_variable_index: usize::MAX,
@@ -54,69 +54,153 @@ impl AndInverterGraph {
             ands: Vec::new(),
             bad: Vec::new(),
             constraints: Vec::new(),
-            comments: String::from(""),
-            // justice: Vec::new(),
-            // fairness: Vec::new(),
+            justice: Vec::new(),
+            fairness: Vec::new(),
+            justice_symbols: Vec::new(),
+            comments: Vec::new(),
         }
     }
 
-    fn convert_string_to_number(str1: &str) -> usize {
-        str1.parse::<usize>().unwrap()
+    fn convert_string_to_number(
+        str1: &str,
+        line_number_from_1: usize,
+    ) -> Result<usize, AigParseError> {
+        str1.parse::<usize>()
+            .map_err(|_| AigParseError::InvalidLine {
+                line: line_number_from_1,
+                reason: format!("'{str1}' is not a valid number."),
+            })
     }
 
-    fn check_first_line_of_aig_and_load_it(&mut self, lines: &[Vec<u8>]) {
-        let first_line_as_str = std::str::from_utf8(&lines[0]).unwrap();
+    fn get_line_as_str(
+        lines: &[Vec<u8>],
+        line_number_from_0: usize,
+    ) -> Result<&str, AigParseError> {
+        let line_number_from_1 = line_number_from_0 + 1;
+        let line_as_vector_of_chars =
+            lines
+                .get(line_number_from_0)
+                .ok_or(AigParseError::InvalidLine {
+                    line: line_number_from_1,
+                    reason: String::from("Unexpected end of file."),
+                })?;
+        std::str::from_utf8(line_as_vector_of_chars).map_err(|_| AigParseError::InvalidLine {
+            line: line_number_from_1,
+            reason: String::from("Line is not valid UTF-8."),
+        })
+    }
+
+    fn get_line_as_numbers(
+        lines: &[Vec<u8>],
+        line_number_from_0: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        let line_number_from_1 = line_number_from_0 + 1;
+        Self::get_line_as_str(lines, line_number_from_0)?
+            .split(' ')
+            .map(|s| Self::convert_string_to_number(s, line_number_from_1))
+            .collect()
+    }
+
+    fn check_first_line_of_aig_and_load_it(
+        &mut self,
+        lines: &[Vec<u8>],
+        format: &str,
+    ) -> Result<(), AigParseError> {
+        let header_error = |reason: String| AigParseError::InvalidLine { line: 1, reason };
+        let first_line_as_str = Self::get_line_as_str(lines, 0)?;
         let params: Vec<&str> = first_line_as_str.split(' ').collect();
 
-        // check if the input file format is correct (starts with aig)
-        assert_eq!(
-            params[0], "aig",
-            "The parameter line (first line in aig file) must start with the word 'aig'."
-        );
-        assert!(
-            params.len() > 5,
-            "The parameter line (first line in aig file) has too few arguments."
-        );
-
-        assert!(
-            params.len() < 10,
-            "The parameter line (first line in aig file) has too many arguments."
-        );
+        // check if the input file format is correct (starts with aig or aag)
+        if params[0] != format {
+            return Err(header_error(format!(
+                "The parameter line (first line in {format} file) must start with the word '{format}'."
+            )));
+        }
+        if params.len() <= 5 {
+            return Err(header_error(format!(
+                "The parameter line (first line in {format} file) has too few arguments."
+            )));
+        }
+        if params.len() >= 11 {
+            return Err(header_error(format!(
+                "The parameter line (first line in {format} file) has too many arguments."
+            )));
+        }
 
         // first 5 fields always exist
-        self.maximum_variable_index = Self::convert_string_to_number(params[1]);
-        self.number_of_inputs = Self::convert_string_to_number(params[2]);
-        self.number_of_latches = Self::convert_string_to_number(params[3]);
-        self.number_of_outputs = Self::convert_string_to_number(params[4]);
-        self.number_of_and_gates = Self::convert_string_to_number(params[5]);
+        self.maximum_variable_index = Self::convert_string_to_number(params[1], 1)?;
+        self.number_of_inputs = Self::convert_string_to_number(params[2], 1)?;
+        self.number_of_latches = Self::convert_string_to_number(params[3], 1)?;
+        self.number_of_outputs = Self::convert_string_to_number(params[4], 1)?;
+        self.number_of_and_gates = Self::convert_string_to_number(params[5], 1)?;
 
         // these fields do not always exist
         self.number_of_bad_state_constraints =
-            Self::convert_string_to_number(params.get(6).unwrap_or(&"0"));
+            Self::convert_string_to_number(params.get(6).unwrap_or(&"0"), 1)?;
         self.number_of_invariant_constraints =
-            Self::convert_string_to_number(params.get(7).unwrap_or(&"0"));
+            Self::convert_string_to_number(params.get(7).unwrap_or(&"0"), 1)?;
         self.number_of_justice_constraints =
-            Self::convert_string_to_number(params.get(8).unwrap_or(&"0"));
+            Self::convert_string_to_number(params.get(8).unwrap_or(&"0"), 1)?;
         self.number_of_fairness_constraints =
-            Self::convert_string_to_number(params.get(9).unwrap_or(&"0"));
-
-        assert_eq!(
-            self.maximum_variable_index,
-            self.number_of_inputs + self.number_of_latches + self.number_of_and_gates,
-            "The number of variables does not add up."
-        );
-        assert_eq!(
-            self.number_of_fairness_constraints, 0,
-            "Fairness is currently unsupported."
-        );
-        assert_eq!(
-            self.number_of_justice_constraints, 0,
-            "Justice is currently unsupported."
-        );
-    }
-
-    fn allocate_vectors(&mut self) {
-        self.nodes = Vec::with_capacity(self.maximum_variable_index + 1);
+            Self::convert_string_to_number(params.get(9).unwrap_or(&"0"), 1)?;
+
+        let number_of_variables = self
+            .number_of_inputs
+            .checked_add(self.number_of_latches)
+            .and_then(|n| n.checked_add(self.number_of_and_gates));
+        if number_of_variables != Some(self.maximum_variable_index) {
+            return Err(header_error(String::from(
+                "The number of variables does not add up.",
+            )));
+        }
+        if self.maximum_variable_index >= usize::MAX >> 2 {
+            return Err(header_error(String::from(
+                "The maximum variable index is too large.",
+            )));
+        }
+
+        // every latch, output, bad state, constraint, justice and fairness takes at least one
+        // line, and so do the inputs and and gates of the ascii format.
+        let mut counts_of_lines = vec![
+            self.number_of_latches,
+            self.number_of_outputs,
+            self.number_of_bad_state_constraints,
+            self.number_of_invariant_constraints,
+            self.number_of_justice_constraints,
+            self.number_of_fairness_constraints,
+        ];
+        if format == "aag" {
+            counts_of_lines.push(self.number_of_inputs);
+            counts_of_lines.push(self.number_of_and_gates);
+        }
+        let number_of_lines = counts_of_lines
+            .iter()
+            .fold(0_usize, |sum, count| sum.saturating_add(*count));
+        if number_of_lines >= lines.len() {
+            return Err(header_error(String::from(
+                "The file is too short for the parameter line.",
+            )));
+        }
+        // the and gates of the binary format take at least two bytes each.
+        let number_of_bytes: usize = lines[1..].iter().map(|line| line.len() + 1).sum();
+        if format == "aig" && self.number_of_and_gates.saturating_mul(2) > number_of_bytes {
+            return Err(header_error(String::from(
+                "The file is too short for the parameter line.",
+            )));
+        }
+        Ok(())
+    }
+
+    /// the inputs of the binary format take no space in the file, so the nodes are reserved
+    /// without aborting when the header asks for too many of them.
+    fn allocate_vectors(&mut self) -> Result<(), AigParseError> {
+        self.nodes = Vec::new();
+        self.nodes
+            .try_reserve_exact(self.maximum_variable_index + 1)
+            .map_err(|_| AigParseError::InvalidLine {
+                line: 1,
+                reason: String::from("There is not enough memory for the number of variables."),
+            })?;
         self.nodes.push(AIGNode::new(0, AIGNodeType::ConstantZero));
 
         self.inputs = Vec::with_capacity(self.number_of_inputs);
@@ -125,8 +209,10 @@ impl AndInverterGraph {
         self.ands = Vec::with_capacity(self.number_of_and_gates);
         self.bad = Vec::with_capacity(self.number_of_bad_state_constraints);
         self.constraints = Vec::with_capacity(self.number_of_invariant_constraints);
-        // self.justice = Vec::with_capacity(self.number_of_justice_constraints);
-        // self.fairness = Vec::with_capacity(self.number_of_fairness_constraints);
+        self.justice = Vec::with_capacity(self.number_of_justice_constraints);
+        self.fairness = Vec::with_capacity(self.number_of_fairness_constraints);
+        self.justice_symbols = vec![String::from(""); self.number_of_justice_constraints];
+        Ok(())
     }
 
     /// notice that this function does not need to read from the file since in AIG
@@ -140,111 +226,201 @@ impl AndInverterGraph {
         }
     }
 
-    fn check_literal(&self, literal_number: usize, line_num: usize) {
+    fn check_literal(&self, literal_number: usize, line_num: usize) -> Result<(), AigParseError> {
         let var_number = literal_number >> 1;
         // assert!(2 <= literal_number, "Line {line_num}: '.aig' file contains literal {literal_number} which is reserved for constants.");
-        assert!(var_number <= self.maximum_variable_index, "Line {line_num}: '.aig' file contains literal {literal_number} which is higher than maximum variable index.");
+        if var_number > self.maximum_variable_index {
+            return Err(AigParseError::InvalidLine {
+                line: line_num,
+                reason: format!(
+                    "File contains literal {literal_number} which is higher than maximum variable index."
+                ),
+            });
+        }
+        Ok(())
     }
 
-    fn create_latch_nodes_of_aig(&mut self, lines: &[Vec<u8>]) {
+    fn check_reset_of_latch(
+        lit: usize,
+        reset: usize,
+        line_number_from_1: usize,
+    ) -> Result<(), AigParseError> {
+        if reset == 0 || reset == 1 || reset == lit {
+            Ok(())
+        } else {
+            Err(AigParseError::InvalidLine {
+                line: line_number_from_1,
+                reason: String::from(
+                    "Latch reset may be 0, 1, or equal to literal designated for latch.",
+                ),
+            })
+        }
+    }
+
+    fn create_latch_nodes_of_aig(&mut self, lines: &[Vec<u8>]) -> Result<(), AigParseError> {
         for i in 0..self.number_of_latches {
             // latch literal is known because this is the binary AIGER format.
             let lit = 2 * (i + self.number_of_inputs + 1);
-            self.latches.push(self.nodes.len());
-            self.nodes.push(AIGNode::new(lit, AIGNodeType::Latch));
+            let mut node = AIGNode::new(lit, AIGNodeType::Latch);
 
             let line_number_from_0: usize = i + 1;
             let line_number_from_1: usize = line_number_from_0 + 1;
-            let line_as_vector_of_chars = &lines[line_number_from_0];
-            let line_as_string = std::str::from_utf8(line_as_vector_of_chars).unwrap();
-
-            let parsed_line: Vec<&str> = line_as_string.split(' ').collect();
-            assert!(
-                parsed_line.len() == 1 || parsed_line.len() == 2,
-                "Line {line_number_from_1}: Wrong number of arguments for latch line."
-            );
+            let parsed_line = Self::get_line_as_numbers(lines, line_number_from_0)?;
+            if parsed_line.len() != 1 && parsed_line.len() != 2 {
+                return Err(AigParseError::InvalidLine {
+                    line: line_number_from_1,
+                    reason: String::from("Wrong number of arguments for latch line."),
+                });
+            }
 
-            let next_lit = Self::convert_string_to_number(parsed_line[0]);
-            self.check_literal(next_lit, line_number_from_1);
-            self.nodes.last_mut().unwrap().set_input_of_latch(next_lit);
+            let next_lit = parsed_line[0];
+            self.check_literal(next_lit, line_number_from_1)?;
+            node.set_input_of_latch(next_lit);
 
             if parsed_line.len() == 2 {
                 // latch has a reset literal
-                let reset = Self::convert_string_to_number(parsed_line[1]);
-                assert!(
-                    (reset == 0 || reset == 1 || reset == lit),
-                    "Line {line_number_from_1}: Latch reset may be 0, 1, or equal to literal designated for latch."
-                );
-                self.nodes.last_mut().unwrap().set_reset_of_latch(reset);
+                let reset = parsed_line[1];
+                Self::check_reset_of_latch(lit, reset, line_number_from_1)?;
+                node.set_reset_of_latch(reset);
             } else {
                 // latch does not have a reset literal (defaults to 0)
                 // https://epub.jku.at/obvulioa/content/titleinfo/5973560/full.pdf
-                self.nodes.last_mut().unwrap().set_reset_of_latch(0);
+                node.set_reset_of_latch(0);
             }
+
+            self.latches.push(self.nodes.len());
+            self.nodes.push(node);
         }
+        Ok(())
     }
 
-    fn create_output_nodes_of_aig(&mut self, lines: &[Vec<u8>]) {
-        for i in 0..self.number_of_outputs {
-            let line_number_from_0: usize = i + 1 + self.number_of_latches;
+    fn read_literal_lines(
+        &self,
+        lines: &[Vec<u8>],
+        first_line_from_0: usize,
+        amount: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        let mut result = Vec::with_capacity(amount);
+        for i in 0..amount {
+            let line_number_from_0: usize = i + first_line_from_0;
             let line_number_from_1: usize = line_number_from_0 + 1;
 
-            let line_as_vector_of_chars = &lines[line_number_from_0];
-            let line_as_string = std::str::from_utf8(line_as_vector_of_chars).unwrap();
-
-            let output_literal = Self::convert_string_to_number(line_as_string);
-            self.check_literal(output_literal, line_number_from_1);
-            self.outputs.push(output_literal);
+            let line_as_string = Self::get_line_as_str(lines, line_number_from_0)?;
+            let literal = Self::convert_string_to_number(line_as_string, line_number_from_1)?;
+            self.check_literal(literal, line_number_from_1)?;
+            result.push(literal);
         }
+        Ok(result)
     }
 
-    fn create_bad_nodes_of_aig(&mut self, lines: &[Vec<u8>]) {
-        // println!("Bad:");
-        for i in 0..self.number_of_bad_state_constraints {
-            let line_number_from_0: usize = i + 1 + self.number_of_latches + self.number_of_outputs;
-            let line_number_from_1: usize = line_number_from_0 + 1;
-
-            let line_as_vector_of_chars = &lines[line_number_from_0];
-            let line_as_string = std::str::from_utf8(line_as_vector_of_chars).unwrap();
+    fn create_output_nodes_of_aig(
+        &mut self,
+        lines: &[Vec<u8>],
+        input_lines: usize,
+    ) -> Result<(), AigParseError> {
+        let first_line_from_0 = 1 + input_lines + self.number_of_latches;
+        self.outputs = self.read_literal_lines(lines, first_line_from_0, self.number_of_outputs)?;
+        Ok(())
+    }
 
-            let bad_literal = Self::convert_string_to_number(line_as_string);
-            // println!("{bad_literal}");
-            self.check_literal(bad_literal, line_number_from_1);
-            self.bad.push(bad_literal);
-        }
+    fn create_bad_nodes_of_aig(
+        &mut self,
+        lines: &[Vec<u8>],
+        input_lines: usize,
+    ) -> Result<(), AigParseError> {
+        let first_line_from_0 = 1 + input_lines + self.number_of_latches + self.number_of_outputs;
+        self.bad = self.read_literal_lines(
+            lines,
+            first_line_from_0,
+            self.number_of_bad_state_constraints,
+        )?;
+        Ok(())
     }
 
-    fn create_invariant_constraint_nodes_of_aig(&mut self, lines: &[Vec<u8>]) {
-        for i in 0..self.number_of_invariant_constraints {
-            let line_number_from_0: usize = i
-                + 1
-                + self.number_of_latches
-                + self.number_of_outputs
-                + self.number_of_bad_state_constraints;
-            let line_number_from_1: usize = line_number_from_0 + 1;
+    fn create_invariant_constraint_nodes_of_aig(
+        &mut self,
+        lines: &[Vec<u8>],
+        input_lines: usize,
+    ) -> Result<(), AigParseError> {
+        let first_line_from_0 = 1
+            + input_lines
+            + self.number_of_latches
+            + self.number_of_outputs
+            + self.number_of_bad_state_constraints;
+        self.constraints = self.read_literal_lines(
+            lines,
+            first_line_from_0,
+            self.number_of_invariant_constraints,
+        )?;
+        Ok(())
+    }
 
-            let line_as_vector_of_chars = &lines[line_number_from_0];
-            let line_as_string = std::str::from_utf8(line_as_vector_of_chars).unwrap();
+    fn get_number_of_lines_before_justice(&self, input_lines: usize) -> usize {
+        1 + input_lines
+            + self.number_of_latches
+            + self.number_of_outputs
+            + self.number_of_bad_state_constraints
+            + self.number_of_invariant_constraints
+    }
 
-            let inv_const_literal = Self::convert_string_to_number(line_as_string);
-            self.check_literal(inv_const_literal, line_number_from_1);
-            self.constraints.push(inv_const_literal);
+    fn create_justice_nodes_of_aig(
+        &mut self,
+        lines: &[Vec<u8>],
+        input_lines: usize,
+    ) -> Result<(), AigParseError> {
+        // the sizes of all justice properties come first, then the literals of each one in turn.
+        let first_line_from_0 = self.get_number_of_lines_before_justice(input_lines);
+        let mut next_line_from_0 = first_line_from_0 + self.number_of_justice_constraints;
+        for i in 0..self.number_of_justice_constraints {
+            let line_number_from_0 = first_line_from_0 + i;
+            let line_as_string = Self::get_line_as_str(lines, line_number_from_0)?;
+            let size = Self::convert_string_to_number(line_as_string, line_number_from_0 + 1)?;
+            if size == 0 {
+                return Err(AigParseError::InvalidLine {
+                    line: line_number_from_0 + 1,
+                    reason: String::from("Justice property must contain at least one literal."),
+                });
+            }
+            let justice_property = self.read_literal_lines(lines, next_line_from_0, size)?;
+            self.justice.push(justice_property);
+            next_line_from_0 += size;
         }
+        Ok(())
+    }
+
+    fn get_number_of_lines_before_fairness(&self, input_lines: usize) -> usize {
+        let number_of_justice_literals: usize = self.justice.iter().map(|j| j.len()).sum();
+        self.get_number_of_lines_before_justice(input_lines)
+            + self.number_of_justice_constraints
+            + number_of_justice_literals
+    }
+
+    fn create_fairness_nodes_of_aig(
+        &mut self,
+        lines: &[Vec<u8>],
+        input_lines: usize,
+    ) -> Result<(), AigParseError> {
+        let first_line_from_0 = self.get_number_of_lines_before_fairness(input_lines);
+        self.fairness = self.read_literal_lines(
+            lines,
+            first_line_from_0,
+            self.number_of_fairness_constraints,
+        )?;
+        Ok(())
     }
 
     fn get_max_literal_of_input_or_latch(&self) -> usize {
         2 * (self.number_of_inputs + self.number_of_latches)
     }
 
-    fn get_position_of_start_of_and_segment(&self, bytes: &[u8]) -> usize {
+    fn get_number_of_lines_before_and_segment(&self, input_lines: usize) -> usize {
+        self.get_number_of_lines_before_fairness(input_lines) + self.number_of_fairness_constraints
+    }
+
+    fn get_position_after_lines(bytes: &[u8], amount_of_lines_to_skip: usize) -> usize {
         let mut read_index: usize = 0;
-        let amount_of_lines_to_skip: usize = 1
-            + self.number_of_latches
-            + self.number_of_outputs
-            + self.number_of_bad_state_constraints
-            + self.number_of_invariant_constraints;
         let mut new_lines_seen = 0;
-        while new_lines_seen < amount_of_lines_to_skip {
+        while new_lines_seen < amount_of_lines_to_skip && read_index < bytes.len() {
             if bytes[read_index] == b'\n' {
                 new_lines_seen += 1;
             }
@@ -253,51 +429,74 @@ impl AndInverterGraph {
         read_index
     }
 
-    fn read_delta(&self, bytes: &[u8], mut read_index: usize) -> (usize, usize) {
-        assert!(read_index < bytes.len(), "Unexpected end of file");
-
+    fn read_delta(
+        &self,
+        bytes: &[u8],
+        mut read_index: usize,
+    ) -> Result<(usize, usize), AigParseError> {
+        let byte_error = |byte_offset: usize, reason: &str| AigParseError::InvalidBytes {
+            byte_offset,
+            reason: reason.to_string(),
+        };
         let mut i: usize = 0;
         let mut delta: usize = 0;
-        let mut ch: usize = bytes[read_index].into();
+        let mut ch: usize = bytes
+            .get(read_index)
+            .ok_or_else(|| byte_error(read_index, "Unexpected end of file."))?
+            .to_owned()
+            .into();
 
         while (ch & 0x80) != 0 {
-            assert_ne!(i, 5, "Invalid code");
+            if i == 5 {
+                return Err(byte_error(read_index, "Invalid code."));
+            }
 
             delta |= (ch & 0x7f) << (7 * i);
             i += 1;
             read_index += 1;
-            ch = bytes[read_index].into();
-
-            assert!(read_index < bytes.len(), "Unexpected end of file");
+            ch = bytes
+                .get(read_index)
+                .ok_or_else(|| byte_error(read_index, "Unexpected end of file."))?
+                .to_owned()
+                .into();
+        }
+        if i == 5 && ch >= 8 {
+            return Err(byte_error(read_index, "Invalid code."));
         }
-        assert!(i != 5 || ch < 8);
         delta |= ch << (7 * i);
-        (delta, (read_index + 1))
+        Ok((delta, (read_index + 1)))
     }
 
-    fn create_and_nodes_of_aig(&mut self, bytes: &[u8]) -> usize {
+    fn create_and_nodes_of_aig(&mut self, bytes: &[u8]) -> Result<usize, AigParseError> {
         let mut lhs = self.get_max_literal_of_input_or_latch();
 
-        let mut read_index = self.get_position_of_start_of_and_segment(bytes);
+        let mut read_index =
+            Self::get_position_after_lines(bytes, self.get_number_of_lines_before_and_segment(0));
 
         for _i in 0..self.number_of_and_gates {
             lhs += 2;
-            let (delta, new_read_index) = self.read_delta(bytes, read_index);
+            let start_of_and_gate = read_index;
+            let (delta, new_read_index) = self.read_delta(bytes, read_index)?;
             read_index = new_read_index;
-            assert!(delta <= lhs, "Invalid delta.");
-            let rhs0: usize = lhs - delta;
+            let rhs0 = lhs.checked_sub(delta);
 
-            let (delta, new_read_index) = self.read_delta(bytes, read_index);
+            let (delta, new_read_index) = self.read_delta(bytes, read_index)?;
             read_index = new_read_index;
-            assert!(delta <= rhs0, "Invalid delta.");
-            let rhs1: usize = rhs0 - delta;
+            let rhs1 = rhs0.and_then(|r| r.checked_sub(delta));
 
-            // the assert is from https://github.com/arminbiere/aiger/blob/master/FORMAT
+            // the check is from https://github.com/arminbiere/aiger/blob/master/FORMAT
             // line 456 as of writing this.
-            assert!(
-                lhs > rhs0 && rhs0 >= rhs1,
-                "Error (lhs > rhs0 >= rhs1) does not hold for and gate {lhs}"
-            );
+            let (rhs0, rhs1) = match (rhs0, rhs1) {
+                (Some(rhs0), Some(rhs1)) if lhs > rhs0 && rhs0 >= rhs1 => (rhs0, rhs1),
+                _ => {
+                    return Err(AigParseError::InvalidBytes {
+                        byte_offset: start_of_and_gate,
+                        reason: format!(
+                            "Error (lhs > rhs0 >= rhs1) does not hold for and gate {lhs}."
+                        ),
+                    })
+                }
+            };
 
             let mut node = AIGNode::new(lhs, AIGNodeType::And);
             node.set_rhs0_of_and(rhs0);
@@ -306,115 +505,511 @@ impl AndInverterGraph {
             self.nodes.push(node);
         }
 
-        read_index
+        Ok(read_index)
     }
 
-    fn add_symbol_to_node(&mut self, symbol_type: &str, symbol_number: usize, symbol: &str) {
+    fn add_symbol_to_node(
+        &mut self,
+        symbol_type: &str,
+        symbol_number: usize,
+        symbol: &str,
+    ) -> Result<(), String> {
+        if symbol_type == "j" {
+            // justice properties are sets of literals so their symbols are not kept in the nodes.
+            let justice_symbol = self
+                .justice_symbols
+                .get_mut(symbol_number)
+                .ok_or(format!("There is no {symbol_type}{symbol_number} to name."))?;
+            *justice_symbol = symbol.to_string();
+            return Ok(());
+        }
+
+        let list_of_symbol_type = match symbol_type {
+            "i" => &self.inputs,
+            "l" => &self.latches,
+            "o" => &self.outputs,
+            "b" => &self.bad,
+            "c" => &self.constraints,
+            "f" => &self.fairness,
+            _ => unreachable!(),
+        };
+        let index_or_literal = list_of_symbol_type
+            .get(symbol_number)
+            .ok_or(format!("There is no {symbol_type}{symbol_number} to name."))?
+            .to_owned();
+
         if symbol_type == "i" {
-            let node_index = self.inputs[symbol_number];
-            self.nodes[node_index].set_input_symbol(symbol);
+            self.nodes[index_or_literal].set_input_symbol(symbol);
         } else if symbol_type == "l" {
-            let node_index = self.latches[symbol_number];
-            self.nodes[node_index].set_latch_symbol(symbol);
+            self.nodes[index_or_literal].set_latch_symbol(symbol);
         } else if symbol_type == "o" {
-            let node_index = self.outputs[symbol_number] >> 1;
-            self.nodes[node_index].set_output_symbol(symbol);
+            self.nodes[index_or_literal >> 1].set_output_symbol(symbol);
         } else if symbol_type == "b" {
-            let node_index = self.bad[symbol_number] >> 1;
-            self.nodes[node_index].set_bad_symbol(symbol);
+            self.nodes[index_or_literal >> 1].set_bad_symbol(symbol);
         } else if symbol_type == "c" {
-            let node_index = self.constraints[symbol_number] >> 1;
-            self.nodes[node_index].set_constraint_symbol(symbol);
+            self.nodes[index_or_literal >> 1].set_constraint_symbol(symbol);
+        } else if symbol_type == "f" {
+            self.nodes[index_or_literal >> 1].set_fairness_symbol(symbol);
         } else {
             unreachable!();
         }
+        Ok(())
+    }
+
+    fn read_symbol_line(&mut self, line_as_vector_of_chars: &[u8]) -> Result<(), String> {
+        let line_as_string = std::str::from_utf8(line_as_vector_of_chars)
+            .map_err(|_| String::from("Symbol line is not valid UTF-8."))?;
+
+        // the symbol itself may contain spaces, it ends only at the end of the line.
+        let parsed_line: Vec<&str> = line_as_string.splitn(2, ' ').collect();
+        if parsed_line.len() != 2 {
+            return Err(format!(
+                "Line '{line_as_string}': Wrong number of arguments for symbol line."
+            ));
+        }
+        let mut symbol_and_variable_split: Vec<&str> = parsed_line[0].split("").collect();
+        // "i0" gets split into vec!["" , "i", "0", ""], let's drop start and end.
+        symbol_and_variable_split =
+            symbol_and_variable_split[1..(symbol_and_variable_split.len() - 1)].to_vec();
+
+        let symbol_type = symbol_and_variable_split.first().unwrap_or(&"");
+        if symbol_and_variable_split.len() <= 1
+            || !["i", "l", "o", "b", "c", "j", "f"].contains(symbol_type)
+        {
+            return Err(format!(
+                "Line '{line_as_string}': Symbol line should start with [ilobcjf]<pos>."
+            ));
+        }
+        let var_as_vector_of_strings = symbol_and_variable_split[1..].to_vec();
+        let symbol_number_as_string = var_as_vector_of_strings.join("");
+        let symbol_number = symbol_number_as_string.parse::<usize>().map_err(|_| {
+            format!("Line '{line_as_string}': Symbol line should start with [ilobcjf]<pos>.")
+        })?;
+        self.add_symbol_to_node(symbol_type, symbol_number, parsed_line[1])
     }
 
+    /// errors are reported by line number if it is known (ascii format) and otherwise by the
+    /// byte offset in the file (binary format).
     fn read_symbols_and_comments(
         &mut self,
         bytes: &[u8],
         position_of_end_of_and_segment_plus_one: usize,
-    ) {
+        first_line_from_0: Option<usize>,
+    ) -> Result<(), AigParseError> {
         // position_of_end_of_and_segment_plus_one == position where symbol table might begin
         let lines: &[Vec<u8>] =
             &Self::split_vector_by_newline(&bytes[position_of_end_of_and_segment_plus_one..]);
+        let mut byte_offset = position_of_end_of_and_segment_plus_one;
         for (index, line_as_vector_of_chars) in lines.iter().enumerate() {
-            let line_as_string = std::str::from_utf8(line_as_vector_of_chars).unwrap();
-
-            if line_as_string == "c" {
+            if line_as_vector_of_chars == b"c" {
                 // comment segment started, we can read this till the end and return
-                let rest_of_comments = &lines[index..].join(&b'\n');
-                let comment_section_as_is =
-                    std::str::from_utf8(rest_of_comments).unwrap().to_string();
-                self.comments = comment_section_as_is.replace(&char::from(0).to_string(), "");
+                // kept as bytes (even with null characters or invalid UTF-8) so that the binary
+                // file can be reproduced exactly.
+                self.comments = lines[index..].join(&b'\n');
                 break;
+            } else if let Err(reason) = self.read_symbol_line(line_as_vector_of_chars) {
+                return Err(match first_line_from_0 {
+                    Some(first_line) => AigParseError::InvalidLine {
+                        line: first_line + index + 1,
+                        reason,
+                    },
+                    None => AigParseError::InvalidBytes {
+                        byte_offset,
+                        reason,
+                    },
+                });
+            }
+            byte_offset += line_as_vector_of_chars.len() + 1;
+        }
+        Ok(())
+    }
+
+    // ********************************************************************************************
+    // helper functions for the ascii format
+    // ********************************************************************************************
+
+    fn define_node_of_aag(
+        &self,
+        nodes: &mut [Option<AIGNode>],
+        node: AIGNode,
+        line_number_from_1: usize,
+    ) -> Result<(), AigParseError> {
+        let line_error = |reason: String| AigParseError::InvalidLine {
+            line: line_number_from_1,
+            reason,
+        };
+        let lit = node.get_literal();
+        self.check_literal(lit, line_number_from_1)?;
+        if lit < 2 {
+            return Err(line_error(format!(
+                "Literal {lit} is reserved for constants."
+            )));
+        }
+        let var = lit >> 1;
+        if nodes[var].is_some() {
+            return Err(line_error(format!(
+                "Variable {var} is defined more than once."
+            )));
+        }
+        nodes[var] = Some(node);
+        Ok(())
+    }
+
+    fn check_literal_of_aag_is_not_negated(
+        lit: usize,
+        kind: &str,
+        line_number_from_1: usize,
+    ) -> Result<(), AigParseError> {
+        if (lit & 1) == 0 {
+            Ok(())
+        } else {
+            Err(AigParseError::InvalidLine {
+                line: line_number_from_1,
+                reason: format!("{kind} literal {lit} may not be negated."),
+            })
+        }
+    }
+
+    fn create_input_nodes_of_aag(
+        &mut self,
+        lines: &[Vec<u8>],
+        nodes: &mut [Option<AIGNode>],
+    ) -> Result<(), AigParseError> {
+        for i in 0..self.number_of_inputs {
+            let line_number_from_0: usize = i + 1;
+            let line_number_from_1: usize = line_number_from_0 + 1;
+            let parsed_line = Self::get_line_as_numbers(lines, line_number_from_0)?;
+            if parsed_line.len() != 1 {
+                return Err(AigParseError::InvalidLine {
+                    line: line_number_from_1,
+                    reason: String::from("Wrong number of arguments for input line."),
+                });
+            }
+
+            let lit = parsed_line[0];
+            Self::check_literal_of_aag_is_not_negated(lit, "Input", line_number_from_1)?;
+            self.define_node_of_aag(
+                nodes,
+                AIGNode::new(lit, AIGNodeType::Input),
+                line_number_from_1,
+            )?;
+            self.inputs.push(lit >> 1);
+        }
+        Ok(())
+    }
+
+    fn create_latch_nodes_of_aag(
+        &mut self,
+        lines: &[Vec<u8>],
+        nodes: &mut [Option<AIGNode>],
+    ) -> Result<(), AigParseError> {
+        for i in 0..self.number_of_latches {
+            let line_number_from_0: usize = i + 1 + self.number_of_inputs;
+            let line_number_from_1: usize = line_number_from_0 + 1;
+            let parsed_line = Self::get_line_as_numbers(lines, line_number_from_0)?;
+            if parsed_line.len() != 2 && parsed_line.len() != 3 {
+                return Err(AigParseError::InvalidLine {
+                    line: line_number_from_1,
+                    reason: String::from("Wrong number of arguments for latch line."),
+                });
+            }
+
+            let lit = parsed_line[0];
+            Self::check_literal_of_aag_is_not_negated(lit, "Latch", line_number_from_1)?;
+            let mut node = AIGNode::new(lit, AIGNodeType::Latch);
+
+            let next_lit = parsed_line[1];
+            self.check_literal(next_lit, line_number_from_1)?;
+            node.set_input_of_latch(next_lit);
+
+            // latch without a reset literal defaults to 0
+            let reset = parsed_line.get(2).copied().unwrap_or(0);
+            Self::check_reset_of_latch(lit, reset, line_number_from_1)?;
+            node.set_reset_of_latch(reset);
+
+            self.define_node_of_aag(nodes, node, line_number_from_1)?;
+            self.latches.push(lit >> 1);
+        }
+        Ok(())
+    }
+
+    fn create_and_nodes_of_aag(
+        &mut self,
+        lines: &[Vec<u8>],
+        nodes: &mut [Option<AIGNode>],
+    ) -> Result<(), AigParseError> {
+        let first_line_from_0 = self.get_number_of_lines_before_and_segment(self.number_of_inputs);
+        for i in 0..self.number_of_and_gates {
+            let line_number_from_0: usize = i + first_line_from_0;
+            let line_number_from_1: usize = line_number_from_0 + 1;
+            let parsed_line = Self::get_line_as_numbers(lines, line_number_from_0)?;
+            if parsed_line.len() != 3 {
+                return Err(AigParseError::InvalidLine {
+                    line: line_number_from_1,
+                    reason: String::from("Wrong number of arguments for and line."),
+                });
+            }
+
+            let (lhs, rhs0, rhs1) = (parsed_line[0], parsed_line[1], parsed_line[2]);
+            Self::check_literal_of_aag_is_not_negated(lhs, "And gate", line_number_from_1)?;
+            self.check_literal(rhs0, line_number_from_1)?;
+            self.check_literal(rhs1, line_number_from_1)?;
+
+            let mut node = AIGNode::new(lhs, AIGNodeType::And);
+            node.set_rhs0_of_and(rhs0);
+            node.set_rhs1_of_and(rhs1);
+            self.define_node_of_aag(nodes, node, line_number_from_1)?;
+            self.ands.push(lhs >> 1);
+        }
+        Ok(())
+    }
+
+    fn move_nodes_of_aag(&mut self, nodes: Vec<Option<AIGNode>>) -> Result<(), AigParseError> {
+        self.nodes = Vec::with_capacity(nodes.len());
+        for (var, node) in nodes.into_iter().enumerate() {
+            match node {
+                Some(n) => self.nodes.push(n),
+                None => {
+                    return Err(AigParseError::InvalidStructure {
+                        reason: format!("Variable {var} is never defined."),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// the variables in topological order, every and gate comes after both of its inputs.
+    /// The nodes are visited by variable number, so a graph that is already in topological
+    /// order by variable number keeps its order.
+    fn get_topological_order_of_aag(&self) -> Result<Vec<usize>, AigParseError> {
+        let fanins = |variable: usize| -> Vec<usize> {
+            let node = &self.nodes[variable];
+            if node.get_type() == AIGNodeType::And {
+                vec![node.get_and_rhs0() >> 1, node.get_and_rhs1() >> 1]
             } else {
-                let parsed_line: Vec<&str> = line_as_string.split(' ').collect();
-                assert!(
-                    parsed_line.len() == 2,
-                    "Line '{line_as_string}': Wrong number of arguments for symbol line."
-                );
-                let mut symbol_and_variable_split: Vec<&str> = parsed_line[0].split("").collect();
-                // "i0" gets split into vec!["" , "i", "0", ""], let's drop start and end.
-                symbol_and_variable_split =
-                    symbol_and_variable_split[1..(symbol_and_variable_split.len() - 1)].to_vec();
-                assert!(
-                    symbol_and_variable_split.len() > 1,
-                    "Line '{line_as_string}': Symbol line should start with [ilobc]<pos>."
-                );
-
-                let symbol_type = symbol_and_variable_split[0];
-                assert!(
-                    ["i", "l", "o", "b", "c"].contains(&symbol_type),
-                    "Line '{line_as_string}': Symbol line should start with [ilobc]<pos>."
-                );
-                let var_as_vector_of_strings = symbol_and_variable_split[1..].to_vec();
-                let symbol_number_as_string = var_as_vector_of_strings.join("");
-                let symbol_number = Self::convert_string_to_number(&symbol_number_as_string);
-                self.add_symbol_to_node(symbol_type, symbol_number, parsed_line[1])
+                Vec::new()
+            }
+        };
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut is_on_stack = vec![false; self.nodes.len()];
+        let mut is_ordered = vec![false; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if is_ordered[root] {
+                continue;
+            }
+            is_on_stack[root] = true;
+            let mut stack = vec![(root, fanins(root))];
+            while let Some((variable, fanins_to_visit)) = stack.last_mut() {
+                let variable = variable.to_owned();
+                match fanins_to_visit.pop() {
+                    Some(fanin) if is_on_stack[fanin] => {
+                        return Err(AigParseError::InvalidStructure {
+                            reason: format!("And gate {} is on a cycle.", fanin << 1),
+                        });
+                    }
+                    Some(fanin) if !is_ordered[fanin] => {
+                        is_on_stack[fanin] = true;
+                        stack.push((fanin, fanins(fanin)));
+                    }
+                    Some(_) => {}
+                    None => {
+                        is_on_stack[variable] = false;
+                        is_ordered[variable] = true;
+                        order.push(variable);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// renumbers the variables so that every and gate has a higher variable number than both
+    /// of its inputs, see AndInverterGraph. The ascii format allows and gates to use inputs
+    /// with a higher variable number, but not cycles of and gates.
+    fn sort_nodes_of_aag_topologically(&mut self) -> Result<(), AigParseError> {
+        let order = self.get_topological_order_of_aag()?;
+        if order.iter().enumerate().all(|(position, v)| position == *v) {
+            return Ok(());
+        }
+        let mut new_variables = vec![0; self.nodes.len()];
+        for (position, variable) in order.iter().enumerate() {
+            new_variables[variable.to_owned()] = position;
+        }
+        let map_literal = |literal: usize| (new_variables[literal >> 1] << 1) | (literal & 1);
+
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        for variable in order {
+            let node = &self.nodes[variable];
+            let lit = map_literal(node.get_literal());
+            let mut new_node = AIGNode::new(lit, node.get_type());
+            match node.get_type() {
+                AIGNodeType::Latch => {
+                    new_node.set_input_of_latch(map_literal(node.get_latch_input()));
+                    let reset = node.get_latch_reset();
+                    new_node.set_reset_of_latch(if reset < 2 { reset } else { lit });
+                }
+                AIGNodeType::And => {
+                    new_node.set_rhs0_of_and(map_literal(node.get_and_rhs0()));
+                    new_node.set_rhs1_of_and(map_literal(node.get_and_rhs1()));
+                }
+                AIGNodeType::ConstantZero | AIGNodeType::Input => {}
+            }
+            nodes.push(new_node);
+        }
+        self.nodes = nodes;
+
+        for variables in [&mut self.inputs, &mut self.latches, &mut self.ands] {
+            for variable in variables.iter_mut() {
+                *variable = new_variables[variable.to_owned()];
             }
         }
+        let justice_literals = self.justice.iter_mut().flatten();
+        for literal in self
+            .outputs
+            .iter_mut()
+            .chain(self.bad.iter_mut())
+            .chain(self.constraints.iter_mut())
+            .chain(justice_literals)
+            .chain(self.fairness.iter_mut())
+        {
+            *literal = map_literal(literal.to_owned());
+        }
+        Ok(())
     }
 
-    fn check_aig(&self) {
-        assert_eq!(self.nodes[0].get_type(), AIGNodeType::ConstantZero);
+    pub(super) fn check_aig(&self) -> Result<(), AigParseError> {
+        let check = |condition: bool, reason: &str| {
+            if condition {
+                Ok(())
+            } else {
+                Err(AigParseError::InvalidStructure {
+                    reason: reason.to_string(),
+                })
+            }
+        };
+        check(
+            self.nodes[0].get_type() == AIGNodeType::ConstantZero,
+            "Variable 0 must be the constant.",
+        )?;
         // inputs
-        assert_eq!(self.number_of_inputs, self.inputs.len());
+        check(
+            self.number_of_inputs == self.inputs.len(),
+            "Wrong number of inputs.",
+        )?;
         for input_index in &self.inputs {
             let i = input_index.to_owned();
-            assert_eq!(self.nodes[i].get_type(), AIGNodeType::Input);
+            check(
+                self.nodes[i].get_type() == AIGNodeType::Input,
+                "Input points to a node that is not an input.",
+            )?;
         }
         // latches
-        assert_eq!(self.number_of_latches, self.latches.len());
+        check(
+            self.number_of_latches == self.latches.len(),
+            "Wrong number of latches.",
+        )?;
         for latch_index in &self.latches {
             let i = latch_index.to_owned();
-            assert_eq!(self.nodes[i].get_type(), AIGNodeType::Latch);
+            check(
+                self.nodes[i].get_type() == AIGNodeType::Latch,
+                "Latch points to a node that is not a latch.",
+            )?;
         }
         // ands
-        assert_eq!(self.number_of_and_gates, self.ands.len());
+        check(
+            self.number_of_and_gates == self.ands.len(),
+            "Wrong number of and gates.",
+        )?;
         for and_index in &self.ands {
             let i = and_index.to_owned();
-            assert_eq!(self.nodes[i].get_type(), AIGNodeType::And);
+            check(
+                self.nodes[i].get_type() == AIGNodeType::And,
+                "And gate points to a node that is not an and gate.",
+            )?;
         }
-        assert_eq!(self.number_of_outputs, self.outputs.len());
-        assert_eq!(self.number_of_bad_state_constraints, self.bad.len());
-        assert_eq!(self.number_of_invariant_constraints, self.constraints.len());
+        check(
+            self.number_of_outputs == self.outputs.len(),
+            "Wrong number of outputs.",
+        )?;
+        check(
+            self.number_of_bad_state_constraints == self.bad.len(),
+            "Wrong number of bad state literals.",
+        )?;
+        check(
+            self.number_of_invariant_constraints == self.constraints.len(),
+            "Wrong number of invariant constraints.",
+        )?;
+        check(
+            self.number_of_justice_constraints == self.justice.len(),
+            "Wrong number of justice properties.",
+        )?;
+        check(
+            self.number_of_fairness_constraints == self.fairness.len(),
+            "Wrong number of fairness constraints.",
+        )
     }
 
-    fn from_vector_of_bytes(vec_of_bytes: &[u8]) -> AndInverterGraph {
+    fn from_vector_of_bytes(vec_of_bytes: &[u8]) -> Result<AndInverterGraph, AigParseError> {
         let lines = Self::split_vector_by_newline(vec_of_bytes);
         let mut aig = AndInverterGraph::new();
-        aig.check_first_line_of_aig_and_load_it(&lines);
-        aig.allocate_vectors();
+        aig.check_first_line_of_aig_and_load_it(&lines, "aig")?;
+        aig.allocate_vectors()?;
         aig.create_input_nodes_of_aig();
-        aig.create_latch_nodes_of_aig(&lines);
-        aig.create_output_nodes_of_aig(&lines);
-        aig.create_bad_nodes_of_aig(&lines);
-        aig.create_invariant_constraint_nodes_of_aig(&lines);
-        let position_of_end_of_and_segment_plus_one = aig.create_and_nodes_of_aig(vec_of_bytes);
-        aig.read_symbols_and_comments(vec_of_bytes, position_of_end_of_and_segment_plus_one);
-        aig.check_aig();
-        aig
+        aig.create_latch_nodes_of_aig(&lines)?;
+        aig.create_output_nodes_of_aig(&lines, 0)?;
+        aig.create_bad_nodes_of_aig(&lines, 0)?;
+        aig.create_invariant_constraint_nodes_of_aig(&lines, 0)?;
+        aig.create_justice_nodes_of_aig(&lines, 0)?;
+        aig.create_fairness_nodes_of_aig(&lines, 0)?;
+        let position_of_end_of_and_segment_plus_one = aig.create_and_nodes_of_aig(vec_of_bytes)?;
+        aig.read_symbols_and_comments(vec_of_bytes, position_of_end_of_and_segment_plus_one, None)?;
+        aig.check_aig()?;
+        Ok(aig)
+    }
+
+    fn from_aag_vector_of_bytes(vec_of_bytes: &[u8]) -> Result<AndInverterGraph, AigParseError> {
+        let lines = Self::split_vector_by_newline(vec_of_bytes);
+        let mut aig = AndInverterGraph::new();
+        aig.check_first_line_of_aig_and_load_it(&lines, "aag")?;
+        aig.allocate_vectors()?;
+
+        // in the ascii format the nodes may appear in any order, so they are collected first.
+        let mut nodes: Vec<Option<AIGNode>> = Vec::new();
+        nodes.resize_with(aig.maximum_variable_index + 1, || None);
+        nodes[0] = aig.nodes.pop();
+
+        let input_lines = aig.number_of_inputs;
+        aig.create_input_nodes_of_aag(&lines, &mut nodes)?;
+        aig.create_latch_nodes_of_aag(&lines, &mut nodes)?;
+        aig.create_output_nodes_of_aig(&lines, input_lines)?;
+        aig.create_bad_nodes_of_aig(&lines, input_lines)?;
+        aig.create_invariant_constraint_nodes_of_aig(&lines, input_lines)?;
+        aig.create_justice_nodes_of_aig(&lines, input_lines)?;
+        aig.create_fairness_nodes_of_aig(&lines, input_lines)?;
+        aig.create_and_nodes_of_aag(&lines, &mut nodes)?;
+        aig.move_nodes_of_aag(nodes)?;
+        aig.sort_nodes_of_aag_topologically()?;
+
+        let lines_before_symbol_table =
+            aig.get_number_of_lines_before_and_segment(input_lines) + aig.number_of_and_gates;
+        let position_of_symbol_table =
+            Self::get_position_after_lines(vec_of_bytes, lines_before_symbol_table);
+        aig.read_symbols_and_comments(
+            vec_of_bytes,
+            position_of_symbol_table,
+            Some(lines_before_symbol_table),
+        )?;
+        aig.check_aig()?;
+        Ok(aig)
+    }
+
+    pub(super) fn read_file(file_path: &str) -> Result<Vec<u8>, AigParseError> {
+        fs::read(file_path).map_err(|e| AigParseError::UnreadableFile {
+            path: file_path.to_string(),
+            reason: e.to_string(),
+        })
     }
 
     // ********************************************************************************************
@@ -435,8 +1030,139 @@ impl AndInverterGraph {
     /// let aig = AndInverterGraph::from_aig_path(file_path);
     /// ```
     pub fn from_aig_path(file_path: &str) -> AndInverterGraph {
-        let file_as_vec_of_bytes = fs::read(file_path)
-            .unwrap_or_else(|_| panic!("Unable to read the '.aig' file {file_path}"));
+        Self::try_from_aig_path(file_path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes path to '.aig' file and creates a corresponding AndInverterGraph object,
+    /// or reports why the file is not a valid '.aig' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.aig' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::try_from_aig_path("tests/examples/ours/counter.aig");
+    /// assert!(aig.is_ok());
+    /// let missing = AndInverterGraph::try_from_aig_path("tests/examples/ours/missing.aig");
+    /// assert!(matches!(missing, Err(AigParseError::UnreadableFile { .. })));
+    /// ```
+    pub fn try_from_aig_path(file_path: &str) -> Result<AndInverterGraph, AigParseError> {
+        let file_as_vec_of_bytes = Self::read_file(file_path)?;
         Self::from_vector_of_bytes(&file_as_vec_of_bytes)
     }
+
+    /// Function that takes the content of an '.aig' file and creates a corresponding
+    /// AndInverterGraph object, or reports why the content is not a valid '.aig' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - the content of the '.aig' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // the second and gate is missing.
+    /// let aig = AndInverterGraph::try_from_aig_bytes(b"aig 3 1 0 1 2\n6\n\x02\x02");
+    /// assert_eq!(
+    ///     aig.err(),
+    ///     Some(AigParseError::InvalidBytes {
+    ///         byte_offset: 18,
+    ///         reason: String::from("Unexpected end of file.")
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_aig_bytes(bytes: &[u8]) -> Result<AndInverterGraph, AigParseError> {
+        Self::from_vector_of_bytes(bytes)
+    }
+
+    /// Function that takes path to '.aag' file and creates a corresponding AndInverterGraph object.
+    /// The '.aag' file is in accordance to http://fmv.jku.at/aiger/
+    ///
+    /// And gates may use inputs with a higher variable number, the variables are then
+    /// renumbered so that every and gate comes after its inputs. Cycles of and gates are
+    /// reported as errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.aag' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let file_path = "tests/examples/ours/counter.aag";
+    /// let aig = AndInverterGraph::from_aag_path(file_path);
+    /// assert_eq!(aig.get_aag_string(), AndInverterGraph::from_aig_path("tests/examples/ours/counter.aig").get_aag_string());
+    /// ```
+    pub fn from_aag_path(file_path: &str) -> AndInverterGraph {
+        Self::try_from_aag_path(file_path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes path to '.aag' file and creates a corresponding AndInverterGraph object,
+    /// or reports why the file is not a valid '.aag' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.aag' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::try_from_aag_path("tests/examples/ours/counter.aag");
+    /// assert!(aig.is_ok());
+    /// ```
+    pub fn try_from_aag_path(file_path: &str) -> Result<AndInverterGraph, AigParseError> {
+        let file_as_vec_of_bytes = Self::read_file(file_path)?;
+        Self::from_aag_vector_of_bytes(&file_as_vec_of_bytes)
+    }
+
+    /// Function that takes the content of an '.aag' file and creates a corresponding
+    /// AndInverterGraph object.
+    ///
+    /// # Arguments
+    ///
+    /// * `aag` - the content of the '.aag' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a single latch that toggles, starts at 1, and is bad when it is 0.
+    /// let aag = "aag 1 0 1 0 0 1\n2 3 1\n3\nl0 toggle\nb0 is zero\nc\nhand written\n";
+    /// let aig = AndInverterGraph::from_aag_str(aag);
+    /// assert_eq!(aig.get_latch_information(), vec![(2, 3, 1)]);
+    /// assert_eq!(aig.get_bad_information(), vec![3]);
+    /// assert_eq!(aig.get_aag_string(), aag);
+    /// ```
+    pub fn from_aag_str(aag: &str) -> AndInverterGraph {
+        Self::try_from_aag_str(aag).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes the content of an '.aag' file and creates a corresponding
+    /// AndInverterGraph object, or reports why the content is not a valid '.aag' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `aag` - the content of the '.aag' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::try_from_aag_str("aag 1 0 1 0 0\n2 5\n");
+    /// assert_eq!(
+    ///     aig.err(),
+    ///     Some(AigParseError::InvalidLine {
+    ///         line: 2,
+    ///         reason: String::from(
+    ///             "File contains literal 5 which is higher than maximum variable index."
+    ///         )
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_aag_str(aag: &str) -> Result<AndInverterGraph, AigParseError> {
+        Self::from_aag_vector_of_bytes(aag.as_bytes())
+    }
 }
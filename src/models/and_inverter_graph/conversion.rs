@@ -3,12 +3,132 @@
 // ************************************************************************************************
 
 use crate::models::and_inverter_graph::AndInverterGraph;
+use std::cmp::{max, min};
+use std::fs;
 
 // ************************************************************************************************
 // impl
 // ************************************************************************************************
 
 impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_header_line(&self, format: &str) -> String {
+        let mut first_line = vec![String::from(format)];
+        first_line.push(self.maximum_variable_index.to_string());
+        first_line.push(self.number_of_inputs.to_string());
+        first_line.push(self.number_of_latches.to_string());
+        first_line.push(self.number_of_outputs.to_string());
+        first_line.push(self.number_of_and_gates.to_string());
+        // the optional counts are written only if they or any count after them is non zero.
+        let optional_counts = [
+            self.number_of_bad_state_constraints,
+            self.number_of_invariant_constraints,
+            self.number_of_justice_constraints,
+            self.number_of_fairness_constraints,
+        ];
+        let amount_of_optional_counts = optional_counts
+            .iter()
+            .rposition(|count| *count > 0)
+            .map_or(0, |position| position + 1);
+        for count in optional_counts[..amount_of_optional_counts].iter() {
+            first_line.push(count.to_string());
+        }
+        first_line.join(" ")
+    }
+
+    fn get_symbol_table_lines(&self) -> Vec<String> {
+        let mut symbol_table: Vec<String> = Vec::new();
+        for (index, input_index) in self.inputs.iter().enumerate() {
+            let symbol = self.nodes[input_index.to_owned()].get_input_symbol();
+            if !symbol.is_empty() {
+                symbol_table.push(format!("i{index} {symbol}"));
+            }
+        }
+        for (index, latch_index) in self.latches.iter().enumerate() {
+            let symbol = self.nodes[latch_index.to_owned()].get_latch_symbol();
+            if !symbol.is_empty() {
+                symbol_table.push(format!("l{index} {symbol}"));
+            }
+        }
+        for (index, output_literal) in self.outputs.iter().enumerate() {
+            let symbol = self.nodes[output_literal >> 1].get_output_symbol();
+            if !symbol.is_empty() {
+                symbol_table.push(format!("o{index} {symbol}"));
+            }
+        }
+        for (index, bad_literal) in self.bad.iter().enumerate() {
+            let symbol = self.nodes[bad_literal >> 1].get_bad_symbol();
+            if !symbol.is_empty() {
+                symbol_table.push(format!("b{index} {symbol}"));
+            }
+        }
+        for (index, constraint_literal) in self.constraints.iter().enumerate() {
+            let symbol = self.nodes[constraint_literal >> 1].get_constraint_symbol();
+            if !symbol.is_empty() {
+                symbol_table.push(format!("c{index} {symbol}"));
+            }
+        }
+        for (index, symbol) in self.justice_symbols.iter().enumerate() {
+            if !symbol.is_empty() {
+                symbol_table.push(format!("j{index} {symbol}"));
+            }
+        }
+        for (index, fairness_literal) in self.fairness.iter().enumerate() {
+            let symbol = self.nodes[fairness_literal >> 1].get_fairness_symbol();
+            if !symbol.is_empty() {
+                symbol_table.push(format!("f{index} {symbol}"));
+            }
+        }
+        symbol_table
+    }
+
+    /// binary AIGER requires the inputs to be the first variables, then the latches, and then
+    /// the and gates, this gives the new variable number of each variable.
+    fn get_binary_variable_mapping(&self) -> Vec<usize> {
+        let mut variable_map = vec![0; self.nodes.len()];
+        let mut next_variable = 1;
+        for index in self.inputs.iter().chain(self.latches.iter()) {
+            variable_map[index.to_owned()] = next_variable;
+            next_variable += 1;
+        }
+        let mut and_indexes = self.ands.to_owned();
+        and_indexes.sort();
+        for index in and_indexes {
+            variable_map[index] = next_variable;
+            next_variable += 1;
+        }
+        variable_map
+    }
+
+    /// the justice section is the size of each justice property followed by all of their
+    /// literals, and then the fairness constraints.
+    fn get_justice_and_fairness_lines(&self, map_literal: impl Fn(usize) -> usize) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for justice_property in self.justice.iter() {
+            lines.push(justice_property.len().to_string());
+        }
+        for literal in self.justice.iter().flatten().chain(self.fairness.iter()) {
+            lines.push(map_literal(literal.to_owned()).to_string());
+        }
+        lines
+    }
+
+    fn write_delta(mut delta: usize, bytes: &mut Vec<u8>) {
+        // 7 bits per byte, the highest bit marks that more bytes follow.
+        while delta & !0x7f != 0 {
+            bytes.push(((delta & 0x7f) | 0x80) as u8);
+            delta >>= 7;
+        }
+        bytes.push(delta as u8);
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
     /// Function that converts an AndInverterGraph into '.aag' format as described in:
     /// The '.aag' file is in accordance to http://fmv.jku.at/aiger/
     ///
@@ -24,31 +144,12 @@ impl AndInverterGraph {
     /// assert_eq!("aag 5 0 3 1 2\n2 10\n4 2\n6 4\n10\n8 7 5\n10 8 3\n", aig.get_aag_string());
     /// ```
     pub fn get_aag_string(&self) -> String {
-        let mut result: Vec<String> = Vec::new();
-        let mut symbol_table: Vec<String> = Vec::new();
-        let mut first_line = vec![String::from("aag")];
-        first_line.push(self.maximum_variable_index.to_string());
-        first_line.push(self.number_of_inputs.to_string());
-        first_line.push(self.number_of_latches.to_string());
-        first_line.push(self.number_of_outputs.to_string());
-        first_line.push(self.number_of_and_gates.to_string());
-        assert!(self.number_of_justice_constraints + self.number_of_fairness_constraints == 0);
-        if self.number_of_bad_state_constraints + self.number_of_invariant_constraints > 0 {
-            first_line.push(self.number_of_bad_state_constraints.to_string());
-        }
-        if self.number_of_invariant_constraints > 0 {
-            first_line.push(self.number_of_invariant_constraints.to_string());
-        }
-        result.push(first_line.join(" "));
-        for (index, input_index) in self.inputs.iter().enumerate() {
+        let mut result: Vec<String> = vec![self.get_header_line("aag")];
+        for input_index in self.inputs.iter() {
             let node = &self.nodes[input_index.to_owned()];
             result.push(node.get_literal().to_string());
-            let symbol = node.get_input_symbol();
-            if !symbol.is_empty() {
-                symbol_table.push(format!("i{index} {symbol}"));
-            }
         }
-        for (index, latch_index) in self.latches.iter().enumerate() {
+        for latch_index in self.latches.iter() {
             let mut line = Vec::new();
             let node = &self.nodes[latch_index.to_owned()];
             line.push(node.get_literal().to_string());
@@ -57,38 +158,17 @@ impl AndInverterGraph {
                 line.push(node.get_latch_reset().to_string());
             }
             result.push(line.join(" "));
-            let symbol = node.get_latch_symbol();
-            if !symbol.is_empty() {
-                symbol_table.push(format!("l{index} {symbol}"));
-            }
         }
-        for (index, output_literal) in self.outputs.iter().enumerate() {
+        for output_literal in self.outputs.iter() {
             result.push(output_literal.to_string());
-
-            let node = &self.nodes[(output_literal >> 1).to_owned()];
-            let symbol = node.get_output_symbol();
-            if !symbol.is_empty() {
-                symbol_table.push(format!("o{index} {symbol}"));
-            }
         }
-        for (index, bad_literal) in self.bad.iter().enumerate() {
+        for bad_literal in self.bad.iter() {
             result.push(bad_literal.to_string());
-
-            let node = &self.nodes[(bad_literal >> 1).to_owned()];
-            let symbol = node.get_bad_symbol();
-            if !symbol.is_empty() {
-                symbol_table.push(format!("b{index} {symbol}"));
-            }
         }
-        for (index, constraint_literal) in self.constraints.iter().enumerate() {
+        for constraint_literal in self.constraints.iter() {
             result.push(constraint_literal.to_string());
-
-            let node = &self.nodes[(constraint_literal >> 1).to_owned()];
-            let symbol = node.get_constraint_symbol();
-            if !symbol.is_empty() {
-                symbol_table.push(format!("c{index} {symbol}"));
-            }
         }
+        result.append(&mut self.get_justice_and_fairness_lines(|literal| literal));
         for and_index in &self.ands {
             let node = &self.nodes[and_index.to_owned()];
             let lhs = node.get_literal();
@@ -96,8 +176,10 @@ impl AndInverterGraph {
             let rhs1 = node.get_and_rhs1();
             result.push(format!("{lhs} {rhs0} {rhs1}"));
         }
-        result.append(&mut symbol_table);
-        result.append(&mut vec![self.comments.to_owned()]);
+        result.append(&mut self.get_symbol_table_lines());
+        result.append(&mut vec![
+            String::from_utf8_lossy(&self.comments).replace(&char::from(0).to_string(), "")
+        ]);
         let mut final_res = result.join("\n");
         if final_res.ends_with('\n') {
             // do nothing
@@ -107,4 +189,103 @@ impl AndInverterGraph {
         }
         final_res
     }
+
+    /// Function that converts an AndInverterGraph into binary '.aig' format as described in:
+    /// http://fmv.jku.at/aiger/
+    /// The variables are renumbered if needed so that inputs come first, then latches, and
+    /// then the and gates in topological order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired for conversion.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let file_path = "tests/examples/ours/counter.aig";
+    /// let aig = AndInverterGraph::from_aig_path(file_path);
+    /// assert_eq!(aig.to_aig_bytes(), std::fs::read(file_path).unwrap());
+    /// ```
+    pub fn to_aig_bytes(&self) -> Vec<u8> {
+        let variable_map = self.get_binary_variable_mapping();
+        let map_literal = |lit: usize| (variable_map[lit >> 1] << 1) | (lit & 1);
+
+        let mut lines: Vec<String> = vec![self.get_header_line("aig")];
+        for latch_index in self.latches.iter() {
+            let node = &self.nodes[latch_index.to_owned()];
+            let next = map_literal(node.get_latch_input());
+            let reset = node.get_latch_reset();
+            if reset == 0 {
+                lines.push(next.to_string());
+            } else {
+                lines.push(format!("{next} {}", map_literal(reset)));
+            }
+        }
+        for literal in self
+            .outputs
+            .iter()
+            .chain(self.bad.iter())
+            .chain(self.constraints.iter())
+        {
+            lines.push(map_literal(literal.to_owned()).to_string());
+        }
+        lines.append(&mut self.get_justice_and_fairness_lines(map_literal));
+
+        let mut result: Vec<u8> = Vec::new();
+        for line in lines {
+            result.extend_from_slice(line.as_bytes());
+            result.push(b'\n');
+        }
+
+        // the and gates are written in the order of their new variable numbers
+        let mut and_gates: Vec<(usize, usize, usize)> = self
+            .ands
+            .iter()
+            .map(|and_index| {
+                let node = &self.nodes[and_index.to_owned()];
+                let lhs = map_literal(node.get_literal());
+                let rhs0 = map_literal(node.get_and_rhs0());
+                let rhs1 = map_literal(node.get_and_rhs1());
+                (lhs, max(rhs0, rhs1), min(rhs0, rhs1))
+            })
+            .collect();
+        and_gates.sort();
+        for (lhs, rhs0, rhs1) in and_gates {
+            debug_assert!(lhs > rhs0 && rhs0 >= rhs1);
+            Self::write_delta(lhs - rhs0, &mut result);
+            Self::write_delta(rhs0 - rhs1, &mut result);
+        }
+
+        for line in self.get_symbol_table_lines() {
+            result.extend_from_slice(line.as_bytes());
+            result.push(b'\n');
+        }
+        if !self.comments.is_empty() {
+            result.extend_from_slice(&self.comments);
+            result.push(b'\n');
+        }
+        result
+    }
+
+    /// Function that writes an AndInverterGraph into a binary '.aig' file.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired for conversion.
+    /// * `file_path` - the path of the '.aig' file to write.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter.aig");
+    /// let file_path = std::env::temp_dir().join("counter_copy.aig");
+    /// let file_path = file_path.to_str().unwrap();
+    /// aig.write_aig_path(file_path);
+    /// let copy = AndInverterGraph::from_aig_path(file_path);
+    /// assert_eq!(aig.get_aag_string(), copy.get_aag_string());
+    /// ```
+    pub fn write_aig_path(&self, file_path: &str) {
+        fs::write(file_path, self.to_aig_bytes())
+            .unwrap_or_else(|_| panic!("Unable to write the '.aig' file {file_path}"));
+    }
 }
@@ -0,0 +1,196 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::AndInverterGraph;
+use std::collections::{HashMap, HashSet};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the union of two sorted cuts, or None if it has more than `k` variables.
+    fn merge_cuts(a: &[usize], b: &[usize], k: usize) -> Option<Vec<usize>> {
+        let mut result = Vec::with_capacity(k);
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() || j < b.len() {
+            let variable = if j == b.len() || (i < a.len() && a[i] < b[j]) {
+                i += 1;
+                a[i - 1]
+            } else if i == a.len() || b[j] < a[i] {
+                j += 1;
+                b[j - 1]
+            } else {
+                i += 1;
+                j += 1;
+                a[i - 1]
+            };
+            if result.len() == k {
+                return None;
+            }
+            result.push(variable);
+        }
+        Some(result)
+    }
+
+    fn is_sub_cut(small: &[usize], big: &[usize]) -> bool {
+        small.len() <= big.len() && small.iter().all(|v| big.binary_search(v).is_ok())
+    }
+
+    /// the truth table of every leaf of a cut with up to 6 leaves.
+    fn get_leaf_truth_table(position: usize) -> u64 {
+        const LEAF_TRUTH_TABLES: [u64; 6] = [
+            0xAAAA_AAAA_AAAA_AAAA,
+            0xCCCC_CCCC_CCCC_CCCC,
+            0xF0F0_F0F0_F0F0_F0F0,
+            0xFF00_FF00_FF00_FF00,
+            0xFFFF_0000_FFFF_0000,
+            0xFFFF_FFFF_0000_0000,
+        ];
+        LEAF_TRUTH_TABLES[position]
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that enumerates the k-feasible cuts of every node of an AndInverterGraph.
+    /// A cut of a node is a set of variables such that every path from an input or a latch
+    /// to the node goes through one of them, so the node is a function of the cut.
+    /// The cuts of an and gate are merged from the cuts of its inputs, cuts with more than
+    /// `k` variables are dropped, and so are cuts that contain another cut of the same node.
+    /// Every node has its trivial cut, the node itself, as its first cut, and the constant
+    /// has the empty cut. At most `maximum_number_of_cuts` cuts are kept for every node,
+    /// the smallest ones are preferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `k` - the maximum number of variables in a cut.
+    /// * `maximum_number_of_cuts` - the maximum number of cuts of every node, including the
+    ///   trivial one.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // (x & y) & (y & z), as 8 = 4 & 2 and 10 = 6 & 4 and 12 = 10 & 8.
+    /// let aig = AndInverterGraph::from_aag_str("aag 6 3 0 1 3\n2\n4\n6\n12\n8 4 2\n10 6 4\n12 10 8\n");
+    /// let cuts = aig.get_k_feasible_cuts(3, 10);
+    /// assert_eq!(cuts[1], vec![vec![1]]);
+    /// assert_eq!(cuts[4], vec![vec![4], vec![1, 2]]);
+    /// assert_eq!(
+    ///     cuts[6],
+    ///     vec![vec![6], vec![4, 5], vec![1, 2, 3], vec![1, 2, 5], vec![2, 3, 4]]
+    /// );
+    /// ```
+    pub fn get_k_feasible_cuts(
+        &self,
+        k: usize,
+        maximum_number_of_cuts: usize,
+    ) -> Vec<Vec<Vec<usize>>> {
+        assert!(k > 0, "A cut must have at least one variable.");
+        assert!(maximum_number_of_cuts > 0, "The trivial cut must be kept.");
+        let mut cuts: Vec<Vec<Vec<usize>>> = Vec::with_capacity(self.nodes.len());
+        for (variable, node) in self.nodes.iter().enumerate() {
+            let mut node_cuts = match node.get_type() {
+                AIGNodeType::ConstantZero => vec![Vec::new()],
+                _ => vec![vec![variable]],
+            };
+            if node.get_type() == AIGNodeType::And {
+                let mut merged_cuts: Vec<Vec<usize>> = Vec::new();
+                for a in cuts[node.get_and_rhs0() >> 1].iter() {
+                    for b in cuts[node.get_and_rhs1() >> 1].iter() {
+                        if let Some(cut) = Self::merge_cuts(a, b, k) {
+                            merged_cuts.push(cut);
+                        }
+                    }
+                }
+                merged_cuts.sort_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+                merged_cuts.dedup();
+                for cut in merged_cuts {
+                    if node_cuts.len() == maximum_number_of_cuts {
+                        break;
+                    }
+                    if !node_cuts[1..].iter().any(|c| Self::is_sub_cut(c, &cut)) {
+                        node_cuts.push(cut);
+                    }
+                }
+            }
+            cuts.push(node_cuts);
+        }
+        cuts
+    }
+
+    /// Function that computes the truth table of a node as a function of the variables of
+    /// one of its cuts. Bit `i` of the result is the value of the node when the value of
+    /// the `j`-th variable of the cut is bit `j` of `i`. Cuts with fewer than 6 variables
+    /// give a truth table that repeats itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `variable` - the variable of the node.
+    /// * `cut` - a cut of the node with at most 6 variables.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // (x & y) & (y & z), as 8 = 4 & 2 and 10 = 6 & 4 and 12 = 10 & 8.
+    /// let aig = AndInverterGraph::from_aag_str("aag 6 3 0 1 3\n2\n4\n6\n12\n8 4 2\n10 6 4\n12 10 8\n");
+    /// // x & y & z is only true when all three are true.
+    /// assert_eq!(aig.get_cut_truth_table(6, &[1, 2, 3]), 0x8080_8080_8080_8080);
+    /// // 10 & 8 is only true when both are true.
+    /// assert_eq!(aig.get_cut_truth_table(6, &[4, 5]), 0x8888_8888_8888_8888);
+    /// ```
+    pub fn get_cut_truth_table(&self, variable: usize, cut: &[usize]) -> u64 {
+        assert!(
+            cut.len() <= 6,
+            "Cuts with more than 6 variables are not supported."
+        );
+        let mut truth_tables: HashMap<usize, u64> = HashMap::new();
+        truth_tables.insert(0, 0);
+        for (position, leaf) in cut.iter().enumerate() {
+            truth_tables.insert(leaf.to_owned(), Self::get_leaf_truth_table(position));
+        }
+
+        // the nodes between the cut and the variable are found before they are computed.
+        let mut variables_to_visit = vec![variable];
+        let mut variables_in_cone = HashSet::new();
+        while let Some(v) = variables_to_visit.pop() {
+            if truth_tables.contains_key(&v) || !variables_in_cone.insert(v) {
+                continue;
+            }
+            let node = &self.nodes[v];
+            assert_eq!(
+                node.get_type(),
+                AIGNodeType::And,
+                "The variables are not a cut of variable {variable}."
+            );
+            variables_to_visit.push(node.get_and_rhs0() >> 1);
+            variables_to_visit.push(node.get_and_rhs1() >> 1);
+        }
+        let mut variables_in_cone: Vec<usize> = variables_in_cone.into_iter().collect();
+        variables_in_cone.sort();
+        let get_literal_truth_table = |truth_tables: &HashMap<usize, u64>, literal: usize| {
+            let truth_table = truth_tables[&(literal >> 1)];
+            if literal & 1 == 1 {
+                !truth_table
+            } else {
+                truth_table
+            }
+        };
+        for v in variables_in_cone {
+            let node = &self.nodes[v];
+            let truth_table = get_literal_truth_table(&truth_tables, node.get_and_rhs0())
+                & get_literal_truth_table(&truth_tables, node.get_and_rhs1());
+            truth_tables.insert(v, truth_table);
+        }
+        truth_tables[&variable]
+    }
+}
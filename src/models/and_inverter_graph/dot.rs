@@ -0,0 +1,185 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::AndInverterGraph;
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn escape_dot_label(label: &str) -> String {
+        label.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    fn get_dot_label(name: String, symbol: &str) -> String {
+        if symbol.is_empty() {
+            name
+        } else {
+            format!("{name}\\n{}", Self::escape_dot_label(symbol))
+        }
+    }
+
+    /// edge from the node of the literal to the desired node, inverted literals are drawn
+    /// with a hollow dot at the head of the edge.
+    fn get_dot_edge(literal: usize, to: &str, attributes: &[&str]) -> String {
+        let mut attributes: Vec<&str> = attributes.to_vec();
+        if (literal & 1) == 1 {
+            attributes.push("arrowhead=odot");
+        }
+        if attributes.is_empty() {
+            format!("    n{} -> {to};", literal >> 1)
+        } else {
+            format!("    n{} -> {to} [{}];", literal >> 1, attributes.join(", "))
+        }
+    }
+
+    fn get_dot_string(&self, is_drawn: &[bool]) -> String {
+        let mut nodes: Vec<String> = Vec::new();
+        let mut edges: Vec<String> = Vec::new();
+
+        for (index, input_index) in self.inputs.iter().enumerate() {
+            if is_drawn[input_index.to_owned()] {
+                let node = &self.nodes[input_index.to_owned()];
+                let label = Self::get_dot_label(format!("i{index}"), node.get_input_symbol());
+                nodes.push(format!(
+                    "    n{input_index} [shape=triangle, label=\"{label}\"];"
+                ));
+            }
+        }
+        for (index, latch_index) in self.latches.iter().enumerate() {
+            if is_drawn[latch_index.to_owned()] {
+                let node = &self.nodes[latch_index.to_owned()];
+                let label = Self::get_dot_label(format!("l{index}"), node.get_latch_symbol());
+                nodes.push(format!(
+                    "    n{latch_index} [shape=box, label=\"{label}\"];"
+                ));
+                let to = format!("n{latch_index}");
+                edges.push(Self::get_dot_edge(
+                    node.get_latch_input(),
+                    &to,
+                    &["style=bold", "label=\"next\""],
+                ));
+                // uninitialized latches are reset to themselves, so they have no reset edge.
+                let reset = node.get_latch_reset();
+                if reset < 2 {
+                    edges.push(Self::get_dot_edge(
+                        reset,
+                        &to,
+                        &["style=dashed", "label=\"reset\""],
+                    ));
+                }
+            }
+        }
+        for and_index in self.ands.iter() {
+            if is_drawn[and_index.to_owned()] {
+                let node = &self.nodes[and_index.to_owned()];
+                nodes.push(format!(
+                    "    n{and_index} [shape=ellipse, label=\"{}\"];",
+                    node.get_literal()
+                ));
+                let to = format!("n{and_index}");
+                edges.push(Self::get_dot_edge(node.get_and_rhs0(), &to, &[]));
+                edges.push(Self::get_dot_edge(node.get_and_rhs1(), &to, &[]));
+            }
+        }
+
+        let roots = [
+            ("o", "black", &self.outputs),
+            ("b", "red", &self.bad),
+            ("c", "blue", &self.constraints),
+        ];
+        for (prefix, color, literals) in roots {
+            for (index, literal) in literals.iter().enumerate() {
+                if is_drawn[literal >> 1] {
+                    let node = &self.nodes[literal >> 1];
+                    let symbol = match prefix {
+                        "o" => node.get_output_symbol(),
+                        "b" => node.get_bad_symbol(),
+                        _ => node.get_constraint_symbol(),
+                    };
+                    let label = Self::get_dot_label(format!("{prefix}{index}"), symbol);
+                    nodes.push(format!(
+                        "    {prefix}{index} [shape=invtriangle, color={color}, label=\"{label}\"];"
+                    ));
+                    edges.push(Self::get_dot_edge(
+                        literal.to_owned(),
+                        &format!("{prefix}{index}"),
+                        &[],
+                    ));
+                }
+            }
+        }
+
+        // the constant is drawn only if some edge starts at it.
+        let is_constant_used = edges.iter().any(|edge| edge.starts_with("    n0 -> "));
+        if is_constant_used {
+            nodes.insert(0, String::from("    n0 [shape=box, label=\"0\"];"));
+        }
+        let mut lines = vec![String::from("digraph aig {")];
+        lines.append(&mut nodes);
+        lines.append(&mut edges);
+        lines.push(String::from("}"));
+        lines.join("\n") + "\n"
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that draws the AndInverterGraph in the DOT language of Graphviz.
+    /// Inputs are triangles, latches are boxes with bold next state edges and dashed reset
+    /// edges, and gates are ellipses labeled by their literal, and outputs, bad state
+    /// literals and invariant constraints are inverted triangles.
+    /// Inverted edges have a hollow dot at their head, and symbols are added to the labels.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aag_str("aag 3 2 0 1 1\n2\n4\n7\n6 4 2\ni0 x\no0 nand\n");
+    /// assert_eq!(
+    ///     aig.to_dot(),
+    ///     "digraph aig {\n    n1 [shape=triangle, label=\"i0\\nx\"];\n    n2 [shape=triangle, label=\"i1\"];\n    n3 [shape=ellipse, label=\"6\"];\n    o0 [shape=invtriangle, color=black, label=\"o0\\nnand\"];\n    n2 -> n3;\n    n1 -> n3;\n    n3 -> o0 [arrowhead=odot];\n}\n"
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        self.get_dot_string(&vec![true; self.nodes.len()])
+    }
+
+    /// Function that draws only the part of the AndInverterGraph that can affect the desired
+    /// literals in any cycle, in the DOT language of Graphviz.
+    /// Outputs, bad state literals and invariant constraints are drawn only if they are in
+    /// this part, see `to_dot` for the shapes used.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `desired_literals` - the literals whose cone of influence should be drawn.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // two independent latches, each one with its own bad state literal.
+    /// let aag = "aag 3 1 2 0 0 2\n2\n4 2\n6 7\n4\n6\ni0 x\nl1 toggle\nb1 toggle is 1\n";
+    /// let aig = AndInverterGraph::from_aag_str(aag);
+    /// let dot = aig.to_dot_of_cone_of_influence(&[6]);
+    /// assert!(dot.contains("n3 [shape=box, label=\"l1\\ntoggle\"];"));
+    /// assert!(dot.contains("n3 -> n3 [style=bold, label=\"next\", arrowhead=odot];"));
+    /// assert!(dot.contains("n0 -> n3 [style=dashed, label=\"reset\"];"));
+    /// assert!(!dot.contains("i0"));
+    /// assert!(!dot.contains("b0"));
+    /// ```
+    pub fn to_dot_of_cone_of_influence(&self, desired_literals: &[usize]) -> String {
+        let is_in_cone = self.get_variables_in_sequential_cone_of_influence(desired_literals);
+        self.get_dot_string(&is_in_cone)
+    }
+}
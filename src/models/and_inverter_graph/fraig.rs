@@ -0,0 +1,323 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::formulas::literal::VariableType;
+use crate::formulas::{Clause, Cube, Literal, CNF};
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::{AigBuilder, AndInverterGraph};
+use crate::solvers::sat::stateful::{StatefulSatSolver, StatefulSatSolverHint};
+use crate::solvers::sat::SatResponse;
+use rand::Rng;
+use std::collections::HashMap;
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the literal of the sat solver for an aig literal, the constant gets the variable
+    /// after the last one of the graph since variable 0 cannot be used.
+    fn get_fraig_literal(&self, aig_literal: usize) -> Literal {
+        let variable = if aig_literal < 2 {
+            self.nodes.len()
+        } else {
+            aig_literal >> 1
+        };
+        let variable: VariableType = variable.try_into().unwrap();
+        Literal::new(variable).negate_if_true(aig_literal % 2 == 1)
+    }
+
+    /// the cnf of every and gate in the graph, where inputs and latches are free.
+    fn get_fraig_cnf(&self) -> CNF {
+        let mut cnf = CNF::new();
+        cnf.add_clause(&Clause::new(&[!self.get_fraig_literal(0)]));
+        for (variable, node) in self.nodes.iter().enumerate() {
+            if node.get_type() == AIGNodeType::And {
+                let and_output = self.get_fraig_literal(variable << 1);
+                let and_input_0 = self.get_fraig_literal(node.get_and_rhs0());
+                let and_input_1 = self.get_fraig_literal(node.get_and_rhs1());
+                cnf.add_clause(&Clause::new(&[!and_output, and_input_0]));
+                cnf.add_clause(&Clause::new(&[!and_output, and_input_1]));
+                cnf.add_clause(&Clause::new(&[and_output, !and_input_0, !and_input_1]));
+            }
+        }
+        cnf
+    }
+
+    /// computes the words of all and gates, given the words of the inputs and latches.
+    pub(super) fn simulate_and_words(&self, values: &mut [u64], number_of_words: usize) {
+        for (variable, node) in self.nodes.iter().enumerate() {
+            if node.get_type() == AIGNodeType::And {
+                for word in 0..number_of_words {
+                    values[variable * number_of_words + word] =
+                        Self::get_literal_words(values, node.get_and_rhs0(), number_of_words, word)
+                            & Self::get_literal_words(
+                                values,
+                                node.get_and_rhs1(),
+                                number_of_words,
+                                word,
+                            );
+                }
+            }
+        }
+    }
+
+    /// splits every class by the words of its variables in the pattern, where variables of
+    /// opposite phase are in the same class when their words are opposite. The variables of
+    /// every resulting class are sorted, so the lowest variable of a class is first.
+    pub(super) fn refine_equivalence_classes(
+        classes: Vec<Vec<usize>>,
+        pattern: &[u64],
+        phases: &[bool],
+    ) -> Vec<Vec<usize>> {
+        let mut result = Vec::with_capacity(classes.len());
+        for class in classes {
+            let mut class_of_word: HashMap<u64, usize> = HashMap::new();
+            let mut new_classes: Vec<Vec<usize>> = Vec::new();
+            for variable in class {
+                let word = if phases[variable] {
+                    !pattern[variable]
+                } else {
+                    pattern[variable]
+                };
+                let index = *class_of_word.entry(word).or_insert_with(|| {
+                    new_classes.push(Vec::new());
+                    new_classes.len() - 1
+                });
+                new_classes[index].push(variable);
+            }
+            result.extend(
+                new_classes
+                    .into_iter()
+                    .filter(|c| c.len() > 1)
+                    .map(|mut c| {
+                        c.sort();
+                        c
+                    }),
+            );
+        }
+        result
+    }
+
+    /// marks the variables that the rebuilt graph needs, where merged and gates are
+    /// replaced by their representatives.
+    fn get_variables_used_after_fraig(&self, representatives: &HashMap<usize, usize>) -> Vec<bool> {
+        let mut is_used = vec![false; self.nodes.len()];
+        let mut variables_to_visit: Vec<usize> = self
+            .latches
+            .iter()
+            .map(|l| self.nodes[l.to_owned()].get_latch_input())
+            .chain(self.outputs.iter().copied())
+            .chain(self.bad.iter().copied())
+            .chain(self.constraints.iter().copied())
+            .map(|l| l >> 1)
+            .collect();
+        while let Some(variable) = variables_to_visit.pop() {
+            if is_used[variable] {
+                continue;
+            }
+            is_used[variable] = true;
+            let node = &self.nodes[variable];
+            if node.get_type() == AIGNodeType::And {
+                match representatives.get(&variable) {
+                    Some(representative) => variables_to_visit.push(representative >> 1),
+                    None => {
+                        variables_to_visit.push(node.get_and_rhs0() >> 1);
+                        variables_to_visit.push(node.get_and_rhs1() >> 1);
+                    }
+                }
+            }
+        }
+        is_used
+    }
+
+    /// returns true if the literals are equivalent, otherwise the values of the inputs and
+    /// latches of an assignment that shows they are different are stored in a bit of the
+    /// pattern.
+    fn are_fraig_literals_equivalent<T: StatefulSatSolver>(
+        &self,
+        solver: &mut T,
+        a: usize,
+        b: usize,
+        pattern: &mut [u64],
+        bit: usize,
+    ) -> bool {
+        let a_lit = self.get_fraig_literal(a);
+        let b_lit = self.get_fraig_literal(b);
+        for cube in [Cube::new(&[a_lit, !b_lit]), Cube::new(&[!a_lit, b_lit])] {
+            if let SatResponse::Sat { assignment } = solver.solve(Some(&cube), None) {
+                for variable in self.inputs.iter().chain(self.latches.iter()) {
+                    let sat_variable: VariableType = variable.to_owned().try_into().unwrap();
+                    if assignment.get_value(&sat_variable).unwrap_or(false) {
+                        pattern[variable.to_owned()] |= 1 << bit;
+                    }
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    /// maps every and gate that is equivalent to a lower variable to the literal of that
+    /// variable, possibly negated.
+    fn get_fraig_representatives<T: StatefulSatSolver>(
+        &self,
+        number_of_words: usize,
+    ) -> HashMap<usize, usize> {
+        // random simulation, where inputs and latches are free.
+        let mut rng = rand::thread_rng();
+        let mut values = vec![0; self.nodes.len() * number_of_words];
+        for variable in self.inputs.iter().chain(self.latches.iter()) {
+            for word in 0..number_of_words {
+                values[variable * number_of_words + word] = rng.gen();
+            }
+        }
+        self.simulate_and_words(&mut values, number_of_words);
+
+        // variables are in the same class if their words are equal or opposite.
+        let phases: Vec<bool> = (0..self.nodes.len())
+            .map(|v| values[v * number_of_words] & 1 == 1)
+            .collect();
+        let mut class_of_words: HashMap<Vec<u64>, Vec<usize>> = HashMap::new();
+        for (variable, phase) in phases.iter().enumerate() {
+            let words = values[variable * number_of_words..][..number_of_words]
+                .iter()
+                .map(|w| if *phase { !w } else { w.to_owned() })
+                .collect();
+            class_of_words.entry(words).or_default().push(variable);
+        }
+        let mut classes: Vec<Vec<usize>> = class_of_words
+            .into_values()
+            .filter(|class| class.len() > 1)
+            .collect();
+
+        let mut solver = T::new(StatefulSatSolverHint::None);
+        solver.add_cnf(&self.get_fraig_cnf());
+        let mut representatives = HashMap::new();
+        // counter examples are collected in the bits of a word before they are simulated.
+        let mut pattern = vec![0; self.nodes.len()];
+        let mut number_of_patterns = 0;
+        while let Some(class) = classes.pop() {
+            let representative = class[0];
+            let mut candidates = class[1..].to_vec();
+            let mut different = Vec::new();
+            while let Some(variable) = candidates.pop() {
+                if self.nodes[variable].get_type() != AIGNodeType::And {
+                    different.push(variable);
+                    continue;
+                }
+                let representative_literal =
+                    (representative << 1) | usize::from(phases[representative] ^ phases[variable]);
+                if self.are_fraig_literals_equivalent(
+                    &mut solver,
+                    representative_literal,
+                    variable << 1,
+                    &mut pattern,
+                    number_of_patterns,
+                ) {
+                    representatives.insert(variable, representative_literal);
+                    // the equivalence helps the following sat calls.
+                    let a = self.get_fraig_literal(representative_literal);
+                    let b = self.get_fraig_literal(variable << 1);
+                    let mut cnf = CNF::new();
+                    cnf.add_clause(&Clause::new(&[!a, b]));
+                    cnf.add_clause(&Clause::new(&[a, !b]));
+                    solver.add_cnf(&cnf);
+                    continue;
+                }
+
+                different.push(variable);
+                number_of_patterns += 1;
+                if number_of_patterns == 64 {
+                    // the patterns split the class and all of the other classes, the checked
+                    // candidates stay in the class since they may be equivalent to unchecked
+                    // ones, their counter examples separate them from the representative.
+                    self.simulate_and_words(&mut pattern, 1);
+                    let checked = std::mem::take(&mut different);
+                    classes.push([&[representative], candidates.as_slice(), &checked].concat());
+                    classes = Self::refine_equivalence_classes(classes, &pattern, &phases);
+                    candidates = match classes.iter().position(|c| c[0] == representative) {
+                        Some(index) => classes.swap_remove(index)[1..].to_vec(),
+                        None => Vec::new(),
+                    };
+                    pattern.iter_mut().for_each(|w| *w = 0);
+                    number_of_patterns = 0;
+                }
+            }
+            if different.len() > 1 {
+                different.sort();
+                classes.push(different);
+            }
+        }
+        representatives
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that merges functionally equivalent nodes of an AndInverterGraph, this is
+    /// also known as SAT sweeping or fraiging.
+    /// Inputs and latches are treated as free variables, so two nodes are merged only if
+    /// they are equivalent in every state, not only in the reachable ones.
+    /// Candidate classes of equivalent nodes are found by random simulation, and each node
+    /// of a class is compared to the lowest variable of the class using a sat solver of type
+    /// `T`. Counter examples of the comparisons are simulated in words of 64 to split the
+    /// classes.
+    /// Every and gate that is equivalent to a lower node, or to its negation, is replaced
+    /// by it, and the result is rebuilt without the and gates that are no longer used.
+    /// The inputs, latches, outputs, bad state literals and invariant constraints are kept
+    /// with their symbols. Panics if the graph has justice properties or fairness
+    /// constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use rust_formal_verification::solvers::sat::stateful::CaDiCalSolver;
+    /// // x ^ y as !(x & y) & !(!x & !y), and as !(!(x & !y) & !(!x & y)), are both outputs.
+    /// let aag = "aag 8 2 0 2 6\n2\n4\n10\n17\n6 4 2\n8 5 3\n10 9 7\n12 5 2\n14 4 3\n16 15 13\n";
+    /// let aig = AndInverterGraph::from_aag_str(aag);
+    /// let fraig = aig.fraig::<CaDiCalSolver>();
+    /// assert_eq!(
+    ///     fraig.get_aag_string(),
+    ///     "aag 5 2 0 2 3\n2\n4\n10\n10\n6 4 2\n8 5 3\n10 9 7\n"
+    /// );
+    /// ```
+    pub fn fraig<T: StatefulSatSolver>(&self) -> AndInverterGraph {
+        self.assert_no_justice_or_fairness("Fraig");
+        let representatives = self.get_fraig_representatives::<T>(32);
+        let is_used = self.get_variables_used_after_fraig(&representatives);
+
+        let mut builder = AigBuilder::new();
+        let mut literal_map = self.get_literal_map_with_copies(&mut builder, |_| true);
+        for (variable, node) in self.nodes.iter().enumerate() {
+            if is_used[variable] && node.get_type() == AIGNodeType::And {
+                literal_map[variable] = match representatives.get(&variable) {
+                    Some(representative) => {
+                        Self::map_literal(&literal_map, representative.to_owned())
+                    }
+                    None => {
+                        let rhs0 = Self::map_literal(&literal_map, node.get_and_rhs0());
+                        let rhs1 = Self::map_literal(&literal_map, node.get_and_rhs1());
+                        builder.add_and(rhs0, rhs1)
+                    }
+                };
+            }
+        }
+        self.set_next_of_copied_latches(&mut builder, &literal_map, |_| true);
+
+        self.add_copies_of_outputs_bad_and_constraints(&mut builder, |_, literal| {
+            Self::map_literal(&literal_map, literal)
+        });
+        builder.build()
+    }
+}
@@ -100,6 +100,42 @@ impl AndInverterGraph {
         self.bad.clone()
     }
 
+    /// Function that gets a vector describing the justice properties in the system.
+    /// Each justice property is a vector of literals, a trace violates the property
+    /// if all of these literals are true infinitely often.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 2 0 0 0 0 0 1 1\n2\n4\n2\n2\n5\n3\n");
+    /// assert_eq!(vec![vec![2, 5]], aig.get_justice_information());
+    /// ```
+    pub fn get_justice_information(&self) -> Vec<Vec<usize>> {
+        self.justice.clone()
+    }
+
+    /// Function that gets a vector describing the fairness constraints in the system.
+    /// The output is a vector containing usize numbers, these are the literals
+    /// that must be true infinitely often on every fair trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 2 0 0 0 0 0 1 1\n2\n4\n2\n2\n5\n3\n");
+    /// assert_eq!(vec![3], aig.get_fairness_information());
+    /// ```
+    pub fn get_fairness_information(&self) -> Vec<usize> {
+        self.fairness.clone()
+    }
+
     /// Function that gets a vector describing the constraints nodes in the system.
     /// The output is a vector containing usize numbers, these are the literals
     /// that are constraint.
@@ -0,0 +1,297 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::AndInverterGraph;
+use std::cmp::Ordering;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that describes which latches of an AndInverterGraph feed which other latches
+/// through the combinational logic, and the strongly connected components of this graph.
+///
+/// Latches are given by their position, and a latch depends on the latches that the logic of
+/// its next state reads. The components are ordered so that every component comes after the
+/// components it depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AigLatchDependencyGraph {
+    dependencies: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+    components: Vec<Vec<usize>>,
+    component_of_latch: Vec<usize>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AigLatchDependencyGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the strongly connected components of a directed graph given by the successors of
+    /// every vertex, found with Tarjan's algorithm without recursion. Every component comes
+    /// after the components that it can reach.
+    fn get_components(successors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let mut index_of_vertex: Vec<Option<usize>> = vec![None; successors.len()];
+        let mut low_link = vec![0; successors.len()];
+        let mut is_on_stack = vec![false; successors.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+        for root in 0..successors.len() {
+            if index_of_vertex[root].is_some() {
+                continue;
+            }
+            index_of_vertex[root] = Some(next_index);
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            is_on_stack[root] = true;
+            // every frame is a vertex and the position of the next successor to visit.
+            let mut frames = vec![(root, 0)];
+            while let Some((vertex, position)) = frames.pop() {
+                if let Some(successor) = successors[vertex].get(position) {
+                    let successor = successor.to_owned();
+                    frames.push((vertex, position + 1));
+                    match index_of_vertex[successor] {
+                        None => {
+                            index_of_vertex[successor] = Some(next_index);
+                            low_link[successor] = next_index;
+                            next_index += 1;
+                            stack.push(successor);
+                            is_on_stack[successor] = true;
+                            frames.push((successor, 0));
+                        }
+                        Some(index) if is_on_stack[successor] => {
+                            low_link[vertex] = low_link[vertex].min(index);
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+                if let Some((parent, _)) = frames.last() {
+                    low_link[*parent] = low_link[*parent].min(low_link[vertex]);
+                }
+                if index_of_vertex[vertex] == Some(low_link[vertex]) {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        is_on_stack[member] = false;
+                        component.push(member);
+                        if member == vertex {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    fn new(dependencies: Vec<Vec<usize>>) -> Self {
+        let mut dependents = vec![Vec::new(); dependencies.len()];
+        for (latch, latch_dependencies) in dependencies.iter().enumerate() {
+            for dependency in latch_dependencies.iter() {
+                dependents[dependency.to_owned()].push(latch);
+            }
+        }
+        // the dependencies of a latch are reached from it, so they come first.
+        let components = Self::get_components(&dependencies);
+        let mut component_of_latch = vec![0; dependencies.len()];
+        for (index, component) in components.iter().enumerate() {
+            for latch in component.iter() {
+                component_of_latch[latch.to_owned()] = index;
+            }
+        }
+        Self {
+            dependencies,
+            dependents,
+            components,
+            component_of_latch,
+        }
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that returns the number of latches in the graph.
+    pub fn get_number_of_latches(&self) -> usize {
+        self.dependencies.len()
+    }
+
+    /// Function that returns the positions of the latches that the next state of a latch
+    /// reads, in increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `latch` - the position of the latch desired.
+    pub fn get_dependencies(&self, latch: usize) -> &[usize] {
+        &self.dependencies[latch]
+    }
+
+    /// Function that returns the positions of the latches whose next state reads a latch, in
+    /// increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `latch` - the position of the latch desired.
+    pub fn get_dependents(&self, latch: usize) -> &[usize] {
+        &self.dependents[latch]
+    }
+
+    /// Function that returns the strongly connected components of the graph, every one of
+    /// them as the positions of its latches in increasing order. The components are in
+    /// topological order, every component comes after the components it depends on.
+    pub fn get_strongly_connected_components(&self) -> &[Vec<usize>] {
+        &self.components
+    }
+
+    /// Function that returns the index of the strongly connected component of a latch.
+    ///
+    /// # Arguments
+    ///
+    /// * `latch` - the position of the latch desired.
+    pub fn get_component_of_latch(&self, latch: usize) -> usize {
+        self.component_of_latch[latch]
+    }
+
+    /// Function that checks if a strongly connected component has a cycle, meaning that it
+    /// has more than one latch, or a latch that depends on itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - the index of the strongly connected component desired.
+    pub fn is_component_cyclic(&self, index: usize) -> bool {
+        let component = &self.components[index];
+        component.len() > 1 || self.dependencies[component[0]].contains(&component[0])
+    }
+
+    /// Function that returns the positions of the latches that are not on any cycle, like
+    /// the stages of a feed-forward pipeline, in increasing order.
+    pub fn get_feed_forward_latches(&self) -> Vec<usize> {
+        (0..self.get_number_of_latches())
+            .filter(|latch| !self.is_component_cyclic(self.component_of_latch[*latch]))
+            .collect()
+    }
+}
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the union of two sorted vectors without duplicates.
+    fn merge_sorted(a: &[usize], b: &[usize]) -> Vec<usize> {
+        let mut result = Vec::with_capacity(a.len() + b.len());
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                Ordering::Less => {
+                    result.push(a[i]);
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    result.push(b[j]);
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    result.push(a[i]);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        result.extend_from_slice(&a[i..]);
+        result.extend_from_slice(&b[j..]);
+        result
+    }
+
+    /// the sorted positions of the latches that every variable reads, computed in one pass
+    /// over the and gates, since a cone of influence per latch is quadratic on big graphs.
+    /// Only the variables that the next states read are computed, and the support of an and
+    /// gate is dropped once every gate that reads it has its own.
+    fn get_latch_supports_of_next_states(&self) -> Vec<Vec<usize>> {
+        let mut is_needed = vec![false; self.nodes.len()];
+        // the number of and gates and next states that read every variable.
+        let mut references = vec![0_usize; self.nodes.len()];
+        for latch_index in self.latches.iter() {
+            let next = self.nodes[latch_index.to_owned()].get_latch_input();
+            is_needed[next >> 1] = true;
+            references[next >> 1] += 1;
+        }
+        for variable in (0..self.nodes.len()).rev() {
+            let node = &self.nodes[variable];
+            if is_needed[variable] && node.get_type() == AIGNodeType::And {
+                for rhs in [node.get_and_rhs0(), node.get_and_rhs1()] {
+                    is_needed[rhs >> 1] = true;
+                    references[rhs >> 1] += 1;
+                }
+            }
+        }
+
+        let mut supports: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (position, latch_index) in self.latches.iter().enumerate() {
+            supports[latch_index.to_owned()] = vec![position];
+        }
+        for (variable, node) in self.nodes.iter().enumerate() {
+            if !is_needed[variable] || node.get_type() != AIGNodeType::And {
+                continue;
+            }
+            let rhs0 = node.get_and_rhs0() >> 1;
+            let rhs1 = node.get_and_rhs1() >> 1;
+            supports[variable] = Self::merge_sorted(&supports[rhs0], &supports[rhs1]);
+            for rhs in [rhs0, rhs1] {
+                references[rhs] -= 1;
+                if references[rhs] == 0 && self.nodes[rhs].get_type() == AIGNodeType::And {
+                    supports[rhs] = Vec::new();
+                }
+            }
+        }
+        supports
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that computes the dependency graph of the latches of an AndInverterGraph,
+    /// where a latch depends on the latches that the logic of its next state reads, and the
+    /// strongly connected components of this graph in topological order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles, a latch that stores the and of the input and the first latch,
+    /// // and a latch that stores the second.
+    /// let aig = AndInverterGraph::from_aag_str("aag 5 1 3 0 1 1\n2\n4 5\n6 10\n8 6\n8\n10 4 2\n");
+    /// let graph = aig.get_latch_dependency_graph();
+    /// assert_eq!(graph.get_dependencies(1), &[0]);
+    /// assert_eq!(graph.get_dependents(0), &[0, 1]);
+    /// assert_eq!(graph.get_strongly_connected_components(), &[vec![0], vec![1], vec![2]]);
+    /// assert!(graph.is_component_cyclic(0));
+    /// assert_eq!(graph.get_feed_forward_latches(), vec![1, 2]);
+    /// ```
+    pub fn get_latch_dependency_graph(&self) -> AigLatchDependencyGraph {
+        let supports = self.get_latch_supports_of_next_states();
+        let dependencies = self
+            .latches
+            .iter()
+            .map(|latch_index| {
+                let next = self.nodes[latch_index.to_owned()].get_latch_input();
+                supports[next >> 1].to_owned()
+            })
+            .collect();
+        AigLatchDependencyGraph::new(dependencies)
+    }
+}
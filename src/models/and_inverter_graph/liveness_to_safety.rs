@@ -0,0 +1,120 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigTraceMap, AndInverterGraph};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// adds a latch that becomes 1 in the cycle after the literal is true inside the loop,
+    /// and stays 1 from then on.
+    fn add_seen_latch(builder: &mut AigBuilder, literal: usize, is_in_loop: usize) -> usize {
+        let seen = builder.add_latch(0, Some(false));
+        let seen_now = builder.add_and(literal, is_in_loop);
+        let next = builder.add_or(seen, seen_now);
+        builder.set_latch_next(seen, next);
+        seen
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that turns the justice properties of an AndInverterGraph into bad state
+    /// literals, using the liveness to safety transformation of Biere, Artho and Schuppan.
+    /// A justice property is violated by an infinite trace, which for a finite system is a
+    /// lasso: a prefix followed by a loop in which all of its literals and all of the
+    /// fairness constraints are true at least once.
+    /// The result has an extra input, the save oracle, that chooses the first state of the
+    /// loop. That state is kept in shadow latches, and a bad state literal for each justice
+    /// property is true when the current state is equal to the saved one and every needed
+    /// literal was true since the state was saved.
+    /// The inputs and latches of the graph come first, followed by the save input, the
+    /// latch that remembers that a state was saved, the shadow latches and a latch for every
+    /// literal of the justice properties and the fairness constraints.
+    /// So a counter example of the result is a lasso of the graph after dropping the save
+    /// input and the extra latches. Its loop starts in the first cycle the save input is 1,
+    /// and the last cycle is in the same state as that one. The trace map gives the lasso in
+    /// terms of the graph, and the cycle in which its loop starts.
+    /// The invariant constraints are kept. Outputs and bad state literals are not kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles, with a justice property that it is 1 infinitely often.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0 0 0 1 0\n2 3\n1\n2\n");
+    /// let (safety, trace_map) = aig.liveness_to_safety();
+    /// assert_eq!(safety.get_input_information().len(), 1);
+    /// assert_eq!(safety.get_latch_information().len(), 4);
+    /// assert_eq!(safety.get_bad_information().len(), 1);
+    /// assert!(safety.get_justice_information().is_empty());
+    /// // the state is saved in the first cycle, so the loop starts there.
+    /// assert_eq!(trace_map.get_loop_start(&[vec![true], vec![false]]), Some(0));
+    /// ```
+    pub fn liveness_to_safety(&self) -> (AndInverterGraph, AigTraceMap) {
+        let mut builder = AigBuilder::new();
+        let mut trace_map = AigTraceMap::new(self.latches.len());
+        let inputs = self.add_copies_of_inputs(&mut builder, "");
+        let latches = self.add_copies_of_latches(&mut builder, &mut trace_map);
+        let literal_map = self.get_literals_of_copy(&mut builder, &inputs, &latches);
+        self.set_next_of_copied_latches(&mut builder, &literal_map, |_| true);
+
+        // the state is saved at most once, in the first cycle the oracle asks for it.
+        let save = builder.add_input();
+        builder.set_input_symbol(save, "l2s_save");
+        trace_map.add_cycle((0..self.inputs.len()).collect());
+        trace_map.set_loop_start_input(self.inputs.len());
+        let saved = builder.add_latch(0, Some(false));
+        builder.set_latch_symbol(saved, "l2s_saved");
+        let save_now = builder.add_and(save, saved ^ 1);
+        let is_in_loop = builder.add_or(saved, save_now);
+        builder.set_latch_next(saved, is_in_loop);
+
+        let mut is_state_equal_to_saved = 1;
+        for latch in latches.iter() {
+            let shadow = builder.add_latch(0, Some(false));
+            let next = builder.add_mux(save_now, latch.to_owned(), shadow);
+            builder.set_latch_next(shadow, next);
+            let is_equal = builder.add_xor(latch.to_owned(), shadow) ^ 1;
+            is_state_equal_to_saved = builder.add_and(is_state_equal_to_saved, is_equal);
+        }
+        let is_loop_closed = builder.add_and(saved, is_state_equal_to_saved);
+
+        let mut are_fairness_constraints_seen = 1;
+        for fairness in self.fairness.iter() {
+            let literal = Self::map_literal(&literal_map, fairness.to_owned());
+            let seen = Self::add_seen_latch(&mut builder, literal, is_in_loop);
+            are_fairness_constraints_seen = builder.add_and(are_fairness_constraints_seen, seen);
+        }
+        let is_fair_loop_closed = builder.add_and(is_loop_closed, are_fairness_constraints_seen);
+
+        for (index, justice) in self.justice.iter().enumerate() {
+            let mut bad = is_fair_loop_closed;
+            for justice_literal in justice.iter() {
+                let literal = Self::map_literal(&literal_map, justice_literal.to_owned());
+                let seen = Self::add_seen_latch(&mut builder, literal, is_in_loop);
+                bad = builder.add_and(bad, seen);
+            }
+            builder.add_bad(bad);
+            if !self.justice_symbols[index].is_empty() {
+                builder.set_bad_symbol(index, &self.justice_symbols[index]);
+            }
+        }
+
+        for constraint in self.constraints.iter() {
+            builder.add_constraint(Self::map_literal(&literal_map, constraint.to_owned()));
+        }
+        (builder.build(), trace_map)
+    }
+}
@@ -0,0 +1,263 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AndInverterGraph};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// creates a fresh input for every input or latch of the graph, except for the ones that
+    /// already have a literal, symbols of inputs and latches are kept.
+    fn add_miter_inputs(
+        &self,
+        builder: &mut AigBuilder,
+        literals: &mut [Option<usize>],
+        are_latches: bool,
+    ) -> Vec<usize> {
+        let indices = if are_latches {
+            &self.latches
+        } else {
+            &self.inputs
+        };
+        let mut result = Vec::with_capacity(indices.len());
+        for (index, literal) in indices.iter().zip(literals.iter_mut()) {
+            let literal = literal.get_or_insert_with(|| {
+                let input = builder.add_input();
+                let node = &self.nodes[index.to_owned()];
+                let symbol = if are_latches {
+                    node.get_latch_symbol()
+                } else {
+                    node.get_input_symbol()
+                };
+                if !symbol.is_empty() {
+                    builder.set_input_symbol(input, symbol);
+                }
+                input
+            });
+            result.push(literal.to_owned());
+        }
+        result
+    }
+
+    /// the literals of the second graph that are shared with the first one, by position.
+    fn get_mapped_miter_literals(
+        mapping: &[(usize, usize)],
+        first_literals: &[usize],
+        amount_in_second: usize,
+        kind: &str,
+    ) -> Vec<Option<usize>> {
+        let mut second_literals = vec![None; amount_in_second];
+        for (i, j) in mapping.iter() {
+            assert!(
+                i < &first_literals.len(),
+                "There is no {kind} {i} in the first graph."
+            );
+            assert!(
+                j < &amount_in_second,
+                "There is no {kind} {j} in the second graph."
+            );
+            assert!(
+                second_literals[j.to_owned()].is_none(),
+                "The {kind} {j} of the second graph is mapped more than once."
+            );
+            second_literals[j.to_owned()] = Some(first_literals[i.to_owned()]);
+        }
+        second_literals
+    }
+
+    fn add_miter_bad_and_constraints(
+        builder: &mut AigBuilder,
+        differences: &[usize],
+        graphs_and_maps: [(&AndInverterGraph, &[usize]); 2],
+    ) {
+        let mut bad = 0;
+        for difference in differences.iter() {
+            bad = builder.add_or(bad, difference.to_owned());
+        }
+        builder.add_bad(bad);
+        builder.set_bad_symbol(0, "miter");
+        for (graph, literal_map) in graphs_and_maps {
+            for constraint in graph.constraints.iter() {
+                let literal = Self::map_literal(literal_map, constraint.to_owned());
+                builder.add_constraint(literal);
+            }
+        }
+    }
+
+    fn get_output_differences(
+        builder: &mut AigBuilder,
+        output_mapping: &[(usize, usize)],
+        (a, a_map): (&AndInverterGraph, &[usize]),
+        (b, b_map): (&AndInverterGraph, &[usize]),
+    ) -> Vec<usize> {
+        output_mapping
+            .iter()
+            .map(|(i, j)| {
+                assert!(
+                    i < &a.outputs.len(),
+                    "There is no output {i} in the first graph."
+                );
+                assert!(
+                    j < &b.outputs.len(),
+                    "There is no output {j} in the second graph."
+                );
+                let a_output = Self::map_literal(a_map, a.outputs[i.to_owned()]);
+                let b_output = Self::map_literal(b_map, b.outputs[j.to_owned()]);
+                builder.add_xor(a_output, b_output)
+            })
+            .collect()
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that creates the product machine of two AndInverterGraphs, with a single bad
+    /// state literal that is true when some pair of matched outputs differs.
+    /// Converting the result with `FiniteStateTransitionSystem::from_aig(&miter, false)` and
+    /// proving it safe (for example with `PDR`) shows that the two graphs are sequentially
+    /// equivalent, meaning that starting from their initial states they produce the same
+    /// matched outputs for every sequence of inputs.
+    /// The inputs of the first graph come first, then the inputs of the second graph that
+    /// are not matched to an input of the first graph. The latches of the first graph come
+    /// before the latches of the second graph.
+    /// The invariant constraints of both graphs are kept, their outputs and bad state literals
+    /// are not kept. Panics if either graph has justice properties or fairness constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - the first AndInverterGraph.
+    /// * `b` - the second AndInverterGraph.
+    /// * `input_mapping` - pairs of positions of an input of `a` and an input of `b` that are
+    ///   the same input.
+    /// * `output_mapping` - pairs of positions of an output of `a` and an output of `b` that
+    ///   should always be equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles, and a negated latch that toggles and outputs its negation.
+    /// let a = AndInverterGraph::from_aag_str("aag 1 0 1 1 0\n2 3\n2\n");
+    /// let b = AndInverterGraph::from_aag_str("aag 1 0 1 1 0\n2 3 1\n3\n");
+    /// let miter = AndInverterGraph::miter(&a, &b, &[], &[(0, 0)]);
+    /// assert_eq!(
+    ///     miter.get_aag_string(),
+    ///     "aag 5 0 2 0 3 1\n2 3\n4 5 1\n11\n6 4 2\n8 5 3\n10 9 7\nb0 miter\n"
+    /// );
+    /// ```
+    pub fn miter(
+        a: &AndInverterGraph,
+        b: &AndInverterGraph,
+        input_mapping: &[(usize, usize)],
+        output_mapping: &[(usize, usize)],
+    ) -> AndInverterGraph {
+        a.assert_no_justice_or_fairness("Miter");
+        b.assert_no_justice_or_fairness("Miter");
+        let mut builder = AigBuilder::new();
+        let a_inputs = a.add_miter_inputs(&mut builder, &mut vec![None; a.inputs.len()], false);
+        let mut b_inputs =
+            Self::get_mapped_miter_literals(input_mapping, &a_inputs, b.inputs.len(), "input");
+        let b_inputs = b.add_miter_inputs(&mut builder, &mut b_inputs, false);
+
+        let mut get_latches = |graph: &AndInverterGraph| -> Vec<usize> {
+            graph
+                .latches
+                .iter()
+                .map(|latch_index| {
+                    let reset = graph.get_latch_initial_value(latch_index.to_owned());
+                    graph.add_copy_of_latch(&mut builder, latch_index.to_owned(), reset)
+                })
+                .collect()
+        };
+        let a_latches = get_latches(a);
+        let b_latches = get_latches(b);
+
+        let a_map = a.get_literals_of_copy(&mut builder, &a_inputs, &a_latches);
+        let b_map = b.get_literals_of_copy(&mut builder, &b_inputs, &b_latches);
+        for (graph, literal_map) in [(a, &a_map), (b, &b_map)] {
+            graph.set_next_of_copied_latches(&mut builder, literal_map, |_| true);
+        }
+
+        let differences =
+            Self::get_output_differences(&mut builder, output_mapping, (a, &a_map), (b, &b_map));
+        Self::add_miter_bad_and_constraints(&mut builder, &differences, [(a, &a_map), (b, &b_map)]);
+        builder.build()
+    }
+
+    /// Function that creates a combinational miter of two AndInverterGraphs, with no latches
+    /// and a single bad state literal that is true when some pair of matched outputs or
+    /// matched next states differs.
+    /// Every latch is replaced by a free input, and matched latches share that input. So
+    /// when the result is safe, the two graphs are equivalent for every state in which the
+    /// matched latches are equal, which implies that they are sequentially equivalent if
+    /// the matched latches also have the same initial values.
+    /// For purely combinational graphs, `latch_mapping` is empty and this is the same as
+    /// `miter`. Since the result has no latches, a single satisfiability check decides it.
+    /// The inputs are ordered like in `miter`, followed by the latches of the first graph and
+    /// then the latches of the second graph that are not matched.
+    /// Panics if either graph has justice properties or fairness constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - the first AndInverterGraph.
+    /// * `b` - the second AndInverterGraph.
+    /// * `input_mapping` - pairs of positions of an input of `a` and an input of `b` that are
+    ///   the same input.
+    /// * `output_mapping` - pairs of positions of an output of `a` and an output of `b` that
+    ///   should always be equal.
+    /// * `latch_mapping` - pairs of positions of a latch of `a` and a latch of `b` that
+    ///   should always be equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // an and gate and an or gate of the same inputs, they differ when exactly one is 1.
+    /// let a = AndInverterGraph::from_aag_str("aag 3 2 0 1 1\n2\n4\n6\n6 4 2\n");
+    /// let b = AndInverterGraph::from_aag_str("aag 3 2 0 1 1\n2\n4\n7\n6 5 3\n");
+    /// let miter = AndInverterGraph::combinational_miter(&a, &b, &[(0, 0), (1, 1)], &[(0, 0)], &[]);
+    /// assert_eq!(
+    ///     miter.get_aag_string(),
+    ///     "aag 7 2 0 0 5 1\n2\n4\n15\n6 4 2\n8 5 3\n10 8 6\n12 9 7\n14 13 11\nb0 miter\n"
+    /// );
+    /// ```
+    pub fn combinational_miter(
+        a: &AndInverterGraph,
+        b: &AndInverterGraph,
+        input_mapping: &[(usize, usize)],
+        output_mapping: &[(usize, usize)],
+        latch_mapping: &[(usize, usize)],
+    ) -> AndInverterGraph {
+        a.assert_no_justice_or_fairness("Combinational miter");
+        b.assert_no_justice_or_fairness("Combinational miter");
+        let mut builder = AigBuilder::new();
+        let a_inputs = a.add_miter_inputs(&mut builder, &mut vec![None; a.inputs.len()], false);
+        let mut b_inputs =
+            Self::get_mapped_miter_literals(input_mapping, &a_inputs, b.inputs.len(), "input");
+        let b_inputs = b.add_miter_inputs(&mut builder, &mut b_inputs, false);
+        let a_latches = a.add_miter_inputs(&mut builder, &mut vec![None; a.latches.len()], true);
+        let mut b_latches =
+            Self::get_mapped_miter_literals(latch_mapping, &a_latches, b.latches.len(), "latch");
+        let b_latches = b.add_miter_inputs(&mut builder, &mut b_latches, true);
+
+        let a_map = a.get_literals_of_copy(&mut builder, &a_inputs, &a_latches);
+        let b_map = b.get_literals_of_copy(&mut builder, &b_inputs, &b_latches);
+        let mut differences =
+            Self::get_output_differences(&mut builder, output_mapping, (a, &a_map), (b, &b_map));
+        for (i, j) in latch_mapping.iter() {
+            let a_next = a.nodes[a.latches[i.to_owned()]].get_latch_input();
+            let b_next = b.nodes[b.latches[j.to_owned()]].get_latch_input();
+            let a_next = Self::map_literal(&a_map, a_next);
+            let b_next = Self::map_literal(&b_map, b_next);
+            differences.push(builder.add_xor(a_next, b_next));
+        }
+        Self::add_miter_bad_and_constraints(&mut builder, &differences, [(a, &a_map), (b, &b_map)]);
+        builder.build()
+    }
+}
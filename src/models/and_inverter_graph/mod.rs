@@ -13,6 +13,9 @@ use crate::models::and_inverter_graph::aig_node::AIGNode;
 
 /// Struct that describes memory layout of the AIG.
 ///
+/// Every and gate has a higher variable number than both of its inputs, so scanning the
+/// nodes by variable number visits them in topological order.
+///
 /// implementations of many additional features can be found in sub-modules.
 pub struct AndInverterGraph {
     maximum_variable_index: usize,
@@ -36,19 +39,46 @@ pub struct AndInverterGraph {
     outputs: Vec<usize>,
     bad: Vec<usize>,
     constraints: Vec<usize>,
+    justice: Vec<Vec<usize>>,
+    fairness: Vec<usize>,
+
+    // justice properties are sets of literals, so their symbols cannot be kept in the nodes.
+    justice_symbols: Vec<String>,
 
     // comments
-    comments: String,
+    comments: Vec<u8>,
 }
 
 // ************************************************************************************************
 // rust submodule declaration, they get searched in their respective file  names
 // ************************************************************************************************
 
+pub mod aig_parse_error;
+pub mod aig_simulation_error;
+pub mod balancing;
+pub mod bit_parallel_simulation;
+pub mod blif;
+pub mod builder;
+pub mod cone_of_influence;
 pub mod construction;
 pub mod conversion;
+pub mod cuts;
+pub mod dot;
+pub mod fraig;
 pub mod getting;
+pub mod latch_dependency_graph;
+pub mod liveness_to_safety;
+pub mod miter;
+pub mod phase_abstraction;
+pub mod reset_normalization;
+pub mod rewriting;
+pub mod signal_correspondence;
 pub mod simulation;
+pub mod stats;
+pub mod temporal_decomposition;
+pub mod ternary_simulation;
+pub mod trace_map;
+pub mod verilog;
 
 mod aig_node;
 
@@ -56,4 +86,12 @@ mod aig_node;
 // re-exports of structs in these modules to simplify paths for other imports
 // ************************************************************************************************
 
-// pub use simulation::AIGSimulationResult;
+pub use aig_parse_error::AigParseError;
+pub use aig_simulation_error::AigSimulationError;
+pub use bit_parallel_simulation::AIGBitParallelSimulationResult;
+pub use builder::AigBuilder;
+pub use latch_dependency_graph::AigLatchDependencyGraph;
+pub use simulation::AIGSimulationResult;
+pub use stats::AigStats;
+pub use ternary_simulation::TernaryValue;
+pub use trace_map::AigTraceMap;
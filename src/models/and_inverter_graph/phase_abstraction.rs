@@ -0,0 +1,240 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigTraceMap, AndInverterGraph, TernaryValue};
+use std::collections::HashMap;
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the smallest number of cycles after which every latch that is never X in the loop of
+    /// ternary states has the same value again.
+    fn get_period_of_ternary_loop(loop_states: &[Vec<TernaryValue>]) -> usize {
+        let length = loop_states.len();
+        let known_positions: Vec<usize> = (0..loop_states[0].len())
+            .filter(|p| {
+                loop_states
+                    .iter()
+                    .all(|s| s[p.to_owned()] != TernaryValue::X)
+            })
+            .collect();
+        (1..=length)
+            .filter(|period| length.is_multiple_of(*period))
+            .find(|period| {
+                (0..length).all(|i| {
+                    let later = &loop_states[(i + period) % length];
+                    known_positions
+                        .iter()
+                        .all(|p| loop_states[i][*p] == later[*p])
+                })
+            })
+            .unwrap()
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that finds latches that oscillate with a fixed period, like the phases of a
+    /// clock, using three valued simulation from the initial state where all inputs are X.
+    /// Once the ternary states repeat, the latches that are never X in the loop have the
+    /// same values in every reachable state at the same point of the loop.
+    /// The result is the number of cycles before the loop and the smallest period of these
+    /// latches, or None if they do not oscillate or the simulation does not repeat within
+    /// the number of cycles given.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `maximum_number_of_cycles` - the number of cycles after which to give up.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0\n2 3\n");
+    /// assert_eq!(aig.get_phase_period(10), Some((0, 2)));
+    /// // a latch that becomes 1, and a latch that toggles after that.
+    /// let aig = AndInverterGraph::from_aag_str("aag 5 0 2 0 3\n2 1\n4 11\n6 5 2\n8 4 3\n10 9 7\n");
+    /// assert_eq!(aig.get_phase_period(10), Some((1, 2)));
+    /// // a latch that stores the input does not oscillate.
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 1 1 0 0\n2\n4 2\n");
+    /// assert_eq!(aig.get_phase_period(10), None);
+    /// ```
+    pub fn get_phase_period(&self, maximum_number_of_cycles: usize) -> Option<(usize, usize)> {
+        let mut states: Vec<Vec<TernaryValue>> = Vec::new();
+        let mut cycle_of_state: HashMap<Vec<TernaryValue>, usize> = HashMap::new();
+        let mut latch_values = self.get_initial_ternary_latch_values(&HashMap::new());
+        let no_inputs = HashMap::new();
+        for cycle in 0..=maximum_number_of_cycles {
+            let state: Vec<TernaryValue> = self.latches.iter().map(|l| latch_values[l]).collect();
+            if let Some(start) = cycle_of_state.get(&state) {
+                let period = Self::get_period_of_ternary_loop(&states[start.to_owned()..]);
+                return if period > 1 {
+                    Some((start.to_owned(), period))
+                } else {
+                    None
+                };
+            }
+            cycle_of_state.insert(state.to_owned(), cycle);
+            states.push(state);
+            let values = self.evaluate_ternary(&no_inputs, &latch_values);
+            latch_values = self.get_next_ternary_latch_values(&values);
+        }
+        None
+    }
+
+    /// Function that unfolds an AndInverterGraph so that every cycle of the result is a
+    /// number of consecutive cycles of the graph, called phases.
+    /// The result has a copy of the inputs for every phase, ordered by phase, and the same
+    /// latches, that hold the state at the start of every cycle of the result. A bad state
+    /// literal of the result is true when the bad state literal of the graph is true in some
+    /// phase, and the invariant constraints hold up to that phase.
+    /// The invariant constraints of the first phase are kept, and one more constraint says
+    /// that the constraints of the other phases hold, unless a bad state literal is true.
+    /// So the result is unsafe exactly when the graph is, and the trace map gives its
+    /// counter examples in terms of the graph.
+    /// The outputs of the first phase and the symbols are kept, the symbols of inputs get
+    /// the suffix `_phase<phase>`. Panics if the graph has justice properties or fairness
+    /// constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `number_of_phases` - the number of cycles of the graph in every cycle of the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles, bad when it is 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0 1\n2 3\n2\n");
+    /// let (unfolded, trace_map) = aig.unfold(2);
+    /// // after 2 cycles the latch is back to 0, and it is 1 in one of the phases.
+    /// assert_eq!(unfolded.get_aag_string(), "aag 1 0 1 0 0 1\n2 2\n1\n");
+    /// assert_eq!(trace_map.get_number_of_cycles_per_cycle(), 2);
+    /// ```
+    pub fn unfold(&self, number_of_phases: usize) -> (AndInverterGraph, AigTraceMap) {
+        self.assert_no_justice_or_fairness("Unfolding");
+        assert!(number_of_phases > 0, "There must be at least one phase.");
+        let mut builder = AigBuilder::new();
+        let mut trace_map = AigTraceMap::new(self.latches.len());
+        let inputs_of_phase: Vec<Vec<usize>> = (0..number_of_phases)
+            .map(|phase| {
+                let first = phase * self.inputs.len();
+                trace_map.add_cycle((first..first + self.inputs.len()).collect());
+                self.add_copies_of_inputs(&mut builder, &format!("_phase{phase}"))
+            })
+            .collect();
+        let latches = self.add_copies_of_latches(&mut builder, &mut trace_map);
+
+        let mut state = latches.to_owned();
+        let mut literal_map_of_first_phase = Vec::new();
+        // the constraints of the first phase are constraints of the result.
+        let mut are_constraints_met = 1;
+        let mut bad = vec![0; self.bad.len()];
+        for (phase, inputs) in inputs_of_phase.iter().enumerate() {
+            let literal_map = self.get_literals_of_copy(&mut builder, inputs, &state);
+            let map_literal = |literal: &usize| Self::map_literal(&literal_map, *literal);
+            if phase > 0 {
+                for constraint in self.constraints.iter() {
+                    are_constraints_met =
+                        builder.add_and(are_constraints_met, map_literal(constraint));
+                }
+            }
+            for (bad_index, bad_literal) in self.bad.iter().enumerate() {
+                let is_bad_now = builder.add_and(map_literal(bad_literal), are_constraints_met);
+                bad[bad_index] = builder.add_or(bad[bad_index], is_bad_now);
+            }
+            state = self
+                .latches
+                .iter()
+                .map(|l| map_literal(&self.nodes[l.to_owned()].get_latch_input()))
+                .collect();
+            if phase == 0 {
+                literal_map_of_first_phase = literal_map;
+            }
+        }
+        for (latch, next) in latches.iter().zip(state.iter()) {
+            builder.set_latch_next(latch.to_owned(), next.to_owned());
+        }
+
+        let map_literal =
+            |literal: &usize| Self::map_literal(&literal_map_of_first_phase, *literal);
+        for (index, output_literal) in self.outputs.iter().enumerate() {
+            builder.add_output(map_literal(output_literal));
+            let symbol = self.nodes[output_literal >> 1].get_output_symbol();
+            if !symbol.is_empty() {
+                builder.set_output_symbol(index, symbol);
+            }
+        }
+        let mut is_some_bad = 0;
+        for (index, bad_literal) in self.bad.iter().enumerate() {
+            builder.add_bad(bad[index]);
+            is_some_bad = builder.add_or(is_some_bad, bad[index]);
+            let symbol = self.nodes[bad_literal >> 1].get_bad_symbol();
+            if !symbol.is_empty() {
+                builder.set_bad_symbol(index, symbol);
+            }
+        }
+        for (index, constraint_literal) in self.constraints.iter().enumerate() {
+            builder.add_constraint(map_literal(constraint_literal));
+            let symbol = self.nodes[constraint_literal >> 1].get_constraint_symbol();
+            if !symbol.is_empty() {
+                builder.set_constraint_symbol(index, symbol);
+            }
+        }
+        // the trace may end in a phase after which the constraints no longer hold.
+        let are_later_constraints_met = builder.add_or(are_constraints_met, is_some_bad);
+        if are_later_constraints_met != 1 {
+            builder.add_constraint(are_later_constraints_met);
+        }
+        (builder.build(), trace_map)
+    }
+
+    /// Function that applies phase abstraction to an AndInverterGraph, the graph is
+    /// unfolded by the period of its oscillating latches found with `get_phase_period`.
+    /// The cycles before the loop of ternary states are first removed with
+    /// `temporal_decomposition`. In the result these latches have the same value at the
+    /// start of every cycle, so other simplifications can treat them as constants, and the
+    /// trace map gives the counter examples of the result in terms of the graph.
+    /// The result is None if there are no oscillating latches.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `maximum_number_of_cycles` - the number of cycles of ternary simulation after which
+    ///   to give up.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles and a latch that is set by the input when the first is 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 4 1 2 0 1 1\n2\n4 5\n6 8\n6\n8 4 2\n");
+    /// let (unfolded, trace_map) = aig.phase_abstraction(10).unwrap();
+    /// // the cycle before the loop of ternary states is removed first.
+    /// assert_eq!(trace_map.get_number_of_prefix_cycles(), 1);
+    /// assert_eq!(trace_map.get_number_of_cycles_per_cycle(), 2);
+    /// // the input and its copy for the first cycle, in both phases, come before the latches.
+    /// let constant_latches = unfolded.get_constant_latches(10).unwrap();
+    /// assert_eq!(constant_latches.get(&5), Some(&true));
+    /// ```
+    pub fn phase_abstraction(
+        &self,
+        maximum_number_of_cycles: usize,
+    ) -> Option<(AndInverterGraph, AigTraceMap)> {
+        let (prefix, period) = self.get_phase_period(maximum_number_of_cycles)?;
+        if prefix == 0 {
+            return Some(self.unfold(period));
+        }
+        let (decomposed, decomposition_map) = self.temporal_decomposition(prefix);
+        let (unfolded, unfold_map) = decomposed.unfold(period);
+        Some((unfolded, decomposition_map.compose(&unfold_map)))
+    }
+}
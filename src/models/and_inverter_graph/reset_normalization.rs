@@ -0,0 +1,96 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigTraceMap, AndInverterGraph};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that normalizes the initial values of the latches of an AndInverterGraph,
+    /// so that every latch of the result has the initial value 0.
+    /// A latch with the initial value 1 is replaced by a latch that holds its negation, so
+    /// the logic that reads it and its next state are negated.
+    /// An uninitialized latch gets its value from a new input in the first cycle, a new latch
+    /// that is 0 only in the first cycle chooses between the two. The reachable states of
+    /// the latches of the graph are the same, and the trace map gives the counter examples of
+    /// the result in terms of the graph.
+    /// The inputs of the graph come first, followed by the inputs of the initial values of
+    /// uninitialized latches with the suffix `_init`. The latches of the graph come first,
+    /// followed by the new latch if there are uninitialized latches. Outputs, bad state
+    /// literals, invariant constraints and their symbols are kept, and so are the symbols of
+    /// inputs and latches, even though the latches with the initial value 1 hold the negated
+    /// value. Panics if the graph has justice properties or fairness constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that starts at 1 and keeps its value, bad when it is 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0 1\n2 2 1\n2\n");
+    /// let (normalized, _) = aig.normalize_resets();
+    /// // the latch holds the negation, so it is bad when it is 0.
+    /// assert_eq!(normalized.get_aag_string(), "aag 1 0 1 0 0 1\n2 2\n3\n");
+    /// ```
+    pub fn normalize_resets(&self) -> (AndInverterGraph, AigTraceMap) {
+        self.assert_no_justice_or_fairness("Reset normalization");
+        let mut builder = AigBuilder::new();
+        let mut trace_map = AigTraceMap::new(self.latches.len());
+        let inputs = self.add_copies_of_inputs(&mut builder, "");
+        trace_map.add_cycle((0..self.inputs.len()).collect());
+        let latches: Vec<usize> = self
+            .latches
+            .iter()
+            .map(|latch_index| self.add_copy_of_latch(&mut builder, *latch_index, Some(false)))
+            .collect();
+
+        let mut number_of_inputs = self.inputs.len();
+        let mut is_initialized = None;
+        let mut current_state: Vec<usize> = Vec::with_capacity(self.latches.len());
+        for (latch, latch_index) in latches.iter().zip(self.latches.iter()) {
+            let reset = self.get_latch_initial_value(latch_index.to_owned());
+            trace_map.add_initial_latch_from_input(reset, number_of_inputs);
+            current_state.push(match reset {
+                Some(false) => latch.to_owned(),
+                Some(true) => latch ^ 1,
+                None => {
+                    let input = builder.add_input();
+                    let symbol = self.nodes[latch_index.to_owned()].get_latch_symbol();
+                    if !symbol.is_empty() {
+                        builder.set_input_symbol(input, &format!("{symbol}_init"));
+                    }
+                    number_of_inputs += 1;
+                    let is_initialized =
+                        *is_initialized.get_or_insert_with(|| builder.add_latch(1, Some(false)));
+                    builder.add_mux(is_initialized, latch.to_owned(), input)
+                }
+            });
+        }
+        let literal_map = self.get_literals_of_copy(&mut builder, &inputs, &current_state);
+        for (latch, latch_index) in latches.iter().zip(self.latches.iter()) {
+            let next = self.nodes[latch_index.to_owned()].get_latch_input();
+            let next = Self::map_literal(&literal_map, next);
+            // the latches that start at 1 hold the negated value.
+            let next = if self.get_latch_initial_value(latch_index.to_owned()) == Some(true) {
+                next ^ 1
+            } else {
+                next
+            };
+            builder.set_latch_next(latch.to_owned(), next);
+        }
+
+        self.add_copies_of_outputs_bad_and_constraints(&mut builder, |_, literal| {
+            Self::map_literal(&literal_map, literal)
+        });
+        (builder.build(), trace_map)
+    }
+}
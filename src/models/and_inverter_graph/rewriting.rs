@@ -0,0 +1,492 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::{AigBuilder, AndInverterGraph};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+// ************************************************************************************************
+// constants
+// ************************************************************************************************
+
+// structures up to this number of and gates cover about 29000 of the 65536 functions of 4
+// variables, and computing them takes a fraction of a second.
+const MAXIMUM_STRUCTURE_COST: usize = 7;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// a small and inverter graph over 4 variables, literals 0 and 1 are the constants, literals
+/// 2 to 9 are the variables and their negations, and the i-th and gate has variable 5 + i.
+#[derive(Clone)]
+struct RewritingStructure {
+    ands: Vec<(usize, usize)>,
+    output: usize,
+}
+
+/// the graph while it is being rewritten. Nodes that are rewritten stay in the graph with
+/// a replacement literal, and new and gates come after the variables of the original graph.
+struct RewritingGraph {
+    number_of_original_variables: usize,
+    fanins: Vec<(usize, usize)>,
+    is_and: Vec<bool>,
+    references: Vec<usize>,
+    replacements: Vec<Option<usize>>,
+    and_of_inputs: HashMap<(usize, usize), usize>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl RewritingStructure {
+    /// the conjunction of two structures, and gates of the second one that are already in the
+    /// first one are shared.
+    fn conjunction(a: &Self, b: &Self) -> Self {
+        let mut ands = a.ands.clone();
+        let mut and_of_inputs: HashMap<(usize, usize), usize> = ands
+            .iter()
+            .enumerate()
+            .map(|(i, inputs)| (inputs.to_owned(), (5 + i) << 1))
+            .collect();
+        let mut add_and = |ands: &mut Vec<(usize, usize)>, x: usize, y: usize| {
+            let inputs = (x.max(y), x.min(y));
+            *and_of_inputs.entry(inputs).or_insert_with(|| {
+                ands.push(inputs);
+                (4 + ands.len()) << 1
+            })
+        };
+        let map_literal = |literal_map: &[usize], literal: usize| {
+            if literal < 10 {
+                literal
+            } else {
+                literal_map[(literal >> 1) - 5] ^ (literal & 1)
+            }
+        };
+
+        let mut literal_map = Vec::with_capacity(b.ands.len());
+        for (x, y) in b.ands.iter() {
+            let x = map_literal(&literal_map, x.to_owned());
+            let y = map_literal(&literal_map, y.to_owned());
+            literal_map.push(add_and(&mut ands, x, y));
+        }
+        let output = add_and(&mut ands, a.output, map_literal(&literal_map, b.output));
+        Self { ands, output }
+    }
+
+    /// adds a structure and its negation to the library.
+    fn insert_into_library(
+        library: &mut HashMap<u16, RewritingStructure>,
+        truth_tables_of_cost: &mut [Vec<u16>],
+        truth_table: u16,
+        structure: Self,
+        cost: usize,
+    ) {
+        let mut negation = structure.clone();
+        negation.output ^= 1;
+        library.insert(truth_table, structure);
+        library.insert(!truth_table, negation);
+        truth_tables_of_cost[cost].push(truth_table);
+        truth_tables_of_cost[cost].push(!truth_table);
+    }
+
+    /// the smallest structure found for every function of 4 variables, functions are keyed
+    /// by their truth table. Structures are built from cheaper ones by increasing cost.
+    fn get_library() -> &'static HashMap<u16, RewritingStructure> {
+        static LIBRARY: OnceLock<HashMap<u16, RewritingStructure>> = OnceLock::new();
+        LIBRARY.get_or_init(|| {
+            let mut library = HashMap::new();
+            let mut truth_tables_of_cost: Vec<Vec<u16>> =
+                vec![Vec::new(); MAXIMUM_STRUCTURE_COST + 1];
+            library.insert(
+                0,
+                Self {
+                    ands: Vec::new(),
+                    output: 0,
+                },
+            );
+            library.insert(
+                u16::MAX,
+                Self {
+                    ands: Vec::new(),
+                    output: 1,
+                },
+            );
+            for (i, truth_table) in [0xAAAA, 0xCCCC, 0xF0F0, 0xFF00].into_iter().enumerate() {
+                let structure = Self {
+                    ands: Vec::new(),
+                    output: (i + 1) << 1,
+                };
+                Self::insert_into_library(
+                    &mut library,
+                    &mut truth_tables_of_cost,
+                    truth_table,
+                    structure,
+                    0,
+                );
+            }
+            for cost in 1..=MAXIMUM_STRUCTURE_COST {
+                for cost_of_a in 0..cost {
+                    let cost_of_b = cost - 1 - cost_of_a;
+                    if cost_of_a > cost_of_b {
+                        break;
+                    }
+                    let a_truth_tables = truth_tables_of_cost[cost_of_a].clone();
+                    let b_truth_tables = truth_tables_of_cost[cost_of_b].clone();
+                    for a in a_truth_tables.iter() {
+                        for b in b_truth_tables.iter() {
+                            if library.contains_key(&(a & b)) {
+                                continue;
+                            }
+                            let structure = Self::conjunction(&library[a], &library[b]);
+                            Self::insert_into_library(
+                                &mut library,
+                                &mut truth_tables_of_cost,
+                                a & b,
+                                structure,
+                                cost,
+                            );
+                        }
+                    }
+                }
+            }
+            library
+        })
+    }
+
+    /// the literal of a literal of the structure, given the literals of its variables and of
+    /// its and gates, or None if the and gate it belongs to is not known.
+    fn get_literal(literal: usize, leaves: &[usize], ands: &[Option<usize>]) -> Option<usize> {
+        match literal {
+            0 | 1 => Some(literal),
+            2..=9 => Some(leaves[(literal >> 1) - 1] ^ (literal & 1)),
+            _ => ands[(literal >> 1) - 5].map(|l| l ^ (literal & 1)),
+        }
+    }
+}
+
+impl RewritingGraph {
+    fn new(aig: &AndInverterGraph) -> Self {
+        let mut graph = Self {
+            number_of_original_variables: aig.nodes.len(),
+            fanins: vec![(0, 0); aig.nodes.len()],
+            is_and: vec![false; aig.nodes.len()],
+            references: vec![0; aig.nodes.len()],
+            replacements: vec![None; aig.nodes.len()],
+            and_of_inputs: HashMap::new(),
+        };
+        for (variable, node) in aig.nodes.iter().enumerate() {
+            if node.get_type() == AIGNodeType::And {
+                let (rhs0, rhs1) = (node.get_and_rhs0(), node.get_and_rhs1());
+                graph.fanins[variable] = (rhs0, rhs1);
+                graph.is_and[variable] = true;
+                graph.references[rhs0 >> 1] += 1;
+                graph.references[rhs1 >> 1] += 1;
+                graph
+                    .and_of_inputs
+                    .insert((rhs0.max(rhs1), rhs0.min(rhs1)), variable);
+            }
+        }
+        for root in aig.get_combinational_roots() {
+            graph.references[root >> 1] += 1;
+        }
+        graph
+    }
+
+    /// the literal that a literal was replaced with.
+    fn resolve(&self, literal: usize) -> usize {
+        let mut literal = literal;
+        while let Some(replacement) = self.replacements[literal >> 1] {
+            literal = replacement ^ (literal & 1);
+        }
+        literal
+    }
+
+    fn get_fanin_variables(&self, variable: usize) -> [usize; 2] {
+        let (rhs0, rhs1) = self.fanins[variable];
+        [self.resolve(rhs0) >> 1, self.resolve(rhs1) >> 1]
+    }
+
+    /// removes the references of an and gate to its fanins, and so on for every fanin that is
+    /// no longer referenced. Returns the number of and gates that are no longer referenced.
+    fn dereference(&mut self, variable: usize) -> usize {
+        let mut number_of_removed_ands = 0;
+        let mut variables_to_visit = vec![variable];
+        while let Some(v) = variables_to_visit.pop() {
+            for fanin in self.get_fanin_variables(v) {
+                self.references[fanin] -= 1;
+                if self.references[fanin] == 0 && self.is_and[fanin] {
+                    number_of_removed_ands += 1;
+                    variables_to_visit.push(fanin);
+                }
+            }
+        }
+        number_of_removed_ands
+    }
+
+    /// undoes `dereference`.
+    fn reference(&mut self, variable: usize) {
+        let mut variables_to_visit = vec![variable];
+        while let Some(v) = variables_to_visit.pop() {
+            for fanin in self.get_fanin_variables(v) {
+                self.references[fanin] += 1;
+                if self.references[fanin] == 1 && self.is_and[fanin] {
+                    variables_to_visit.push(fanin);
+                }
+            }
+        }
+    }
+
+    /// the literal of an existing and gate that can be used when rewriting the given
+    /// variable. Only and gates that are still referenced and that come before the variable
+    /// in topological order are used, so the graph stays acyclic.
+    fn find_and(&self, a: usize, b: usize, variable: usize) -> Option<usize> {
+        let (rhs0, rhs1) = (a.max(b), a.min(b));
+        if rhs1 == 0 || rhs0 == (rhs1 ^ 1) {
+            return Some(0);
+        } else if rhs1 == 1 || rhs0 == rhs1 {
+            return Some(rhs0);
+        }
+        let found = self.and_of_inputs.get(&(rhs0, rhs1))?.to_owned();
+        let is_before = found <= variable || found >= self.number_of_original_variables;
+        if is_before && self.references[found] > 0 {
+            Some(found << 1)
+        } else {
+            None
+        }
+    }
+
+    fn add_and(&mut self, a: usize, b: usize, variable: usize) -> usize {
+        if let Some(literal) = self.find_and(a, b, variable) {
+            return literal;
+        }
+        let new_variable = self.fanins.len();
+        self.fanins.push((a, b));
+        self.is_and.push(true);
+        self.references.push(0);
+        self.replacements.push(None);
+        self.references[a >> 1] += 1;
+        self.references[b >> 1] += 1;
+        self.and_of_inputs
+            .insert((a.max(b), a.min(b)), new_variable);
+        new_variable << 1
+    }
+
+    /// the number of and gates that a structure needs on top of the ones in the graph, or
+    /// None if it would use the variable that it should replace.
+    fn get_number_of_new_ands(
+        &self,
+        structure: &RewritingStructure,
+        leaves: &[usize],
+        variable: usize,
+    ) -> Option<usize> {
+        let mut ands: Vec<Option<usize>> = Vec::with_capacity(structure.ands.len());
+        let mut number_of_new_ands = 0;
+        for (a, b) in structure.ands.iter() {
+            let a = RewritingStructure::get_literal(a.to_owned(), leaves, &ands);
+            let b = RewritingStructure::get_literal(b.to_owned(), leaves, &ands);
+            let literal = match (a, b) {
+                (Some(a), Some(b)) => self.find_and(a, b, variable),
+                (Some(0), None) | (None, Some(0)) => Some(0),
+                _ => None,
+            };
+            match literal {
+                Some(l) if l >> 1 == variable => return None,
+                None => number_of_new_ands += 1,
+                Some(_) => {}
+            }
+            ands.push(literal);
+        }
+        match RewritingStructure::get_literal(structure.output, leaves, &ands) {
+            Some(l) if l >> 1 == variable => None,
+            _ => Some(number_of_new_ands),
+        }
+    }
+
+    fn add_structure(
+        &mut self,
+        structure: &RewritingStructure,
+        leaves: &[usize],
+        variable: usize,
+    ) -> usize {
+        let mut ands: Vec<Option<usize>> = Vec::with_capacity(structure.ands.len());
+        for (a, b) in structure.ands.iter() {
+            let a = RewritingStructure::get_literal(a.to_owned(), leaves, &ands).unwrap();
+            let b = RewritingStructure::get_literal(b.to_owned(), leaves, &ands).unwrap();
+            let literal = self.add_and(a, b, variable);
+            ands.push(Some(literal));
+        }
+        RewritingStructure::get_literal(structure.output, leaves, &ands).unwrap()
+    }
+
+    /// the number of and gates saved by implementing the variable with a structure over the
+    /// leaves, or None if the structure cannot be used.
+    fn get_rewriting_gain(
+        &mut self,
+        structure: &RewritingStructure,
+        leaves: &[usize],
+        variable: usize,
+    ) -> Option<usize> {
+        // the leaves are referenced so the removed and gates are the ones above the cut.
+        for leaf in leaves.iter() {
+            self.references[leaf >> 1] += 1;
+        }
+        let number_of_removed_ands = 1 + self.dereference(variable);
+        let number_of_new_ands = self.get_number_of_new_ands(structure, leaves, variable);
+        self.reference(variable);
+        for leaf in leaves.iter() {
+            self.references[leaf >> 1] -= 1;
+        }
+        number_of_removed_ands.checked_sub(number_of_new_ands?)
+    }
+
+    fn replace(&mut self, structure: &RewritingStructure, leaves: &[usize], variable: usize) {
+        for leaf in leaves.iter() {
+            self.references[leaf >> 1] += 1;
+        }
+        self.dereference(variable);
+        let literal = self.add_structure(structure, leaves, variable);
+        for leaf in leaves.iter() {
+            self.references[leaf >> 1] -= 1;
+        }
+        self.references[literal >> 1] += self.references[variable];
+        self.references[variable] = 0;
+        self.replacements[variable] = Some(literal);
+    }
+}
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the literals that are used outside of the and gates.
+    pub(super) fn get_combinational_roots(&self) -> Vec<usize> {
+        self.latches
+            .iter()
+            .map(|l| self.nodes[l.to_owned()].get_latch_input())
+            .chain(self.outputs.iter().copied())
+            .chain(self.bad.iter().copied())
+            .chain(self.constraints.iter().copied())
+            .collect()
+    }
+
+    /// adds the and gates of the rewritten graph that a literal depends on to the builder.
+    fn add_rewritten_literal(
+        builder: &mut AigBuilder,
+        graph: &RewritingGraph,
+        literal_map: &mut [Option<usize>],
+        literal: usize,
+    ) -> usize {
+        let literal = graph.resolve(literal);
+        // an explicit stack, since the graph can be deeper than the call stack.
+        let mut variables_to_visit = vec![literal >> 1];
+        while let Some(variable) = variables_to_visit.last().copied() {
+            if literal_map[variable].is_some() {
+                variables_to_visit.pop();
+                continue;
+            }
+            let (rhs0, rhs1) = graph.fanins[variable];
+            let (rhs0, rhs1) = (graph.resolve(rhs0), graph.resolve(rhs1));
+            match (literal_map[rhs0 >> 1], literal_map[rhs1 >> 1]) {
+                (Some(a), Some(b)) => {
+                    let a = a ^ (rhs0 & 1);
+                    let b = b ^ (rhs1 & 1);
+                    literal_map[variable] = Some(builder.add_and(a, b));
+                    variables_to_visit.pop();
+                }
+                (None, _) => variables_to_visit.push(rhs0 >> 1),
+                (_, None) => variables_to_visit.push(rhs1 >> 1),
+            }
+        }
+        literal_map[literal >> 1].unwrap() ^ (literal & 1)
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that rewrites an AndInverterGraph to reduce the number of and gates.
+    /// Every and gate, in topological order, is compared to the smallest known structures of
+    /// the functions of its cuts with up to 4 variables. A structure replaces the gate when
+    /// the and gates that only the gate uses are more than the and gates the structure adds,
+    /// counting gates that are already in the graph as free.
+    /// Only the combinational logic changes, so the inputs, latches and their initial
+    /// values stay the same, and so does the sequential behavior. The symbols of inputs,
+    /// latches, outputs, bad state literals and invariant constraints are kept. Panics if
+    /// the graph has justice properties or fairness constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // (x & y) | (x & !y) with three and gates, which is just x.
+    /// let aig = AndInverterGraph::from_aag_str("aag 5 2 0 1 3\n2\n4\n11\n6 4 2\n8 5 2\n10 9 7\n");
+    /// assert_eq!(aig.rewrite().get_aag_string(), "aag 2 2 0 1 0\n2\n4\n2\n");
+    /// ```
+    pub fn rewrite(&self) -> AndInverterGraph {
+        self.assert_no_justice_or_fairness("Rewriting");
+        let library = RewritingStructure::get_library();
+        let cuts = self.get_k_feasible_cuts(4, 8);
+        let mut graph = RewritingGraph::new(self);
+        for (variable, node) in self.nodes.iter().enumerate() {
+            if node.get_type() != AIGNodeType::And || graph.references[variable] == 0 {
+                continue;
+            }
+            let mut best: Option<(usize, &RewritingStructure, Vec<usize>)> = None;
+            // the first cut is the variable itself.
+            for cut in cuts[variable].iter().skip(1) {
+                let truth_table = self.get_cut_truth_table(variable, cut) as u16;
+                let structure = match library.get(&truth_table) {
+                    Some(structure) => structure,
+                    None => continue,
+                };
+                // structures only depend on the variables of the function.
+                let leaves: Vec<usize> = (0..4)
+                    .map(|i| cut.get(i).map_or(0, |leaf| graph.resolve(leaf << 1)))
+                    .collect();
+                // leaves that were removed by an earlier rewriting are not used again.
+                if leaves
+                    .iter()
+                    .any(|l| l > &1 && graph.references[l >> 1] == 0)
+                {
+                    continue;
+                }
+                if let Some(gain) = graph.get_rewriting_gain(structure, &leaves, variable) {
+                    if gain > 0 && best.as_ref().is_none_or(|(g, _, _)| gain > *g) {
+                        best = Some((gain, structure, leaves));
+                    }
+                }
+            }
+            if let Some((_, structure, leaves)) = best {
+                graph.replace(structure, &leaves, variable);
+            }
+        }
+
+        let mut builder = AigBuilder::new();
+        let copies = self.get_literal_map_with_copies(&mut builder, |_| true);
+        let mut literal_map = vec![None; graph.fanins.len()];
+        literal_map[0] = Some(0);
+        for variable in self.inputs.iter().chain(self.latches.iter()) {
+            literal_map[variable.to_owned()] = Some(copies[variable.to_owned()]);
+        }
+        let mut map_literal = |builder: &mut AigBuilder, literal: usize| {
+            Self::add_rewritten_literal(builder, &graph, &mut literal_map, literal)
+        };
+        for latch_index in self.latches.iter() {
+            let next = self.nodes[latch_index.to_owned()].get_latch_input();
+            let latch = map_literal(&mut builder, latch_index << 1);
+            let next = map_literal(&mut builder, next);
+            builder.set_latch_next(latch, next);
+        }
+
+        self.add_copies_of_outputs_bad_and_constraints(&mut builder, map_literal);
+        builder.build()
+    }
+}
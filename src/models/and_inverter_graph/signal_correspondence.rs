@@ -0,0 +1,406 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::formulas::literal::VariableType;
+use crate::formulas::{Clause, Cube, Literal, CNF};
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::{AigBuilder, AndInverterGraph};
+use crate::models::FiniteStateTransitionSystem;
+use crate::solvers::sat::stateful::{StatefulSatSolver, StatefulSatSolverHint};
+use crate::solvers::sat::SatResponse;
+use rand::Rng;
+use std::cmp::min;
+use std::collections::HashMap;
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// simulates random traces from the initial states and groups the latches, and the
+    /// constant as variable 0, by their values in all of the cycles. Variables of opposite
+    /// phase are in the same class when their values are opposite.
+    fn get_latch_classes_from_simulation(
+        &self,
+        number_of_cycles: usize,
+        number_of_words: usize,
+    ) -> (Vec<Vec<usize>>, Vec<bool>) {
+        let mut rng = rand::thread_rng();
+        let mut values = vec![0; self.nodes.len() * number_of_words];
+        for latch_index in self.latches.iter() {
+            let reset = self.nodes[latch_index.to_owned()].get_latch_reset();
+            for word in 0..number_of_words {
+                values[latch_index * number_of_words + word] = match reset {
+                    0 => 0,
+                    1 => u64::MAX,
+                    _ => rng.gen(),
+                };
+            }
+        }
+
+        let mut signatures: Vec<Vec<u64>> = vec![Vec::new(); self.nodes.len()];
+        for _ in 0..number_of_cycles {
+            for latch_index in self.latches.iter() {
+                let words = &values[latch_index * number_of_words..][..number_of_words];
+                signatures[latch_index.to_owned()].extend(words);
+            }
+            for input_index in self.inputs.iter() {
+                for word in 0..number_of_words {
+                    values[input_index * number_of_words + word] = rng.gen();
+                }
+            }
+            self.simulate_and_words(&mut values, number_of_words);
+            let mut next_words = Vec::with_capacity(self.latches.len() * number_of_words);
+            for latch_index in self.latches.iter() {
+                let next = self.nodes[latch_index.to_owned()].get_latch_input();
+                for word in 0..number_of_words {
+                    next_words.push(Self::get_literal_words(
+                        &values,
+                        next,
+                        number_of_words,
+                        word,
+                    ));
+                }
+            }
+            for (position, latch_index) in self.latches.iter().enumerate() {
+                values[latch_index * number_of_words..][..number_of_words]
+                    .copy_from_slice(&next_words[position * number_of_words..][..number_of_words]);
+            }
+        }
+        signatures[0] = vec![0; number_of_cycles * number_of_words];
+
+        let mut phases = vec![false; self.nodes.len()];
+        let mut class_of_signature: HashMap<Vec<u64>, Vec<usize>> = HashMap::new();
+        for variable in [0].iter().chain(self.latches.iter()) {
+            let signature = &signatures[variable.to_owned()];
+            phases[variable.to_owned()] = signature[0] & 1 == 1;
+            let normalized = signature
+                .iter()
+                .map(|w| if phases[*variable] { !w } else { w.to_owned() })
+                .collect();
+            class_of_signature
+                .entry(normalized)
+                .or_default()
+                .push(variable.to_owned());
+        }
+        let classes = class_of_signature
+            .into_values()
+            .filter(|class| class.len() > 1)
+            .collect();
+        (classes, phases)
+    }
+
+    /// the pairs of literals that are equal when the classes hold, where None stands for
+    /// the constant false.
+    fn get_correspondence_pairs(
+        classes: &[Vec<usize>],
+        phases: &[bool],
+    ) -> Vec<(Literal, Option<Literal>)> {
+        let mut pairs = Vec::new();
+        for class in classes {
+            let representative = class[0];
+            for variable in class[1..].iter() {
+                let is_opposite = phases[representative] ^ phases[variable.to_owned()];
+                let sat_variable: VariableType = variable.to_owned().try_into().unwrap();
+                let literal = Literal::new(sat_variable);
+                if representative == 0 {
+                    pairs.push((literal.negate_if_true(is_opposite), None));
+                } else {
+                    let sat_representative: VariableType = representative.try_into().unwrap();
+                    let representative_literal =
+                        Literal::new(sat_representative).negate_if_true(is_opposite);
+                    pairs.push((literal, Some(representative_literal)));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// the clauses that hold when both literals are equal in some cycle, where the literal
+    /// `guard` turns all of them off when it is false.
+    fn get_correspondence_clauses(
+        fin_state: &FiniteStateTransitionSystem,
+        pair: &(Literal, Option<Literal>),
+        cycle: VariableType,
+        guard: Literal,
+    ) -> CNF {
+        let (a, b) = pair;
+        let mut equality = CNF::new();
+        match b {
+            None => equality.add_clause(&Clause::new(&[!a.to_owned()])),
+            Some(b) => {
+                equality.add_clause(&Clause::new(&[!a.to_owned(), b.to_owned()]));
+                equality.add_clause(&Clause::new(&[a.to_owned(), !b.to_owned()]));
+            }
+        }
+        let mut cnf = CNF::new();
+        for clause in fin_state.add_tags_to_relation(&equality, cycle).iter() {
+            let mut literals: Vec<Literal> = clause.iter().copied().collect();
+            literals.push(!guard);
+            cnf.add_clause(&Clause::new(&literals));
+        }
+        cnf
+    }
+
+    /// adds a new literal for every pair that is true only if the pair differs in the
+    /// cycle, and returns these literals.
+    fn add_correspondence_differences<T: StatefulSatSolver>(
+        solver: &mut T,
+        fin_state: &FiniteStateTransitionSystem,
+        pairs: &[(Literal, Option<Literal>)],
+        cycle: VariableType,
+        next_variable: &mut VariableType,
+    ) -> Vec<Literal> {
+        let mut differences = Vec::with_capacity(pairs.len());
+        for (a, b) in pairs.iter() {
+            let difference = Literal::new(next_variable.to_owned());
+            *next_variable += 1;
+            // a differs from b when !a is equal to b.
+            let pair = (!a.to_owned(), b.to_owned());
+            solver.add_cnf(&Self::get_correspondence_clauses(
+                fin_state, &pair, cycle, difference,
+            ));
+            differences.push(difference);
+        }
+        differences
+    }
+
+    /// splits the classes until they hold in the first `depth` cycles from the initial
+    /// states, every counter example splits the classes by the latches in all of its cycles.
+    fn refine_correspondence_classes_in_base_case<T: StatefulSatSolver>(
+        &self,
+        fin_state: &FiniteStateTransitionSystem,
+        mut classes: Vec<Vec<usize>>,
+        phases: &[bool],
+        depth: VariableType,
+    ) -> Vec<Vec<usize>> {
+        let max_literal_number = fin_state.get_max_literal_number();
+        let mut solver = T::new(StatefulSatSolverHint::None);
+        solver.add_cnf(&fin_state.get_initial_relation().to_cnf());
+        let transition = fin_state.get_transition_relation();
+        let translation = fin_state.get_state_to_safety_translation();
+        for cycle in 0..depth {
+            if cycle + 1 < depth {
+                solver.add_cnf(&fin_state.add_tags_to_relation(&transition, cycle));
+            }
+            // the translation makes sure the constraints hold even without a transition.
+            solver.add_cnf(&fin_state.add_tags_to_relation(&translation, cycle));
+        }
+
+        let mut next_variable = max_literal_number * (depth + 1) + 1;
+        while !classes.is_empty() {
+            let pairs = Self::get_correspondence_pairs(&classes, phases);
+            let mut differences = Vec::new();
+            for cycle in 0..depth {
+                differences.extend(Self::add_correspondence_differences(
+                    &mut solver,
+                    fin_state,
+                    &pairs,
+                    cycle,
+                    &mut next_variable,
+                ));
+            }
+            match solver.solve(None, Some(&Clause::new(&differences))) {
+                SatResponse::UnSat => break,
+                SatResponse::Sat { assignment } => {
+                    // bits after the last cycle repeat it, since opposite phases flip all bits.
+                    let mut pattern = vec![0; self.nodes.len()];
+                    for latch_index in self.latches.iter() {
+                        let latch: VariableType = latch_index.to_owned().try_into().unwrap();
+                        for bit in 0..64 {
+                            let cycle = min(bit, depth - 1);
+                            let sat_variable = latch + max_literal_number * cycle;
+                            if assignment.get_value(&sat_variable).unwrap_or(false) {
+                                pattern[latch_index.to_owned()] |= 1 << bit;
+                            }
+                        }
+                    }
+                    classes = Self::refine_equivalence_classes(classes, &pattern, phases);
+                }
+            }
+        }
+        classes
+    }
+
+    /// splits the classes until they are inductive, meaning that if they hold in `depth`
+    /// cycles in a row then they hold in the next one, every counter example splits the
+    /// classes by the latches in the last cycle.
+    fn refine_correspondence_classes_in_induction_step<T: StatefulSatSolver>(
+        &self,
+        fin_state: &FiniteStateTransitionSystem,
+        mut classes: Vec<Vec<usize>>,
+        phases: &[bool],
+        depth: VariableType,
+    ) -> Vec<Vec<usize>> {
+        let max_literal_number = fin_state.get_max_literal_number();
+        let mut solver = T::new(StatefulSatSolverHint::None);
+        let transition = fin_state.get_transition_relation();
+        let translation = fin_state.get_state_to_safety_translation();
+        for cycle in 0..depth {
+            solver.add_cnf(&fin_state.add_tags_to_relation(&transition, cycle));
+        }
+        solver.add_cnf(&fin_state.add_tags_to_relation(&translation, depth));
+
+        // the classes are assumed under an activation literal, since they change.
+        let mut next_variable = max_literal_number * (depth + 1) + 1;
+        while !classes.is_empty() {
+            let pairs = Self::get_correspondence_pairs(&classes, phases);
+            let activation = Literal::new(next_variable);
+            next_variable += 1;
+            for cycle in 0..depth {
+                for pair in pairs.iter() {
+                    solver.add_cnf(&Self::get_correspondence_clauses(
+                        fin_state, pair, cycle, activation,
+                    ));
+                }
+            }
+            let differences = Self::add_correspondence_differences(
+                &mut solver,
+                fin_state,
+                &pairs,
+                depth,
+                &mut next_variable,
+            );
+            match solver.solve(
+                Some(&Cube::new(&[activation])),
+                Some(&Clause::new(&differences)),
+            ) {
+                SatResponse::UnSat => break,
+                SatResponse::Sat { assignment } => {
+                    let mut pattern = vec![0; self.nodes.len()];
+                    for latch_index in self.latches.iter() {
+                        let latch: VariableType = latch_index.to_owned().try_into().unwrap();
+                        let sat_variable = latch + max_literal_number * depth;
+                        if assignment.get_value(&sat_variable).unwrap_or(false) {
+                            pattern[latch_index.to_owned()] = u64::MAX;
+                        }
+                    }
+                    classes = Self::refine_equivalence_classes(classes, &pattern, phases);
+                }
+            }
+        }
+        classes
+    }
+
+    /// marks the variables that the rebuilt graph needs, where merged latches are replaced
+    /// by their representatives.
+    fn get_variables_used_after_signal_correspondence(
+        &self,
+        representatives: &HashMap<usize, usize>,
+    ) -> Vec<bool> {
+        let mut is_used = vec![false; self.nodes.len()];
+        let mut variables_to_visit: Vec<usize> = self
+            .latches
+            .iter()
+            .filter(|l| !representatives.contains_key(l))
+            .map(|l| self.nodes[l.to_owned()].get_latch_input())
+            .chain(self.outputs.iter().copied())
+            .chain(self.bad.iter().copied())
+            .chain(self.constraints.iter().copied())
+            .map(|l| l >> 1)
+            .collect();
+        while let Some(variable) = variables_to_visit.pop() {
+            if is_used[variable] {
+                continue;
+            }
+            is_used[variable] = true;
+            if let Some(representative) = representatives.get(&variable) {
+                variables_to_visit.push(representative >> 1);
+            } else if self.nodes[variable].get_type() == AIGNodeType::And {
+                variables_to_visit.push(self.nodes[variable].get_and_rhs0() >> 1);
+                variables_to_visit.push(self.nodes[variable].get_and_rhs1() >> 1);
+            }
+        }
+        is_used
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that merges latches that are sequentially equivalent, meaning that they
+    /// are equal, opposite or constant in every reachable state, even though they are not
+    /// equivalent as functions of the other latches. This is also known as signal or
+    /// register correspondence.
+    /// Candidate classes of latches are found by simulating random traces from the initial
+    /// states. The classes are then proved by k-induction using a sat solver of type `T`:
+    /// they must hold in the first `induction_depth` cycles, and whenever they hold in
+    /// `induction_depth` cycles in a row they must hold in the next one. Every counter
+    /// example splits the classes, until no counter example is left.
+    /// Every latch is then replaced by the lowest latch of its class, or by a constant, and
+    /// the result is rebuilt without the latches and and gates that are no longer used.
+    /// The inputs, remaining latches, outputs, bad state literals and invariant constraints
+    /// are kept with their symbols. Panics if the graph has justice properties or fairness
+    /// constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `induction_depth` - the number of cycles in which the classes are assumed in the
+    ///   induction step, between 1 and 64.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use rust_formal_verification::solvers::sat::stateful::CaDiCalSolver;
+    /// // two latches that store the same input, and a bad state literal when they differ.
+    /// let aag = "aag 6 1 2 0 3 1\n2\n4 2\n6 2\n13\n8 6 5\n10 7 4\n12 11 9\n";
+    /// let aig = AndInverterGraph::from_aag_str(aag);
+    /// let reduced = aig.signal_correspondence::<CaDiCalSolver>(1);
+    /// assert_eq!(reduced.get_aag_string(), "aag 2 1 1 0 0 1\n2\n4 2\n0\n");
+    /// ```
+    pub fn signal_correspondence<T: StatefulSatSolver>(
+        &self,
+        induction_depth: usize,
+    ) -> AndInverterGraph {
+        self.assert_no_justice_or_fairness("Signal correspondence");
+        assert!(
+            (1..=64).contains(&induction_depth),
+            "The induction depth must be between 1 and 64."
+        );
+        let depth: VariableType = induction_depth.try_into().unwrap();
+        let fin_state = FiniteStateTransitionSystem::from_aig(self, false);
+        let (classes, phases) = self.get_latch_classes_from_simulation(16, 4);
+        let classes = self
+            .refine_correspondence_classes_in_base_case::<T>(&fin_state, classes, &phases, depth);
+        let classes = self.refine_correspondence_classes_in_induction_step::<T>(
+            &fin_state, classes, &phases, depth,
+        );
+
+        // maps every merged latch to the literal of the lowest variable in its class.
+        let mut representatives = HashMap::new();
+        for class in classes.iter() {
+            for variable in class[1..].iter() {
+                let is_opposite = phases[class[0]] ^ phases[variable.to_owned()];
+                representatives.insert(
+                    variable.to_owned(),
+                    (class[0] << 1) | usize::from(is_opposite),
+                );
+            }
+        }
+        let is_used = self.get_variables_used_after_signal_correspondence(&representatives);
+
+        let mut builder = AigBuilder::new();
+        let is_kept = |variable: usize| !representatives.contains_key(&variable);
+        let mut literal_map = self.get_literal_map_with_copies(&mut builder, is_kept);
+        // representatives are lower than the latches they replace, so they are mapped first.
+        for latch_index in self.latches.iter() {
+            if let Some(representative) = representatives.get(latch_index) {
+                literal_map[latch_index.to_owned()] =
+                    Self::map_literal(&literal_map, representative.to_owned());
+            }
+        }
+        self.add_copies_of_and_gates(&mut builder, &mut literal_map, |v| is_used[v]);
+        self.set_next_of_copied_latches(&mut builder, &literal_map, is_kept);
+
+        self.add_copies_of_outputs_bad_and_constraints(&mut builder, |_, literal| {
+            Self::map_literal(&literal_map, literal)
+        });
+        builder.build()
+    }
+}
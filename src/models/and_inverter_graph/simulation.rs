@@ -3,7 +3,7 @@
 // ************************************************************************************************
 
 use crate::models::and_inverter_graph::aig_node::AIGNodeType;
-use crate::models::and_inverter_graph::AndInverterGraph;
+use crate::models::and_inverter_graph::{AigSimulationError, AndInverterGraph};
 use std::collections::HashMap;
 
 use super::aig_node::AIGNode;
@@ -12,6 +12,149 @@ use super::aig_node::AIGNode;
 // AIG simulation result
 // ************************************************************************************************
 
+/// Struct that holds the result of simulating an AndInverterGraph, the values of its signals
+/// can be read by position or by their name in the symbol table.
+pub struct AIGSimulationResult<'a> {
+    aig: &'a AndInverterGraph,
+    // the value of every variable in every cycle.
+    states: Vec<Vec<bool>>,
+}
+
+impl<'a> AIGSimulationResult<'a> {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_value_of_literal(&self, cycle: usize, literal: usize) -> bool {
+        self.states[cycle][literal >> 1] ^ ((literal & 1) == 1)
+    }
+
+    fn find_index_of_symbol<T>(
+        list: &[T],
+        symbol: &str,
+        get_symbol: impl Fn(&T) -> &'a str,
+    ) -> Option<usize> {
+        list.iter().position(|x| get_symbol(x) == symbol)
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that returns the number of cycles that were simulated.
+    pub fn get_number_of_cycles(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Function that returns the value of every variable in every cycle, this is the same
+    /// as the result of `simulate`.
+    pub fn get_states(&self) -> &Vec<Vec<bool>> {
+        &self.states
+    }
+
+    /// Function that returns the value of an input at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the input.
+    pub fn get_input_value(&self, cycle: usize, index: usize) -> bool {
+        self.states[cycle][self.aig.inputs[index]]
+    }
+
+    /// Function that returns the value of a latch at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the latch.
+    pub fn get_latch_value(&self, cycle: usize, index: usize) -> bool {
+        self.states[cycle][self.aig.latches[index]]
+    }
+
+    /// Function that returns the value of an output at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the output.
+    pub fn get_output_value(&self, cycle: usize, index: usize) -> bool {
+        self.get_value_of_literal(cycle, self.aig.outputs[index])
+    }
+
+    /// Function that returns the value of a bad state literal at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the bad state literal.
+    pub fn get_bad_value(&self, cycle: usize, index: usize) -> bool {
+        self.get_value_of_literal(cycle, self.aig.bad[index])
+    }
+
+    /// Function that returns the value of an invariant constraint at some cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the cycle desired, starting from 0.
+    /// * `index` - the position of the invariant constraint.
+    pub fn get_constraint_value(&self, cycle: usize, index: usize) -> bool {
+        self.get_value_of_literal(cycle, self.aig.constraints[index])
+    }
+
+    /// Function that returns the value of the input with some symbol at some cycle, or None
+    /// if there is no such input.
+    pub fn get_input_value_by_symbol(&self, cycle: usize, symbol: &str) -> Option<bool> {
+        let nodes = &self.aig.nodes;
+        Self::find_index_of_symbol(&self.aig.inputs, symbol, |i| nodes[*i].get_input_symbol())
+            .map(|index| self.get_input_value(cycle, index))
+    }
+
+    /// Function that returns the value of the latch with some symbol at some cycle, or None
+    /// if there is no such latch.
+    pub fn get_latch_value_by_symbol(&self, cycle: usize, symbol: &str) -> Option<bool> {
+        let nodes = &self.aig.nodes;
+        Self::find_index_of_symbol(&self.aig.latches, symbol, |i| nodes[*i].get_latch_symbol())
+            .map(|index| self.get_latch_value(cycle, index))
+    }
+
+    /// Function that returns the value of the output with some symbol at some cycle, or None
+    /// if there is no such output.
+    pub fn get_output_value_by_symbol(&self, cycle: usize, symbol: &str) -> Option<bool> {
+        let nodes = &self.aig.nodes;
+        Self::find_index_of_symbol(&self.aig.outputs, symbol, |l| {
+            nodes[*l >> 1].get_output_symbol()
+        })
+        .map(|index| self.get_output_value(cycle, index))
+    }
+
+    /// Function that returns the value of the bad state literal with some symbol at some
+    /// cycle, or None if there is no such bad state literal.
+    pub fn get_bad_value_by_symbol(&self, cycle: usize, symbol: &str) -> Option<bool> {
+        let nodes = &self.aig.nodes;
+        Self::find_index_of_symbol(&self.aig.bad, symbol, |l| nodes[*l >> 1].get_bad_symbol())
+            .map(|index| self.get_bad_value(cycle, index))
+    }
+
+    /// Function that returns the value of the invariant constraint with some symbol at some
+    /// cycle, or None if there is no such invariant constraint.
+    pub fn get_constraint_value_by_symbol(&self, cycle: usize, symbol: &str) -> Option<bool> {
+        let nodes = &self.aig.nodes;
+        Self::find_index_of_symbol(&self.aig.constraints, symbol, |l| {
+            nodes[*l >> 1].get_constraint_symbol()
+        })
+        .map(|index| self.get_constraint_value(cycle, index))
+    }
+
+    /// Function that returns the first cycle in which some bad state literal is true, or
+    /// None if this never happens.
+    pub fn get_first_bad_cycle(&self) -> Option<usize> {
+        (0..self.states.len()).find(|cycle| {
+            (0..self.aig.bad.len()).any(|index| self.get_bad_value(cycle.to_owned(), index))
+        })
+    }
+}
+
 // ************************************************************************************************
 // impl
 // ************************************************************************************************
@@ -113,42 +256,49 @@ impl AndInverterGraph {
         result
     }
 
-    // ********************************************************************************************
-    // aig getting and gates
-    // ********************************************************************************************
-
-    pub fn simulate(
+    fn check_simulation_arguments(
         &self,
-        inputs: &Vec<HashMap<usize, bool>>,
+        inputs: &[HashMap<usize, bool>],
         initial_latches: &HashMap<usize, bool>,
-    ) -> Vec<Vec<bool>> {
+    ) -> Result<(), AigSimulationError> {
         // check inputs
-        assert!(
-            !inputs.is_empty(),
-            "Inputs cannot be empty to start simulation."
-        );
-        for clk_inputs in inputs {
-            assert_eq!(clk_inputs.len(), self.number_of_inputs);
-            assert_eq!(self.inputs.len(), self.number_of_inputs);
+        if inputs.is_empty() {
+            return Err(AigSimulationError::EmptyInputs);
+        }
+        for (cycle, clk_inputs) in inputs.iter().enumerate() {
             // check that each clock has the correct var numbers.
-            for input_var in self.inputs.iter() {
-                assert!(clk_inputs.contains_key(input_var));
+            if clk_inputs.len() != self.inputs.len()
+                || !self.inputs.iter().all(|i| clk_inputs.contains_key(i))
+            {
+                return Err(AigSimulationError::WrongInputVariables { cycle });
             }
         }
         // check initial latches
-        assert!(
-            initial_latches.len() <= self.number_of_latches,
-            "Too many initial latches provided."
-        );
-        assert_eq!(self.latches.len(), self.number_of_latches);
+        for latch_var in initial_latches.keys() {
+            let node_type = self.nodes.get(latch_var.to_owned()).map(|n| n.get_type());
+            if node_type != Some(AIGNodeType::Latch) {
+                return Err(AigSimulationError::NotALatch {
+                    variable: latch_var.to_owned(),
+                });
+            }
+        }
         for latch_var in self.latches.iter() {
             let node = &self.nodes[latch_var.to_owned()];
             // check that all uninitialized latches have a provided value.
-            if &node.get_latch_reset() == latch_var {
-                assert!(initial_latches.contains_key(latch_var));
+            if node.get_latch_reset() > 1 && !initial_latches.contains_key(latch_var) {
+                return Err(AigSimulationError::MissingInitialLatch {
+                    variable: latch_var.to_owned(),
+                });
             }
         }
+        Ok(())
+    }
 
+    fn simulate_without_checks(
+        &self,
+        inputs: &[HashMap<usize, bool>],
+        initial_latches: &HashMap<usize, bool>,
+    ) -> Vec<Vec<bool>> {
         // prepare result
         let mut result = Vec::new();
 
@@ -167,4 +317,85 @@ impl AndInverterGraph {
         }
         result
     }
+
+    // ********************************************************************************************
+    // aig simulation
+    // ********************************************************************************************
+
+    /// Function that simulates the AndInverterGraph and returns the value of every variable
+    /// in every cycle, `result[cycle][variable]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `inputs` - for each cycle, maps the variable number of each input to its value.
+    /// * `initial_latches` - maps the variable number of each uninitialized latch to its
+    ///   value in cycle 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arguments do not match the AndInverterGraph, see `try_simulate`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter.aig");
+    /// let states = aig.simulate(&vec![HashMap::new(); 2], &HashMap::new());
+    /// assert_eq!(states[0], vec![false, false, false, false, true, true]);
+    /// assert_eq!(states[1], vec![false, true, false, false, true, false]);
+    /// ```
+    pub fn simulate(
+        &self,
+        inputs: &[HashMap<usize, bool>],
+        initial_latches: &HashMap<usize, bool>,
+    ) -> Vec<Vec<bool>> {
+        self.try_simulate(inputs, initial_latches)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .states
+    }
+
+    /// Function that simulates the AndInverterGraph and returns a result that allows reading
+    /// the values of its signals by position or by symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `inputs` - for each cycle, maps the variable number of each input to its value.
+    /// * `initial_latches` - maps the variable number of each uninitialized latch to its
+    ///   value in cycle 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigSimulationError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// // a latch that becomes 1 once the input is 1.
+    /// let aag = "aag 3 1 1 0 1 1\n2\n4 7\n4\n6 5 3\ni0 go\nl0 done\nb0 finished\n";
+    /// let aig = AndInverterGraph::from_aag_str(aag);
+    /// let inputs = vec![HashMap::from([(1, false)]), HashMap::from([(1, true)]), HashMap::from([(1, false)])];
+    /// let result = aig.try_simulate(&inputs, &HashMap::new()).unwrap();
+    /// assert_eq!(result.get_input_value_by_symbol(1, "go"), Some(true));
+    /// assert_eq!(result.get_latch_value_by_symbol(1, "done"), Some(false));
+    /// assert_eq!(result.get_bad_value_by_symbol(2, "finished"), Some(true));
+    /// assert_eq!(result.get_bad_value_by_symbol(2, "unknown"), None);
+    /// assert_eq!(result.get_first_bad_cycle(), Some(2));
+    ///
+    /// let wrong_inputs = vec![HashMap::from([(2, false)])];
+    /// assert_eq!(
+    ///     aig.try_simulate(&wrong_inputs, &HashMap::new()).err(),
+    ///     Some(AigSimulationError::WrongInputVariables { cycle: 0 })
+    /// );
+    /// ```
+    pub fn try_simulate(
+        &self,
+        inputs: &[HashMap<usize, bool>],
+        initial_latches: &HashMap<usize, bool>,
+    ) -> Result<AIGSimulationResult<'_>, AigSimulationError> {
+        self.check_simulation_arguments(inputs, initial_latches)?;
+        Ok(AIGSimulationResult {
+            aig: self,
+            states: self.simulate_without_checks(inputs, initial_latches),
+        })
+    }
 }
@@ -0,0 +1,381 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::AndInverterGraph;
+use std::fmt;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that holds statistics about the structure of an AndInverterGraph, to help decide
+/// how to verify it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AigStats {
+    // the counts of the header.
+    maximum_variable_index: usize,
+    number_of_inputs: usize,
+    number_of_latches: usize,
+    number_of_outputs: usize,
+    number_of_and_gates: usize,
+    number_of_bad_state_literals: usize,
+    number_of_invariant_constraints: usize,
+    number_of_justice_properties: usize,
+    number_of_fairness_constraints: usize,
+
+    number_of_levels: usize,
+    maximum_fanout: usize,
+    average_fanout: f64,
+    // the number of latches in the cone of influence of every bad state literal.
+    latches_in_cone_of_influence: Vec<usize>,
+    // the sizes of the strongly connected components of latches, largest first.
+    latch_scc_sizes: Vec<usize>,
+    number_of_cyclic_latch_sccs: usize,
+    number_of_constant_nodes: usize,
+    number_of_unused_nodes: usize,
+    number_of_uninitialized_latches: usize,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AigStats {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the statistics as pairs of a name and a value in json.
+    fn get_entries(&self) -> Vec<(&'static str, String)> {
+        let list_to_string = |values: &[usize]| {
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            format!("[{}]", values.join(", "))
+        };
+        vec![
+            (
+                "maximum_variable_index",
+                self.maximum_variable_index.to_string(),
+            ),
+            ("number_of_inputs", self.number_of_inputs.to_string()),
+            ("number_of_latches", self.number_of_latches.to_string()),
+            ("number_of_outputs", self.number_of_outputs.to_string()),
+            ("number_of_and_gates", self.number_of_and_gates.to_string()),
+            (
+                "number_of_bad_state_literals",
+                self.number_of_bad_state_literals.to_string(),
+            ),
+            (
+                "number_of_invariant_constraints",
+                self.number_of_invariant_constraints.to_string(),
+            ),
+            (
+                "number_of_justice_properties",
+                self.number_of_justice_properties.to_string(),
+            ),
+            (
+                "number_of_fairness_constraints",
+                self.number_of_fairness_constraints.to_string(),
+            ),
+            ("number_of_levels", self.number_of_levels.to_string()),
+            ("maximum_fanout", self.maximum_fanout.to_string()),
+            ("average_fanout", format!("{:.2}", self.average_fanout)),
+            (
+                "latches_in_cone_of_influence",
+                list_to_string(&self.latches_in_cone_of_influence),
+            ),
+            (
+                "number_of_latch_sccs",
+                self.latch_scc_sizes.len().to_string(),
+            ),
+            (
+                "largest_latch_scc",
+                self.latch_scc_sizes
+                    .first()
+                    .copied()
+                    .unwrap_or(0)
+                    .to_string(),
+            ),
+            (
+                "number_of_cyclic_latch_sccs",
+                self.number_of_cyclic_latch_sccs.to_string(),
+            ),
+            (
+                "number_of_constant_nodes",
+                self.number_of_constant_nodes.to_string(),
+            ),
+            (
+                "number_of_unused_nodes",
+                self.number_of_unused_nodes.to_string(),
+            ),
+            (
+                "number_of_uninitialized_latches",
+                self.number_of_uninitialized_latches.to_string(),
+            ),
+        ]
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that returns the counts of the header, in the order maximum variable index,
+    /// inputs, latches, outputs, and gates, bad state literals, invariant constraints,
+    /// justice properties and fairness constraints.
+    pub fn get_header_counts(&self) -> [usize; 9] {
+        [
+            self.maximum_variable_index,
+            self.number_of_inputs,
+            self.number_of_latches,
+            self.number_of_outputs,
+            self.number_of_and_gates,
+            self.number_of_bad_state_literals,
+            self.number_of_invariant_constraints,
+            self.number_of_justice_properties,
+            self.number_of_fairness_constraints,
+        ]
+    }
+
+    /// Function that returns the largest number of and gates on a path from an input or a
+    /// latch.
+    pub fn get_number_of_levels(&self) -> usize {
+        self.number_of_levels
+    }
+
+    /// Function that returns the largest number of and gates and latches that use the same
+    /// variable.
+    pub fn get_maximum_fanout(&self) -> usize {
+        self.maximum_fanout
+    }
+
+    /// Function that returns the average number of and gates and latches that use an input,
+    /// a latch or an and gate.
+    pub fn get_average_fanout(&self) -> f64 {
+        self.average_fanout
+    }
+
+    /// Function that returns the number of latches in the cone of influence of every bad
+    /// state literal.
+    pub fn get_latches_in_cone_of_influence(&self) -> &[usize] {
+        &self.latches_in_cone_of_influence
+    }
+
+    /// Function that returns the sizes of the strongly connected components of the graph
+    /// where latches depend on the latches that their next state reads, largest first.
+    pub fn get_latch_scc_sizes(&self) -> &[usize] {
+        &self.latch_scc_sizes
+    }
+
+    /// Function that returns the number of strongly connected components of latches that
+    /// have a cycle, meaning that their latches depend on their own values.
+    pub fn get_number_of_cyclic_latch_sccs(&self) -> usize {
+        self.number_of_cyclic_latch_sccs
+    }
+
+    /// Function that returns the number of and gates and latches that always have the same
+    /// value, found by propagating constants through the graph.
+    pub fn get_number_of_constant_nodes(&self) -> usize {
+        self.number_of_constant_nodes
+    }
+
+    /// Function that returns the number of inputs, latches and and gates that cannot affect
+    /// any output, property or constraint.
+    pub fn get_number_of_unused_nodes(&self) -> usize {
+        self.number_of_unused_nodes
+    }
+
+    /// Function that returns the number of latches without an initial value.
+    pub fn get_number_of_uninitialized_latches(&self) -> usize {
+        self.number_of_uninitialized_latches
+    }
+
+    /// Function that returns the statistics as a json object.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 1 0 1 0\n2\n2\n");
+    /// let json = aig.get_stats().get_json_string();
+    /// assert!(json.starts_with("{\n  \"maximum_variable_index\": 1,\n  \"number_of_inputs\": 1,\n"));
+    /// assert!(json.ends_with("  \"number_of_uninitialized_latches\": 0\n}\n"));
+    /// ```
+    pub fn get_json_string(&self) -> String {
+        let entries: Vec<String> = self
+            .get_entries()
+            .iter()
+            .map(|(name, value)| format!("  \"{name}\": {value}"))
+            .collect();
+        format!("{{\n{}\n}}\n", entries.join(",\n"))
+    }
+}
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the values of the variables that are constant when constants are propagated through
+    /// and gates, and latches that keep their initial value.
+    fn get_constant_values(&self) -> Vec<Option<bool>> {
+        let mut values: Vec<Option<bool>> = vec![None; self.nodes.len()];
+        values[0] = Some(false);
+        let get_value = |values: &[Option<bool>], literal: usize| {
+            values[literal >> 1].map(|value| value ^ (literal & 1 == 1))
+        };
+        // latches may depend on higher variables, so this is repeated until nothing changes.
+        let mut is_changed = true;
+        while is_changed {
+            is_changed = false;
+            for (variable, node) in self.nodes.iter().enumerate() {
+                if values[variable].is_some() {
+                    continue;
+                }
+                let value = match node.get_type() {
+                    AIGNodeType::And => {
+                        let rhs0 = node.get_and_rhs0();
+                        let rhs1 = node.get_and_rhs1();
+                        match (get_value(&values, rhs0), get_value(&values, rhs1)) {
+                            (Some(false), _) | (_, Some(false)) => Some(false),
+                            (Some(true), Some(true)) => Some(true),
+                            _ if rhs0 == rhs1 ^ 1 => Some(false),
+                            _ => None,
+                        }
+                    }
+                    AIGNodeType::Latch => {
+                        let next = node.get_latch_input();
+                        self.get_latch_initial_value(variable).filter(|reset| {
+                            next == node.get_literal() || get_value(&values, next) == Some(*reset)
+                        })
+                    }
+                    AIGNodeType::ConstantZero | AIGNodeType::Input => None,
+                };
+                if value.is_some() {
+                    values[variable] = value;
+                    is_changed = true;
+                }
+            }
+        }
+        values
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that computes statistics about the structure of an AndInverterGraph, like
+    /// the counts of its header, its logic levels, its fanout, the number of latches in the
+    /// cone of influence of every bad state literal, the strongly connected components of
+    /// its latches, and the number of constant, unused and uninitialized nodes.
+    /// The result can be printed as text, or as json with `get_json_string`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles, and a latch that stores the input when the first is 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 4 1 2 0 1 1\n2\n4 5\n6 8\n6\n8 4 2\n");
+    /// let stats = aig.get_stats();
+    /// assert_eq!(stats.get_header_counts(), [4, 1, 2, 0, 1, 1, 0, 0, 0]);
+    /// assert_eq!(stats.get_number_of_levels(), 1);
+    /// assert_eq!(stats.get_maximum_fanout(), 2);
+    /// assert_eq!(stats.get_latches_in_cone_of_influence(), &[2]);
+    /// // the first latch depends on itself, the second one only on the first.
+    /// assert_eq!(stats.get_latch_scc_sizes(), &[1, 1]);
+    /// assert_eq!(stats.get_number_of_cyclic_latch_sccs(), 1);
+    /// assert!(stats.to_string().contains("number of levels: 1\n"));
+    /// ```
+    pub fn get_stats(&self) -> AigStats {
+        let mut levels = vec![0; self.nodes.len()];
+        let mut fanouts = vec![0; self.nodes.len()];
+        for (variable, node) in self.nodes.iter().enumerate() {
+            match node.get_type() {
+                AIGNodeType::And => {
+                    let rhs0 = node.get_and_rhs0() >> 1;
+                    let rhs1 = node.get_and_rhs1() >> 1;
+                    levels[variable] = levels[rhs0].max(levels[rhs1]) + 1;
+                    fanouts[rhs0] += 1;
+                    fanouts[rhs1] += 1;
+                }
+                AIGNodeType::Latch => fanouts[node.get_latch_input() >> 1] += 1,
+                AIGNodeType::ConstantZero | AIGNodeType::Input => {}
+            }
+        }
+        let number_of_nodes = self.nodes.len() - 1;
+        let total_fanout: usize = fanouts[1..].iter().sum();
+
+        let latches_in_cone_of_influence = self
+            .bad
+            .iter()
+            .map(|bad_literal| {
+                let is_in_cone =
+                    self.get_variables_in_sequential_cone_of_influence(&[*bad_literal]);
+                self.latches.iter().filter(|l| is_in_cone[**l]).count()
+            })
+            .collect();
+
+        let dependency_graph = self.get_latch_dependency_graph();
+        let components = dependency_graph.get_strongly_connected_components();
+        let number_of_cyclic_latch_sccs = (0..components.len())
+            .filter(|index| dependency_graph.is_component_cyclic(*index))
+            .count();
+        let mut latch_scc_sizes: Vec<usize> = components.iter().map(|c| c.len()).collect();
+        latch_scc_sizes.sort_by(|a, b| b.cmp(a));
+
+        let mut roots: Vec<usize> = Vec::new();
+        roots.extend(self.outputs.iter());
+        roots.extend(self.bad.iter());
+        roots.extend(self.constraints.iter());
+        roots.extend(self.justice.iter().flatten());
+        roots.extend(self.fairness.iter());
+        let is_used = self.get_variables_in_sequential_cone_of_influence(&roots);
+
+        AigStats {
+            maximum_variable_index: self.maximum_variable_index,
+            number_of_inputs: self.inputs.len(),
+            number_of_latches: self.latches.len(),
+            number_of_outputs: self.outputs.len(),
+            number_of_and_gates: self.ands.len(),
+            number_of_bad_state_literals: self.bad.len(),
+            number_of_invariant_constraints: self.constraints.len(),
+            number_of_justice_properties: self.justice.len(),
+            number_of_fairness_constraints: self.fairness.len(),
+            number_of_levels: levels.iter().max().copied().unwrap_or(0),
+            maximum_fanout: fanouts[1..].iter().max().copied().unwrap_or(0),
+            average_fanout: if number_of_nodes == 0 {
+                0.0
+            } else {
+                total_fanout as f64 / number_of_nodes as f64
+            },
+            latches_in_cone_of_influence,
+            latch_scc_sizes,
+            number_of_cyclic_latch_sccs,
+            number_of_constant_nodes: self.get_constant_values()[1..]
+                .iter()
+                .filter(|v| v.is_some())
+                .count(),
+            number_of_unused_nodes: is_used[1..].iter().filter(|u| !**u).count(),
+            number_of_uninitialized_latches: self
+                .latches
+                .iter()
+                .filter(|l| self.nodes[**l].get_latch_reset() > 1)
+                .count(),
+        }
+    }
+}
+
+// ************************************************************************************************
+// printing
+// ************************************************************************************************
+
+impl fmt::Display for AigStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, value) in self.get_entries() {
+            writeln!(f, "{}: {}", name.replace('_', " "), value)?;
+        }
+        Ok(())
+    }
+}
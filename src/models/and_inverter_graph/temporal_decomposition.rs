@@ -0,0 +1,169 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigTraceMap, AndInverterGraph};
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that applies temporal decomposition to an AndInverterGraph, the initial
+    /// states of the result are the states that the graph reaches after a number of cycles.
+    /// The first cycles are computed symbolically, using a copy of the inputs for every one
+    /// of them and an input for the initial value of every uninitialized latch. A new latch
+    /// that is 0 only in the first cycle replaces the values of all other latches in that
+    /// cycle with the state reached after these cycles.
+    /// A bad state literal of the result is also true in its first cycle when the bad state
+    /// literal of the graph is true in one of the first cycles, while the invariant
+    /// constraints hold up to it. So the result is unsafe exactly when the graph is, and the
+    /// trace map gives its counter examples in terms of the graph.
+    /// The inputs of the graph come first, followed by the inputs of the first cycles with
+    /// the suffix `_cycle<cycle>`, and the inputs of the initial values of latches with the
+    /// suffix `_init`. The latches of the graph come first, followed by the new latch.
+    /// Outputs, bad state literals, invariant constraints and their symbols are kept, and so
+    /// are the symbols of inputs and latches. Panics if the graph has justice properties or
+    /// fairness constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `number_of_cycles` - the number of cycles to compute symbolically.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// // a latch that becomes 1 and stays 1.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0\n2 1\n");
+    /// let (decomposed, trace_map) = aig.temporal_decomposition(1);
+    /// assert_eq!(trace_map.get_number_of_prefix_cycles(), 1);
+    /// // the latch is 1 from the first cycle on.
+    /// let constant_latches = decomposed.get_constant_latches(10).unwrap();
+    /// assert_eq!(constant_latches, HashMap::from([(1, true)]));
+    /// ```
+    pub fn temporal_decomposition(
+        &self,
+        number_of_cycles: usize,
+    ) -> (AndInverterGraph, AigTraceMap) {
+        self.assert_no_justice_or_fairness("Temporal decomposition");
+        let mut builder = AigBuilder::new();
+        let mut trace_map = AigTraceMap::new(self.latches.len());
+        let inputs = self.add_copies_of_inputs(&mut builder, "");
+        trace_map.add_cycle((0..self.inputs.len()).collect());
+        let inputs_of_cycle: Vec<Vec<usize>> = (0..number_of_cycles)
+            .map(|cycle| {
+                let first = (cycle + 1) * self.inputs.len();
+                trace_map.add_prefix_cycle((first..first + self.inputs.len()).collect());
+                self.add_copies_of_inputs(&mut builder, &format!("_cycle{cycle}"))
+            })
+            .collect();
+        let mut number_of_inputs = (number_of_cycles + 1) * self.inputs.len();
+        let mut state: Vec<usize> = Vec::with_capacity(self.latches.len());
+        for latch_index in self.latches.iter() {
+            let reset = self.get_latch_initial_value(latch_index.to_owned());
+            let initial_value = match reset {
+                Some(value) => usize::from(value),
+                None => {
+                    let input = builder.add_input();
+                    let symbol = self.nodes[latch_index.to_owned()].get_latch_symbol();
+                    if !symbol.is_empty() {
+                        builder.set_input_symbol(input, &format!("{symbol}_init"));
+                    }
+                    input
+                }
+            };
+            trace_map.add_initial_latch_from_input(reset, number_of_inputs);
+            if reset.is_none() {
+                number_of_inputs += 1;
+            }
+            state.push(initial_value);
+        }
+        let mut are_constraints_met = 1;
+        let mut bad_in_first_cycles = vec![0; self.bad.len()];
+        for inputs in inputs_of_cycle.iter() {
+            let literal_map = self.get_literals_of_copy(&mut builder, inputs, &state);
+            let map_literal = |literal: &usize| Self::map_literal(&literal_map, *literal);
+            for constraint in self.constraints.iter() {
+                are_constraints_met = builder.add_and(are_constraints_met, map_literal(constraint));
+            }
+            for (bad_index, bad_literal) in self.bad.iter().enumerate() {
+                let is_bad_now = builder.add_and(map_literal(bad_literal), are_constraints_met);
+                bad_in_first_cycles[bad_index] =
+                    builder.add_or(bad_in_first_cycles[bad_index], is_bad_now);
+            }
+            state = self
+                .latches
+                .iter()
+                .map(|l| map_literal(&self.nodes[l.to_owned()].get_latch_input()))
+                .collect();
+        }
+        // latches that reach a constant start with it, the initial values of the other
+        // latches do not matter since they are replaced in the first cycle.
+        let latches: Vec<usize> = self
+            .latches
+            .iter()
+            .zip(state.iter())
+            .map(|(latch_index, reached)| {
+                self.add_copy_of_latch(&mut builder, latch_index.to_owned(), Some(reached == &1))
+            })
+            .collect();
+        let is_initialized = builder.add_latch(1, Some(false));
+        let current_state: Vec<usize> = latches
+            .iter()
+            .zip(state.iter())
+            .map(|(latch, reached)| match reached {
+                0 | 1 => latch.to_owned(),
+                _ => builder.add_mux(is_initialized, latch.to_owned(), reached.to_owned()),
+            })
+            .collect();
+        let literal_map = self.get_literals_of_copy(&mut builder, &inputs, &current_state);
+        let map_literal = |literal: &usize| Self::map_literal(&literal_map, *literal);
+        for (latch, latch_index) in latches.iter().zip(self.latches.iter()) {
+            let next = map_literal(&self.nodes[latch_index.to_owned()].get_latch_input());
+            builder.set_latch_next(latch.to_owned(), next);
+        }
+
+        for (index, output_literal) in self.outputs.iter().enumerate() {
+            builder.add_output(map_literal(output_literal));
+            let symbol = self.nodes[output_literal >> 1].get_output_symbol();
+            if !symbol.is_empty() {
+                builder.set_output_symbol(index, symbol);
+            }
+        }
+        let are_first_cycles_valid = builder.add_or(is_initialized, are_constraints_met);
+        let mut is_some_bad_in_first_cycles = 0;
+        for (index, bad_literal) in self.bad.iter().enumerate() {
+            let is_bad_now = builder.add_and(map_literal(bad_literal), are_first_cycles_valid);
+            let was_bad = builder.add_and(is_initialized ^ 1, bad_in_first_cycles[index]);
+            let bad = builder.add_or(is_bad_now, was_bad);
+            builder.add_bad(bad);
+            is_some_bad_in_first_cycles = builder.add_or(is_some_bad_in_first_cycles, was_bad);
+            let symbol = self.nodes[bad_literal >> 1].get_bad_symbol();
+            if !symbol.is_empty() {
+                builder.set_bad_symbol(index, symbol);
+            }
+        }
+        // once a bad state literal was true in the first cycles the trace may end.
+        for (index, constraint_literal) in self.constraints.iter().enumerate() {
+            let constraint =
+                builder.add_or(map_literal(constraint_literal), is_some_bad_in_first_cycles);
+            builder.add_constraint(constraint);
+            let symbol = self.nodes[constraint_literal >> 1].get_constraint_symbol();
+            if !symbol.is_empty() {
+                builder.set_constraint_symbol(index, symbol);
+            }
+        }
+        let are_first_cycles_valid =
+            builder.add_or(are_first_cycles_valid, is_some_bad_in_first_cycles);
+        if are_first_cycles_valid != 1 {
+            builder.add_constraint(are_first_cycles_valid);
+        }
+        (builder.build(), trace_map)
+    }
+}
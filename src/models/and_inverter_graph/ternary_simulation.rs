@@ -0,0 +1,273 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::aig_node::AIGNodeType;
+use crate::models::and_inverter_graph::AndInverterGraph;
+use std::collections::{HashMap, HashSet};
+use std::ops::{BitAnd, Not};
+
+// ************************************************************************************************
+// enum
+// ************************************************************************************************
+
+/// Value of a signal in three valued simulation, X means that the value is unknown and may be
+/// either 0 or 1.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum TernaryValue {
+    Zero,
+    One,
+    X,
+}
+
+impl From<bool> for TernaryValue {
+    fn from(value: bool) -> Self {
+        if value {
+            TernaryValue::One
+        } else {
+            TernaryValue::Zero
+        }
+    }
+}
+
+impl Not for TernaryValue {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            TernaryValue::Zero => TernaryValue::One,
+            TernaryValue::One => TernaryValue::Zero,
+            TernaryValue::X => TernaryValue::X,
+        }
+    }
+}
+
+impl BitAnd for TernaryValue {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (TernaryValue::Zero, _) | (_, TernaryValue::Zero) => TernaryValue::Zero,
+            (TernaryValue::One, TernaryValue::One) => TernaryValue::One,
+            _ => TernaryValue::X,
+        }
+    }
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_ternary_value_of_literal(values: &[TernaryValue], literal: usize) -> TernaryValue {
+        if (literal & 1) == 0 {
+            values[literal >> 1]
+        } else {
+            !values[literal >> 1]
+        }
+    }
+
+    pub(super) fn get_initial_ternary_latch_values(
+        &self,
+        initial_latches: &HashMap<usize, TernaryValue>,
+    ) -> HashMap<usize, TernaryValue> {
+        let mut result = HashMap::with_capacity(self.latches.len());
+        for latch_index in self.latches.iter() {
+            let value = match initial_latches.get(latch_index) {
+                Some(value) => value.to_owned(),
+                None => match self.get_latch_initial_value(latch_index.to_owned()) {
+                    Some(false) => TernaryValue::Zero,
+                    Some(true) => TernaryValue::One,
+                    None => TernaryValue::X,
+                },
+            };
+            result.insert(latch_index.to_owned(), value);
+        }
+        result
+    }
+
+    pub(super) fn get_next_ternary_latch_values(
+        &self,
+        values: &[TernaryValue],
+    ) -> HashMap<usize, TernaryValue> {
+        self.latches
+            .iter()
+            .map(|latch_index| {
+                let next = self.nodes[latch_index.to_owned()].get_latch_input();
+                let value = Self::get_ternary_value_of_literal(values, next);
+                (latch_index.to_owned(), value)
+            })
+            .collect()
+    }
+
+    // ********************************************************************************************
+    // aig ternary simulation
+    // ********************************************************************************************
+
+    /// Function that evaluates the combinational logic of the AndInverterGraph in three
+    /// valued logic, the result is the value of every variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `input_values` - maps the variable number of inputs to their values, inputs that are
+    ///   not in the map are X.
+    /// * `latch_values` - maps the variable number of latches to their values, latches that
+    ///   are not in the map are X.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::TernaryValue;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// let aig = AndInverterGraph::from_aag_str("aag 3 2 0 1 1\n2\n4\n6\n6 4 2\n");
+    /// let zero = HashMap::from([(1, TernaryValue::Zero)]);
+    /// assert_eq!(aig.evaluate_ternary(&zero, &HashMap::new())[3], TernaryValue::Zero);
+    /// let one = HashMap::from([(1, TernaryValue::One)]);
+    /// assert_eq!(aig.evaluate_ternary(&one, &HashMap::new())[3], TernaryValue::X);
+    /// ```
+    pub fn evaluate_ternary(
+        &self,
+        input_values: &HashMap<usize, TernaryValue>,
+        latch_values: &HashMap<usize, TernaryValue>,
+    ) -> Vec<TernaryValue> {
+        let mut result = Vec::with_capacity(self.nodes.len());
+        for (variable, node) in self.nodes.iter().enumerate() {
+            let value = match node.get_type() {
+                AIGNodeType::ConstantZero => TernaryValue::Zero,
+                AIGNodeType::Input => input_values
+                    .get(&variable)
+                    .copied()
+                    .unwrap_or(TernaryValue::X),
+                AIGNodeType::Latch => latch_values
+                    .get(&variable)
+                    .copied()
+                    .unwrap_or(TernaryValue::X),
+                AIGNodeType::And => {
+                    Self::get_ternary_value_of_literal(&result, node.get_and_rhs0())
+                        & Self::get_ternary_value_of_literal(&result, node.get_and_rhs1())
+                }
+            };
+            result.push(value);
+        }
+        result
+    }
+
+    /// Function that simulates the AndInverterGraph in three valued logic and returns the
+    /// value of every variable in every cycle, `result[cycle][variable]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `inputs` - for each cycle, maps the variable number of inputs to their values,
+    ///   inputs that are not in the map are X.
+    /// * `initial_latches` - maps the variable number of latches to their values in cycle 0,
+    ///   latches that are not in the map start at their reset value, or X if they are
+    ///   uninitialized.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::TernaryValue;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// // an uninitialized latch that is reset to 0 by the input.
+    /// let aig = AndInverterGraph::from_aag_str("aag 3 1 1 0 1\n2\n4 6 4\n6 4 3\n");
+    /// let inputs = vec![HashMap::from([(1, TernaryValue::One)]), HashMap::new()];
+    /// let states = aig.simulate_ternary(&inputs, &HashMap::new());
+    /// assert_eq!(states[0][2], TernaryValue::X);
+    /// assert_eq!(states[1][2], TernaryValue::Zero);
+    /// ```
+    pub fn simulate_ternary(
+        &self,
+        inputs: &[HashMap<usize, TernaryValue>],
+        initial_latches: &HashMap<usize, TernaryValue>,
+    ) -> Vec<Vec<TernaryValue>> {
+        let mut result: Vec<Vec<TernaryValue>> = Vec::with_capacity(inputs.len());
+        let mut latch_values = self.get_initial_ternary_latch_values(initial_latches);
+        for input_values in inputs {
+            let values = self.evaluate_ternary(input_values, &latch_values);
+            latch_values = self.get_next_ternary_latch_values(&values);
+            result.push(values);
+        }
+        result
+    }
+
+    /// Function that simulates the AndInverterGraph in three valued logic from its initial
+    /// state where all inputs are X, until the values of the latches repeat.
+    /// The result is the values of the latches in each cycle until the repetition, one vector
+    /// per cycle ordered like the latches, or None if the values do not repeat within the
+    /// number of cycles given.
+    /// Every reachable state agrees with one of these vectors wherever it is not X.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `maximum_number_of_cycles` - the number of cycles after which to give up.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::TernaryValue;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let aig = AndInverterGraph::from_aig_path("tests/examples/ours/counter.aig");
+    /// let states = aig.get_ternary_latch_values_until_stable(10).unwrap();
+    /// assert_eq!(states.len(), 4);
+    /// assert_eq!(states[0], vec![TernaryValue::Zero; 3]);
+    /// assert_eq!(aig.get_ternary_latch_values_until_stable(2), None);
+    /// ```
+    pub fn get_ternary_latch_values_until_stable(
+        &self,
+        maximum_number_of_cycles: usize,
+    ) -> Option<Vec<Vec<TernaryValue>>> {
+        let mut result: Vec<Vec<TernaryValue>> = Vec::new();
+        let mut seen: HashSet<Vec<TernaryValue>> = HashSet::new();
+        let mut latch_values = self.get_initial_ternary_latch_values(&HashMap::new());
+        let no_inputs = HashMap::new();
+        for _ in 0..=maximum_number_of_cycles {
+            let state: Vec<TernaryValue> = self.latches.iter().map(|l| latch_values[l]).collect();
+            if !seen.insert(state.to_owned()) {
+                return Some(result);
+            }
+            result.push(state);
+            let values = self.evaluate_ternary(&no_inputs, &latch_values);
+            latch_values = self.get_next_ternary_latch_values(&values);
+        }
+        None
+    }
+
+    /// Function that finds latches that have the same value in every reachable state using
+    /// three valued simulation, the result maps the variable number of such latches to their
+    /// value, or is None if the simulation does not stabilize within the number of cycles
+    /// given.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AndInverterGraph desired.
+    /// * `maximum_number_of_cycles` - the number of cycles after which to give up.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// use std::collections::HashMap;
+    /// // latch 2 is stuck at 0, latch 3 copies an input.
+    /// let aig = AndInverterGraph::from_aag_str("aag 4 1 2 0 1\n2\n4 8\n6 2\n8 4 2\n");
+    /// assert_eq!(aig.get_constant_latches(10), Some(HashMap::from([(2, false)])));
+    /// ```
+    pub fn get_constant_latches(
+        &self,
+        maximum_number_of_cycles: usize,
+    ) -> Option<HashMap<usize, bool>> {
+        let states = self.get_ternary_latch_values_until_stable(maximum_number_of_cycles)?;
+        let mut result = HashMap::new();
+        for (position, latch_index) in self.latches.iter().enumerate() {
+            let first = states[0][position];
+            if first != TernaryValue::X && states.iter().all(|s| s[position] == first) {
+                result.insert(latch_index.to_owned(), first == TernaryValue::One);
+            }
+        }
+        Some(result)
+    }
+}
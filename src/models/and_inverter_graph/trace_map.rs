@@ -0,0 +1,255 @@
+// ************************************************************************************************
+// enum
+// ************************************************************************************************
+
+/// where the initial value of a latch of the original graph is found in a trace of the
+/// transformed graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InitialLatchSource {
+    Reset(bool),
+    Latch(usize),
+    FirstCycleInput(usize),
+}
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that describes how a trace of an AndInverterGraph that was created by a
+/// transformation, like phase abstraction or temporal decomposition, maps back to a trace of
+/// the original AndInverterGraph.
+///
+/// Every cycle of the transformed trace gives the same number of cycles of the original
+/// trace, and the first cycle of the transformed trace may also give some cycles that come
+/// before all of them. For liveness to safety the original trace is a lasso, and the trace
+/// map also finds the cycle in which its loop starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AigTraceMap {
+    initial_latches: Vec<InitialLatchSource>,
+    // the positions of the inputs of the transformed graph that give the inputs of the
+    // original graph, in the cycles that come from the first cycle.
+    prefix_inputs: Vec<Vec<usize>>,
+    // the same, for the cycles that every cycle of the transformed trace gives.
+    inputs_of_cycle: Vec<Vec<usize>>,
+    // the position of the input of the transformed graph that is 1 in the first cycle of the
+    // loop of a lasso.
+    loop_start_input: Option<usize>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl AigTraceMap {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    pub(super) fn new(number_of_latches: usize) -> Self {
+        Self {
+            initial_latches: Vec::with_capacity(number_of_latches),
+            prefix_inputs: Vec::new(),
+            inputs_of_cycle: Vec::new(),
+            loop_start_input: None,
+        }
+    }
+
+    /// the next latch has a reset value, or is uninitialized and gets its value from a
+    /// latch of the transformed graph, given by position.
+    pub(super) fn add_initial_latch_from_latch(&mut self, reset: Option<bool>, latch: usize) {
+        self.initial_latches.push(match reset {
+            Some(value) => InitialLatchSource::Reset(value),
+            None => InitialLatchSource::Latch(latch),
+        });
+    }
+
+    /// the next latch has a reset value, or is uninitialized and gets its value from an input
+    /// of the transformed graph in the first cycle, given by position.
+    pub(super) fn add_initial_latch_from_input(&mut self, reset: Option<bool>, input: usize) {
+        self.initial_latches.push(match reset {
+            Some(value) => InitialLatchSource::Reset(value),
+            None => InitialLatchSource::FirstCycleInput(input),
+        });
+    }
+
+    pub(super) fn add_prefix_cycle(&mut self, inputs: Vec<usize>) {
+        self.prefix_inputs.push(inputs);
+    }
+
+    pub(super) fn add_cycle(&mut self, inputs: Vec<usize>) {
+        self.inputs_of_cycle.push(inputs);
+    }
+
+    pub(super) fn set_loop_start_input(&mut self, input: usize) {
+        self.loop_start_input = Some(input);
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that returns the number of cycles of the original trace that come from
+    /// every cycle of the transformed trace.
+    pub fn get_number_of_cycles_per_cycle(&self) -> usize {
+        self.inputs_of_cycle.len()
+    }
+
+    /// Function that returns the number of cycles of the original trace that only come from
+    /// the first cycle of the transformed trace, they come before all other cycles.
+    pub fn get_number_of_prefix_cycles(&self) -> usize {
+        self.prefix_inputs.len()
+    }
+
+    /// Function that maps a trace of the transformed graph to a trace of the original graph.
+    /// The result is the initial values of the latches of the original graph, with None for
+    /// uninitialized latches whose value is unknown, and the values of its inputs in every
+    /// cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AigTraceMap desired.
+    /// * `initial_latches` - the initial values of the latches of the transformed graph.
+    /// * `inputs` - the values of the inputs of the transformed graph in every cycle, there
+    ///   must be at least one cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that toggles, and an input.
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 1 1 0 0\n2\n4 5\n");
+    /// // every cycle of the unfolded graph is 2 cycles of the original graph.
+    /// let (_, trace_map) = aig.unfold(2);
+    /// let (initial_latches, inputs) = trace_map.map_trace(&[Some(false)], &[vec![true, false]]);
+    /// assert_eq!(initial_latches, vec![Some(false)]);
+    /// assert_eq!(inputs, vec![vec![true], vec![false]]);
+    /// ```
+    pub fn map_trace(
+        &self,
+        initial_latches: &[Option<bool>],
+        inputs: &[Vec<bool>],
+    ) -> (Vec<Option<bool>>, Vec<Vec<bool>>) {
+        assert!(!inputs.is_empty(), "A trace has at least one cycle.");
+        let original_initial_latches = self
+            .initial_latches
+            .iter()
+            .map(|source| match source {
+                InitialLatchSource::Reset(value) => Some(value.to_owned()),
+                InitialLatchSource::Latch(position) => initial_latches[position.to_owned()],
+                InitialLatchSource::FirstCycleInput(position) => {
+                    Some(inputs[0][position.to_owned()])
+                }
+            })
+            .collect();
+        let get_values = |values: &[bool], positions: &Vec<usize>| -> Vec<bool> {
+            positions.iter().map(|p| values[p.to_owned()]).collect()
+        };
+        let mut original_inputs: Vec<Vec<bool>> = self
+            .prefix_inputs
+            .iter()
+            .map(|positions| get_values(&inputs[0], positions))
+            .collect();
+        for values in inputs.iter() {
+            for positions in self.inputs_of_cycle.iter() {
+                original_inputs.push(get_values(values, positions));
+            }
+        }
+        (original_initial_latches, original_inputs)
+    }
+
+    /// Function that composes two trace maps, when a graph is transformed and the result is
+    /// transformed again. The result maps traces of the second transformed graph directly to
+    /// traces of the original graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AigTraceMap from the first transformed graph to the original graph,
+    ///   it cannot come from `liveness_to_safety`.
+    /// * `other` - the AigTraceMap from the second transformed graph to the first one.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that becomes 1 and stays 1, and an input.
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 1 1 0 0\n2\n4 1\n");
+    /// let (decomposed, decomposition_map) = aig.temporal_decomposition(1);
+    /// let (_, unfold_map) = decomposed.unfold(2);
+    /// let trace_map = decomposition_map.compose(&unfold_map);
+    /// assert_eq!(trace_map.get_number_of_prefix_cycles(), 1);
+    /// assert_eq!(trace_map.get_number_of_cycles_per_cycle(), 2);
+    /// ```
+    pub fn compose(&self, other: &AigTraceMap) -> AigTraceMap {
+        assert!(
+            self.loop_start_input.is_none(),
+            "The trace map of a lasso cannot be composed with another one."
+        );
+        let compose_positions = |outer: &Vec<usize>, inner: &Vec<usize>| -> Vec<usize> {
+            outer.iter().map(|p| inner[p.to_owned()]).collect()
+        };
+        // the inputs of the first cycle of the first transformed graph.
+        let first_cycle = other
+            .prefix_inputs
+            .first()
+            .unwrap_or(&other.inputs_of_cycle[0]);
+        let initial_latches = self
+            .initial_latches
+            .iter()
+            .map(|source| match source {
+                InitialLatchSource::Reset(value) => InitialLatchSource::Reset(value.to_owned()),
+                InitialLatchSource::Latch(position) => {
+                    other.initial_latches[position.to_owned()].to_owned()
+                }
+                InitialLatchSource::FirstCycleInput(position) => {
+                    InitialLatchSource::FirstCycleInput(first_cycle[position.to_owned()])
+                }
+            })
+            .collect();
+        let mut prefix_inputs: Vec<Vec<usize>> = self
+            .prefix_inputs
+            .iter()
+            .map(|positions| compose_positions(positions, first_cycle))
+            .collect();
+        for inner in other.prefix_inputs.iter() {
+            for outer in self.inputs_of_cycle.iter() {
+                prefix_inputs.push(compose_positions(outer, inner));
+            }
+        }
+        let mut inputs_of_cycle = Vec::new();
+        for inner in other.inputs_of_cycle.iter() {
+            for outer in self.inputs_of_cycle.iter() {
+                inputs_of_cycle.push(compose_positions(outer, inner));
+            }
+        }
+        AigTraceMap {
+            initial_latches,
+            prefix_inputs,
+            inputs_of_cycle,
+            loop_start_input: other.loop_start_input,
+        }
+    }
+
+    /// Function that finds the cycle of the original trace in which the loop of a lasso
+    /// starts, for the trace map of `liveness_to_safety`. The last cycle of the trace is in
+    /// the same state as this cycle. The result is None if the trace is not a lasso.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the AigTraceMap desired.
+    /// * `inputs` - the values of the inputs of the transformed graph in every cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a latch that stays 0, with a justice property that it is 0 infinitely often.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0 0 0 1 0\n2 2\n1\n3\n");
+    /// let (_, trace_map) = aig.liveness_to_safety();
+    /// // the state is saved in the second cycle, the third cycle closes the loop.
+    /// let inputs = [vec![false], vec![true], vec![false]];
+    /// assert_eq!(trace_map.get_loop_start(&inputs), Some(1));
+    /// assert_eq!(trace_map.get_loop_start(&inputs[..1]), None);
+    /// ```
+    pub fn get_loop_start(&self, inputs: &[Vec<bool>]) -> Option<usize> {
+        let loop_start_input = self.loop_start_input?;
+        let cycle = inputs.iter().position(|values| values[loop_start_input])?;
+        Some(self.prefix_inputs.len() + cycle * self.inputs_of_cycle.len())
+    }
+}
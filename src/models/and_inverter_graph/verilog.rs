@@ -0,0 +1,1144 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigParseError, AndInverterGraph};
+use std::collections::{HashMap, HashSet};
+
+// ************************************************************************************************
+// enum
+// ************************************************************************************************
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VerilogExpression {
+    Constant(bool),
+    // the name of the signal and the line it was used in.
+    Signal(String, usize),
+    Not(Box<VerilogExpression>),
+    And(Box<VerilogExpression>, Box<VerilogExpression>),
+    Or(Box<VerilogExpression>, Box<VerilogExpression>),
+    Xor(Box<VerilogExpression>, Box<VerilogExpression>),
+    Mux(
+        Box<VerilogExpression>,
+        Box<VerilogExpression>,
+        Box<VerilogExpression>,
+    ),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VerilogStatement {
+    Block(Vec<VerilogStatement>),
+    If(
+        VerilogExpression,
+        Box<VerilogStatement>,
+        Option<Box<VerilogStatement>>,
+    ),
+    // the assigned signal, its value and the line of the assignment.
+    Assign(String, VerilogExpression, usize),
+}
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// everything that is known about a module while its tokens are read one by one.
+#[derive(Default)]
+struct VerilogReader {
+    // tokens with the line they are in (counted from 1).
+    tokens: Vec<(String, usize)>,
+    position: usize,
+
+    inputs: Vec<(String, usize)>,
+    outputs: Vec<(String, usize)>,
+    vectors: HashSet<String>,
+    // the expressions of wires, and the next state expressions of registers.
+    drivers: HashMap<String, (VerilogExpression, usize)>,
+    next_states: HashMap<String, (VerilogExpression, usize)>,
+    registers: Vec<String>,
+    // initial values with the line they were given in.
+    initial_values: HashMap<String, (Option<bool>, usize)>,
+    clocks: HashSet<String>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl VerilogReader {
+    // ********************************************************************************************
+    // tokens
+    // ********************************************************************************************
+
+    fn error(line: usize, reason: String) -> AigParseError {
+        AigParseError::InvalidLine { line, reason }
+    }
+
+    /// splits the file into identifiers, numbers and symbols, skipping comments, attributes
+    /// and compiler directives.
+    fn get_tokens(verilog: &str) -> Result<Vec<(String, usize)>, AigParseError> {
+        let chars: Vec<char> = verilog.chars().collect();
+        let starts_with = |i: usize, s: &str| {
+            s.chars()
+                .enumerate()
+                .all(|(k, c)| chars.get(i + k) == Some(&c))
+        };
+        let mut tokens = Vec::new();
+        let mut line = 1;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let start = i;
+            if c == '\n' {
+                line += 1;
+                i += 1;
+            } else if c.is_whitespace() {
+                i += 1;
+            } else if starts_with(i, "//") || c == '`' {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            } else if starts_with(i, "/*") || (starts_with(i, "(*") && !starts_with(i, "(*)")) {
+                let end = if c == '/' { "*/" } else { "*)" };
+                let start_line = line;
+                i += 2;
+                while i < chars.len() && !starts_with(i, end) {
+                    line += usize::from(chars[i] == '\n');
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(Self::error(
+                        start_line,
+                        String::from("Comment is never closed."),
+                    ));
+                }
+                i += 2;
+            } else {
+                let is_word_char =
+                    |c: &char| c.is_alphanumeric() || *c == '_' || *c == '$' || *c == '\'';
+                if c == '\\' {
+                    while i < chars.len() && !chars[i].is_whitespace() {
+                        i += 1;
+                    }
+                } else if is_word_char(&c) {
+                    while i < chars.len() && is_word_char(&chars[i]) {
+                        i += 1;
+                    }
+                } else if ["<=", "&&", "||", "~^", "^~"]
+                    .iter()
+                    .any(|s| starts_with(i, s))
+                {
+                    i += 2;
+                } else {
+                    i += 1;
+                }
+                tokens.push((chars[start..i].iter().collect(), line));
+            }
+        }
+        Ok(tokens)
+    }
+
+    fn get_line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or_else(|| self.tokens.last())
+            .map_or(1, |(_, line)| line.to_owned())
+    }
+
+    fn peek(&self) -> &str {
+        self.tokens
+            .get(self.position)
+            .map_or("", |(token, _)| token)
+    }
+
+    fn next(&mut self) -> Result<(String, usize), AigParseError> {
+        let token =
+            self.tokens.get(self.position).cloned().ok_or_else(|| {
+                Self::error(self.get_line(), String::from("Unexpected end of file."))
+            })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn is_next(&mut self, symbol: &str) -> bool {
+        let is_next = self.peek() == symbol;
+        if is_next {
+            self.position += 1;
+        }
+        is_next
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<(), AigParseError> {
+        let (token, line) = self.next()?;
+        if token == symbol {
+            Ok(())
+        } else {
+            Err(Self::error(
+                line,
+                format!("Expected {symbol}, found {token}."),
+            ))
+        }
+    }
+
+    fn is_identifier(token: &str) -> bool {
+        token
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '\\')
+    }
+
+    fn read_name(&mut self) -> Result<(String, usize), AigParseError> {
+        let (token, line) = self.next()?;
+        if Self::is_identifier(&token) {
+            let name = token.strip_prefix('\\').unwrap_or(&token);
+            Ok((name.to_string(), line))
+        } else {
+            Err(Self::error(
+                line,
+                format!("Expected a name, found {token}."),
+            ))
+        }
+    }
+
+    fn read_number(&mut self) -> Result<usize, AigParseError> {
+        let (token, line) = self.next()?;
+        token
+            .parse()
+            .map_err(|_| Self::error(line, format!("Expected a number, found {token}.")))
+    }
+
+    /// a name with an optional bit select, like `a` or `a[3]`.
+    fn read_signal(&mut self) -> Result<(String, usize), AigParseError> {
+        let (name, line) = self.read_name()?;
+        if self.is_next("[") {
+            let index = self.read_number()?;
+            self.expect("]")?;
+            Ok((format!("{name}[{index}]"), line))
+        } else if self.vectors.contains(&name) {
+            Err(Self::error(
+                line,
+                format!("Vector {name} should be used one bit at a time."),
+            ))
+        } else {
+            Ok((name, line))
+        }
+    }
+
+    /// constants like `0`, `1'b1` or `1'h0`, None is an unknown value like `1'bx`. Sized
+    /// constants must have the size 1, even if their value fits in one bit like `4'b0001`.
+    fn get_constant(token: &str, line: usize) -> Result<Option<bool>, AigParseError> {
+        let (size, digits) = match token.split_once('\'') {
+            Some((size, based)) => (size, based.get(1..).unwrap_or("")),
+            None => ("", token),
+        };
+        let is_single_bit = size.is_empty() || size == "1";
+        match digits.trim_start_matches('0') {
+            "" if is_single_bit => Ok(Some(false)),
+            "1" if is_single_bit => Ok(Some(true)),
+            "x" | "X" if is_single_bit => Ok(None),
+            _ => Err(Self::error(
+                line,
+                format!("Constant {token} is not a single bit."),
+            )),
+        }
+    }
+
+    // ********************************************************************************************
+    // expressions
+    // ********************************************************************************************
+
+    fn read_primary(&mut self) -> Result<VerilogExpression, AigParseError> {
+        let token = self.peek().to_string();
+        let line = self.get_line();
+        if self.is_next("(") {
+            let expression = self.read_expression()?;
+            self.expect(")")?;
+            Ok(expression)
+        } else if self.is_next("~") || self.is_next("!") {
+            Ok(VerilogExpression::Not(Box::new(self.read_primary()?)))
+        } else if Self::is_identifier(&token) {
+            let (name, line) = self.read_signal()?;
+            Ok(VerilogExpression::Signal(name, line))
+        } else {
+            self.next()?;
+            match token.chars().next() {
+                Some(c) if c.is_ascii_digit() || c == '\'' => {
+                    match Self::get_constant(&token, line)? {
+                        Some(value) => Ok(VerilogExpression::Constant(value)),
+                        None => Err(Self::error(
+                            line,
+                            String::from("Unknown values are only supported as initial values."),
+                        )),
+                    }
+                }
+                _ => Err(Self::error(
+                    line,
+                    format!("Unexpected {token} in expression."),
+                )),
+            }
+        }
+    }
+
+    /// binary operators from the one that binds the weakest to the one that binds the
+    /// strongest, `~^` and `^~` are xnor.
+    fn read_binary_operators(&mut self, level: usize) -> Result<VerilogExpression, AigParseError> {
+        const LEVELS: [&[&str]; 5] = [&["||"], &["&&"], &["|"], &["^", "~^", "^~"], &["&"]];
+        if level == LEVELS.len() {
+            return self.read_primary();
+        }
+        let mut expression = self.read_binary_operators(level + 1)?;
+        while let Some(operator) = LEVELS[level].iter().find(|o| **o == self.peek()) {
+            self.position += 1;
+            let lhs = Box::new(expression);
+            let rhs = Box::new(self.read_binary_operators(level + 1)?);
+            expression = match *operator {
+                "||" | "|" => VerilogExpression::Or(lhs, rhs),
+                "&&" | "&" => VerilogExpression::And(lhs, rhs),
+                "^" => VerilogExpression::Xor(lhs, rhs),
+                _ => VerilogExpression::Not(Box::new(VerilogExpression::Xor(lhs, rhs))),
+            };
+        }
+        Ok(expression)
+    }
+
+    fn read_expression(&mut self) -> Result<VerilogExpression, AigParseError> {
+        let condition = self.read_binary_operators(0)?;
+        if self.is_next("?") {
+            let then_expression = self.read_expression()?;
+            self.expect(":")?;
+            let else_expression = self.read_expression()?;
+            Ok(VerilogExpression::Mux(
+                Box::new(condition),
+                Box::new(then_expression),
+                Box::new(else_expression),
+            ))
+        } else {
+            Ok(condition)
+        }
+    }
+
+    // ********************************************************************************************
+    // statements
+    // ********************************************************************************************
+
+    fn read_statement(&mut self) -> Result<VerilogStatement, AigParseError> {
+        if self.is_next("begin") {
+            let mut statements = Vec::new();
+            while !self.is_next("end") {
+                statements.push(self.read_statement()?);
+            }
+            Ok(VerilogStatement::Block(statements))
+        } else if self.is_next("if") {
+            self.expect("(")?;
+            let condition = self.read_expression()?;
+            self.expect(")")?;
+            let then_statement = Box::new(self.read_statement()?);
+            let else_statement = if self.is_next("else") {
+                Some(Box::new(self.read_statement()?))
+            } else {
+                None
+            };
+            Ok(VerilogStatement::If(
+                condition,
+                then_statement,
+                else_statement,
+            ))
+        } else {
+            let (signal, line) = self.read_signal()?;
+            if self.peek() == "=" {
+                return Err(Self::error(
+                    line,
+                    format!(
+                        "Blocking assignment to {signal}, registers should be assigned with <=."
+                    ),
+                ));
+            }
+            self.expect("<=")?;
+            let value = self.read_expression()?;
+            self.expect(";")?;
+            Ok(VerilogStatement::Assign(signal, value, line))
+        }
+    }
+
+    /// the next state expressions of the registers that the statement assigns, registers that
+    /// are not assigned in some branch keep their value.
+    fn get_next_states_of_statement(
+        statement: &VerilogStatement,
+        next_states: &mut HashMap<String, (VerilogExpression, usize)>,
+    ) {
+        match statement {
+            VerilogStatement::Block(statements) => {
+                for s in statements.iter() {
+                    Self::get_next_states_of_statement(s, next_states);
+                }
+            }
+            VerilogStatement::Assign(signal, value, line) => {
+                next_states.insert(signal.to_owned(), (value.to_owned(), line.to_owned()));
+            }
+            VerilogStatement::If(condition, then_statement, else_statement) => {
+                let mut then_states = next_states.clone();
+                let mut else_states = next_states.clone();
+                Self::get_next_states_of_statement(then_statement, &mut then_states);
+                if let Some(s) = else_statement {
+                    Self::get_next_states_of_statement(s, &mut else_states);
+                }
+                let registers: HashSet<&String> =
+                    then_states.keys().chain(else_states.keys()).collect();
+                let mut merged = HashMap::new();
+                for register in registers {
+                    let get_state = |states: &HashMap<String, (VerilogExpression, usize)>| {
+                        states
+                            .get(register)
+                            .cloned()
+                            .unwrap_or((VerilogExpression::Signal(register.to_owned(), 0), 0))
+                    };
+                    let (then_value, then_line) = get_state(&then_states);
+                    let (else_value, else_line) = get_state(&else_states);
+                    let value = VerilogExpression::Mux(
+                        Box::new(condition.to_owned()),
+                        Box::new(then_value),
+                        Box::new(else_value),
+                    );
+                    merged.insert(register.to_owned(), (value, then_line.max(else_line)));
+                }
+                *next_states = merged;
+            }
+        }
+    }
+
+    /// the assignments of constants in a statement that only has assignments, or None if it
+    /// has other statements.
+    fn get_constant_assignments(
+        statement: &VerilogStatement,
+        assignments: &mut Vec<(String, bool, usize)>,
+    ) -> Option<()> {
+        match statement {
+            VerilogStatement::Block(statements) => {
+                for s in statements.iter() {
+                    Self::get_constant_assignments(s, assignments)?;
+                }
+                Some(())
+            }
+            VerilogStatement::Assign(signal, VerilogExpression::Constant(value), line) => {
+                assignments.push((signal.to_owned(), value.to_owned(), line.to_owned()));
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn set_initial_value(
+        &mut self,
+        register: &str,
+        value: Option<bool>,
+        line: usize,
+    ) -> Result<(), AigParseError> {
+        match self
+            .initial_values
+            .insert(register.to_string(), (value, line))
+        {
+            Some((previous, _)) if previous != value => Err(Self::error(
+                line,
+                format!("Register {register} has more than one initial value."),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    // ********************************************************************************************
+    // module items
+    // ********************************************************************************************
+
+    /// the names of the bits of a declared signal, vectors are declared bit by bit.
+    fn get_declared_bits(&mut self, name: &str, range: Option<(usize, usize)>) -> Vec<String> {
+        match range {
+            None => vec![name.to_string()],
+            Some((msb, lsb)) => {
+                self.vectors.insert(name.to_string());
+                (msb.min(lsb)..=msb.max(lsb))
+                    .map(|index| format!("{name}[{index}]"))
+                    .collect()
+            }
+        }
+    }
+
+    fn read_range(&mut self) -> Result<Option<(usize, usize)>, AigParseError> {
+        if self.is_next("[") {
+            let msb = self.read_number()?;
+            self.expect(":")?;
+            let lsb = self.read_number()?;
+            self.expect("]")?;
+            Ok(Some((msb, lsb)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn add_driver(
+        &mut self,
+        signal: String,
+        value: VerilogExpression,
+        line: usize,
+    ) -> Result<(), AigParseError> {
+        if self.drivers.contains_key(&signal) || self.next_states.contains_key(&signal) {
+            return Err(Self::error(
+                line,
+                format!("Signal {signal} is assigned more than once."),
+            ));
+        }
+        self.drivers.insert(signal, (value, line));
+        Ok(())
+    }
+
+    /// reads the names of a declaration that starts with `input`, `output`, `wire` or `reg`,
+    /// up to the `;`, or up to the next port in an ANSI style port list.
+    fn read_declaration(&mut self, kind: &str, is_port_list: bool) -> Result<(), AigParseError> {
+        let mut is_reg = kind == "reg";
+        if self.is_next("reg") {
+            is_reg = true;
+        } else {
+            self.is_next("wire");
+        }
+        let range = self.read_range()?;
+        loop {
+            let (name, line) = self.read_name()?;
+            let bits = self.get_declared_bits(&name, range);
+            for bit in bits.iter() {
+                match kind {
+                    "input" => self.inputs.push((bit.to_owned(), line)),
+                    "output" => self.outputs.push((bit.to_owned(), line)),
+                    _ => {}
+                }
+            }
+            if self.is_next("=") {
+                if bits.len() != 1 {
+                    return Err(Self::error(
+                        line,
+                        format!("Vector {name} cannot be assigned."),
+                    ));
+                }
+                if is_reg {
+                    let value = self.read_initial_value(&name)?;
+                    self.set_initial_value(&name, value, line)?;
+                } else {
+                    let value = self.read_expression()?;
+                    self.add_driver(name, value, line)?;
+                }
+            }
+            let is_next_a_name = self
+                .tokens
+                .get(self.position + 1)
+                .is_some_and(|(t, _)| Self::is_identifier(t));
+            let is_next_a_port = is_port_list
+                && ["input", "output", "inout"].contains(
+                    &self
+                        .tokens
+                        .get(self.position + 1)
+                        .map_or("", |(t, _)| t.as_str()),
+                );
+            if is_next_a_port || !is_next_a_name || !self.is_next(",") {
+                break;
+            }
+        }
+        if !is_port_list {
+            self.expect(";")?;
+        }
+        Ok(())
+    }
+
+    fn read_port_list(&mut self) -> Result<(), AigParseError> {
+        if !self.is_next("(") {
+            return Ok(());
+        }
+        while !self.is_next(")") {
+            let token = self.peek().to_string();
+            if token == "input" || token == "output" {
+                self.position += 1;
+                self.read_declaration(&token, true)?;
+            } else if token == "inout" {
+                return Err(Self::error(
+                    self.get_line(),
+                    String::from("Inout ports are not supported."),
+                ));
+            } else {
+                // the direction of ports that are only named is declared in the module.
+                self.read_name()?;
+            }
+            if !self.is_next(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        self.expect(";")
+    }
+
+    fn read_assign(&mut self) -> Result<(), AigParseError> {
+        loop {
+            let (signal, line) = self.read_signal()?;
+            self.expect("=")?;
+            let value = self.read_expression()?;
+            self.add_driver(signal, value, line)?;
+            if !self.is_next(",") {
+                return self.expect(";");
+            }
+        }
+    }
+
+    /// gate primitives like `and g1 (y, a, b);`, the first terminal is the output.
+    fn read_gate(&mut self, gate: &str) -> Result<(), AigParseError> {
+        loop {
+            if Self::is_identifier(self.peek()) {
+                self.read_name()?;
+            }
+            self.expect("(")?;
+            let (output, line) = self.read_signal()?;
+            let mut inputs = Vec::new();
+            while self.is_next(",") {
+                inputs.push(self.read_expression()?);
+            }
+            self.expect(")")?;
+            let is_unary = gate == "not" || gate == "buf";
+            if inputs.is_empty() || (is_unary && inputs.len() != 1) {
+                return Err(Self::error(
+                    line,
+                    format!("Wrong number of inputs for {gate}."),
+                ));
+            }
+            let mut value = inputs.remove(0);
+            for input in inputs {
+                let (lhs, rhs) = (Box::new(value), Box::new(input));
+                value = match gate {
+                    "and" | "nand" => VerilogExpression::And(lhs, rhs),
+                    "or" | "nor" => VerilogExpression::Or(lhs, rhs),
+                    _ => VerilogExpression::Xor(lhs, rhs),
+                };
+            }
+            if ["nand", "nor", "xnor", "not"].contains(&gate) {
+                value = VerilogExpression::Not(Box::new(value));
+            }
+            self.add_driver(output, value, line)?;
+            if !self.is_next(",") {
+                return self.expect(";");
+            }
+        }
+    }
+
+    /// `always @(posedge clk or negedge rst_n)`, when the block starts with an `if` on one
+    /// of the edge signals that assigns constants, these constants are the initial values.
+    /// The `if` must check the level of the edge, like `if (!rst_n)` for `negedge rst_n`.
+    fn read_always(&mut self) -> Result<(), AigParseError> {
+        let line = self.get_line();
+        self.expect("@")?;
+        self.expect("(")?;
+        // every edge signal with whether it is triggered on the rising edge.
+        let mut edge_signals: Vec<(String, bool)> = Vec::new();
+        loop {
+            let is_posedge = self.is_next("posedge");
+            if !is_posedge && !self.is_next("negedge") {
+                return Err(Self::error(
+                    line,
+                    String::from("Only always blocks triggered by clock edges are supported."),
+                ));
+            }
+            edge_signals.push((self.read_signal()?.0, is_posedge));
+            if !self.is_next("or") && !self.is_next(",") {
+                break;
+            }
+        }
+        self.expect(")")?;
+        let statement = self.read_statement()?;
+
+        let mut top_statement = &statement;
+        while let VerilogStatement::Block(statements) = top_statement {
+            match statements.as_slice() {
+                [s] => top_statement = s,
+                _ => break,
+            }
+        }
+        let mut reset_signal = None;
+        if let VerilogStatement::If(condition, then_statement, _) = top_statement {
+            // the signal of the condition, and whether the condition is that it is 1.
+            let signal = match condition {
+                VerilogExpression::Signal(s, line) => Some((s, true, line)),
+                VerilogExpression::Not(inner) => match inner.as_ref() {
+                    VerilogExpression::Signal(s, line) => Some((s, false, line)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let edge = signal.and_then(|(s, is_checked_high, line)| {
+                edge_signals
+                    .iter()
+                    .find(|(edge_signal, _)| edge_signal == s)
+                    .map(|(_, is_posedge)| (s, is_checked_high, line, is_posedge.to_owned()))
+            });
+            let mut assignments = Vec::new();
+            if let Some((s, is_checked_high, line, is_posedge)) = edge {
+                if Self::get_constant_assignments(then_statement, &mut assignments).is_some() {
+                    if is_checked_high != is_posedge {
+                        let (edge_name, level) = if is_posedge {
+                            ("posedge", 1)
+                        } else {
+                            ("negedge", 0)
+                        };
+                        return Err(Self::error(
+                            line.to_owned(),
+                            format!("Reset {s} is triggered by {edge_name} so it should be checked when it is {level}."),
+                        ));
+                    }
+                    reset_signal = Some(s.to_owned());
+                    for (register, value, line) in assignments {
+                        self.set_initial_value(&register, Some(value), line)?;
+                    }
+                }
+            }
+        }
+        for (signal, _) in edge_signals {
+            if Some(&signal) != reset_signal.as_ref() {
+                self.clocks.insert(signal);
+            }
+        }
+
+        let mut next_states = HashMap::new();
+        Self::get_next_states_of_statement(&statement, &mut next_states);
+        let mut assigned: Vec<(String, (VerilogExpression, usize))> =
+            next_states.into_iter().collect();
+        assigned.sort_by_key(|(register, (_, line))| (line.to_owned(), register.to_owned()));
+        for (register, (value, line)) in assigned {
+            if self.drivers.contains_key(&register) || self.next_states.contains_key(&register) {
+                return Err(Self::error(
+                    line,
+                    format!("Signal {register} is assigned more than once."),
+                ));
+            }
+            self.registers.push(register.to_owned());
+            self.next_states.insert(register, (value, line));
+        }
+        Ok(())
+    }
+
+    /// initial values are constants, and can be unknown like `1'bx`.
+    fn read_initial_value(&mut self, register: &str) -> Result<Option<bool>, AigParseError> {
+        let (token, line) = self.next()?;
+        match token.chars().next() {
+            Some(c) if c.is_ascii_digit() || c == '\'' => Self::get_constant(&token, line),
+            _ => Err(Self::error(
+                line,
+                format!("Initial value of {register} should be a constant."),
+            )),
+        }
+    }
+
+    /// `initial q = 1'b0;` or a `begin ... end` block of such assignments.
+    fn read_initial(&mut self) -> Result<(), AigParseError> {
+        let is_block = self.is_next("begin");
+        loop {
+            if is_block && self.is_next("end") {
+                return Ok(());
+            }
+            let (register, line) = self.read_signal()?;
+            if !self.is_next("<=") {
+                self.expect("=")?;
+            }
+            let value = self.read_initial_value(&register)?;
+            self.expect(";")?;
+            self.set_initial_value(&register, value, line)?;
+            if !is_block {
+                return Ok(());
+            }
+        }
+    }
+
+    fn read_module(&mut self) -> Result<(), AigParseError> {
+        self.expect("module")?;
+        self.read_name()?;
+        self.read_port_list()?;
+        loop {
+            let (token, line) = self.next()?;
+            match token.as_str() {
+                "endmodule" => return Ok(()),
+                "input" | "output" | "wire" | "reg" => self.read_declaration(&token, false)?,
+                "assign" => self.read_assign()?,
+                "always" => self.read_always()?,
+                "initial" => self.read_initial()?,
+                "and" | "or" | "xor" | "nand" | "nor" | "xnor" | "not" | "buf" => {
+                    self.read_gate(&token)?
+                }
+                _ if Self::is_identifier(&token) => {
+                    return Err(Self::error(
+                        line,
+                        format!("Unsupported item {token}, module instances are not supported."),
+                    ))
+                }
+                _ => return Err(Self::error(line, format!("Unexpected {token}."))),
+            }
+        }
+    }
+
+    // ********************************************************************************************
+    // building the graph
+    // ********************************************************************************************
+
+    fn get_signals_of_expression<'a>(
+        expression: &'a VerilogExpression,
+        signals: &mut Vec<(&'a str, usize)>,
+    ) {
+        match expression {
+            VerilogExpression::Constant(_) => {}
+            VerilogExpression::Signal(signal, line) => signals.push((signal, line.to_owned())),
+            VerilogExpression::Not(a) => Self::get_signals_of_expression(a, signals),
+            VerilogExpression::And(a, b)
+            | VerilogExpression::Or(a, b)
+            | VerilogExpression::Xor(a, b) => {
+                Self::get_signals_of_expression(a, signals);
+                Self::get_signals_of_expression(b, signals);
+            }
+            VerilogExpression::Mux(c, t, e) => {
+                Self::get_signals_of_expression(c, signals);
+                Self::get_signals_of_expression(t, signals);
+                Self::get_signals_of_expression(e, signals);
+            }
+        }
+    }
+
+    /// the literal of an expression whose signals all have literals already.
+    fn get_literal_of_expression(
+        builder: &mut AigBuilder,
+        literal_of_signal: &HashMap<String, usize>,
+        expression: &VerilogExpression,
+    ) -> usize {
+        let mut get_literal =
+            |e: &VerilogExpression| Self::get_literal_of_expression(builder, literal_of_signal, e);
+        match expression {
+            VerilogExpression::Constant(value) => usize::from(value.to_owned()),
+            VerilogExpression::Signal(signal, _) => literal_of_signal[signal],
+            VerilogExpression::Not(a) => get_literal(a) ^ 1,
+            VerilogExpression::And(a, b) => {
+                let (a, b) = (get_literal(a), get_literal(b));
+                builder.add_and(a, b)
+            }
+            VerilogExpression::Or(a, b) => {
+                let (a, b) = (get_literal(a), get_literal(b));
+                builder.add_or(a, b)
+            }
+            VerilogExpression::Xor(a, b) => {
+                let (a, b) = (get_literal(a), get_literal(b));
+                builder.add_xor(a, b)
+            }
+            VerilogExpression::Mux(c, t, e) => {
+                let (c, t, e) = (get_literal(c), get_literal(t), get_literal(e));
+                builder.add_mux(c, t, e)
+            }
+        }
+    }
+
+    fn get_driver(
+        &self,
+        signal: &str,
+        line: usize,
+    ) -> Result<&(VerilogExpression, usize), AigParseError> {
+        self.drivers.get(signal).ok_or_else(|| {
+            let reason = if self.clocks.contains(signal) {
+                format!("Clock {signal} cannot be used as a signal.")
+            } else {
+                format!("Signal {signal} is never assigned.")
+            };
+            Self::error(line, reason)
+        })
+    }
+
+    /// finds the literal of a signal, creating the gates of every wire it depends on.
+    /// Wires are visited with an explicit stack since netlists can be very deep.
+    fn get_literal_of_signal(
+        &self,
+        builder: &mut AigBuilder,
+        literal_of_signal: &mut HashMap<String, usize>,
+        signal: &str,
+        line: usize,
+    ) -> Result<usize, AigParseError> {
+        // wires whose inputs are being computed, reaching one of them again is a loop.
+        let mut is_in_progress: HashSet<&str> = HashSet::new();
+        let mut signals_to_visit = vec![(signal, line, false)];
+        while let Some((wire, line, are_inputs_ready)) = signals_to_visit.pop() {
+            if literal_of_signal.contains_key(wire) {
+                continue;
+            }
+            let (expression, driver_line) = self.get_driver(wire, line)?;
+            if are_inputs_ready {
+                let literal =
+                    Self::get_literal_of_expression(builder, literal_of_signal, expression);
+                literal_of_signal.insert(wire.to_string(), literal);
+                is_in_progress.remove(wire);
+                continue;
+            }
+            if !is_in_progress.insert(wire) {
+                return Err(Self::error(
+                    driver_line.to_owned(),
+                    format!("Signal {wire} depends on itself."),
+                ));
+            }
+            signals_to_visit.push((wire, line, true));
+            let mut inputs = Vec::new();
+            Self::get_signals_of_expression(expression, &mut inputs);
+            for (input, input_line) in inputs {
+                if !literal_of_signal.contains_key(input) {
+                    signals_to_visit.push((input, input_line, false));
+                }
+            }
+        }
+        Ok(literal_of_signal[signal])
+    }
+
+    /// patterns are names where `*` matches any sequence of characters.
+    fn is_matching_pattern(name: &str, pattern: &str) -> bool {
+        let parts: Vec<&str> = pattern.split('*').collect();
+        if parts.len() == 1 {
+            return name == pattern;
+        }
+        let (first, last) = (parts[0], parts[parts.len() - 1]);
+        if !name.starts_with(first) || name.len() < first.len() + last.len() {
+            return false;
+        }
+        let mut rest = &name[first.len()..(name.len() - last.len())];
+        for part in parts[1..(parts.len() - 1)].iter() {
+            match rest.find(part) {
+                Some(position) => rest = &rest[(position + part.len())..],
+                None => return false,
+            }
+        }
+        name.ends_with(last)
+    }
+
+    fn build(&self, bad_output_patterns: &[&str]) -> Result<AndInverterGraph, AigParseError> {
+        let mut builder = AigBuilder::new();
+        let mut literal_of_signal: HashMap<String, usize> = HashMap::new();
+        for (input, line) in self.inputs.iter() {
+            if self.clocks.contains(input) {
+                continue;
+            }
+            if literal_of_signal.contains_key(input) || self.drivers.contains_key(input) {
+                return Err(Self::error(
+                    line.to_owned(),
+                    format!("Input {input} is declared or assigned more than once."),
+                ));
+            }
+            let literal = builder.add_input();
+            builder.set_input_symbol(literal, input);
+            literal_of_signal.insert(input.to_owned(), literal);
+        }
+        for register in self.registers.iter() {
+            if literal_of_signal.contains_key(register) {
+                return Err(Self::error(
+                    self.next_states[register].1,
+                    format!("Input {register} cannot be assigned."),
+                ));
+            }
+            let reset = self
+                .initial_values
+                .get(register)
+                .and_then(|(value, _)| value.to_owned());
+            let literal = builder.add_latch(0, reset);
+            builder.set_latch_symbol(literal, register);
+            literal_of_signal.insert(register.to_owned(), literal);
+        }
+        for (signal, (_, line)) in self.initial_values.iter() {
+            if !self.next_states.contains_key(signal) {
+                return Err(Self::error(
+                    line.to_owned(),
+                    format!("Signal {signal} has an initial value but is not a register."),
+                ));
+            }
+        }
+
+        for register in self.registers.iter() {
+            let (expression, _) = &self.next_states[register];
+            let mut inputs = Vec::new();
+            Self::get_signals_of_expression(expression, &mut inputs);
+            for (input, line) in inputs {
+                self.get_literal_of_signal(&mut builder, &mut literal_of_signal, input, line)?;
+            }
+            let next =
+                Self::get_literal_of_expression(&mut builder, &literal_of_signal, expression);
+            builder.set_latch_next(literal_of_signal[register], next);
+        }
+        let (mut number_of_outputs, mut number_of_bad) = (0, 0);
+        for (output, line) in self.outputs.iter() {
+            let literal = self.get_literal_of_signal(
+                &mut builder,
+                &mut literal_of_signal,
+                output,
+                line.to_owned(),
+            )?;
+            if bad_output_patterns
+                .iter()
+                .any(|p| Self::is_matching_pattern(output, p))
+            {
+                builder.add_bad(literal);
+                builder.set_bad_symbol(number_of_bad, output);
+                number_of_bad += 1;
+            } else {
+                builder.add_output(literal);
+                builder.set_output_symbol(number_of_outputs, output);
+                number_of_outputs += 1;
+            }
+        }
+        // symbols only name inputs, latches and outputs, so the names of the wires that are
+        // used are kept in the comments, with their literals in the graph.
+        let mut wires: Vec<(&String, usize)> = self
+            .drivers
+            .iter()
+            .filter(|(wire, _)| !self.outputs.iter().any(|(output, _)| output == *wire))
+            .filter(|(wire, _)| literal_of_signal.contains_key(*wire))
+            .map(|(wire, (_, line))| (wire, line.to_owned()))
+            .collect();
+        wires.sort_by_key(|(wire, line)| (line.to_owned(), wire.to_owned()));
+        let mut aig = builder.build();
+        if !wires.is_empty() {
+            let mut comments = vec![String::from("c")];
+            for (wire, _) in wires {
+                let literal = builder.get_literal_in_built_graph(literal_of_signal[wire]);
+                comments.push(format!("wire {wire} {literal}"));
+            }
+            aig.comments = comments.join("\n").into_bytes();
+        }
+        Ok(aig)
+    }
+}
+
+impl AndInverterGraph {
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that takes path to a structural Verilog file and creates a corresponding
+    /// AndInverterGraph object, see `from_verilog_str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.v' file desired.
+    /// * `bad_output_patterns` - names of output ports that are bad states, see
+    ///   `from_verilog_str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let verilog = "module m(a, bad_a); input a; output bad_a; assign bad_a = ~a; endmodule";
+    /// let file_path = std::env::temp_dir().join("verilog_from_path.v");
+    /// std::fs::write(&file_path, verilog).unwrap();
+    /// let aig = AndInverterGraph::from_verilog_path(file_path.to_str().unwrap(), &["bad_*"]);
+    /// assert_eq!(aig.get_bad_information(), vec![3]);
+    /// ```
+    pub fn from_verilog_path(file_path: &str, bad_output_patterns: &[&str]) -> AndInverterGraph {
+        Self::try_from_verilog_path(file_path, bad_output_patterns)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes path to a structural Verilog file and creates a corresponding
+    /// AndInverterGraph object, or reports why the file is not in the supported subset.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.v' file desired.
+    /// * `bad_output_patterns` - names of output ports that are bad states, see
+    ///   `from_verilog_str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let missing = AndInverterGraph::try_from_verilog_path("tests/examples/ours/missing.v", &[]);
+    /// assert!(matches!(missing, Err(AigParseError::UnreadableFile { .. })));
+    /// ```
+    pub fn try_from_verilog_path(
+        file_path: &str,
+        bad_output_patterns: &[&str],
+    ) -> Result<AndInverterGraph, AigParseError> {
+        let file_as_vec_of_bytes = Self::read_file(file_path)?;
+        let verilog = String::from_utf8_lossy(&file_as_vec_of_bytes);
+        Self::try_from_verilog_str(&verilog, bad_output_patterns)
+    }
+
+    /// Function that takes a flat structural Verilog module and creates a corresponding
+    /// AndInverterGraph object.
+    /// Only the first module is read. It may declare inputs, outputs, wires and registers
+    /// (vectors are used one bit at a time, like `a[3]`), and describe the logic with `assign`
+    /// statements using `&`, `|`, `^`, `~`, `!`, `&&`, `||`, `~^` and `? :`, with the gate
+    /// primitives `and`, `or`, `xor`, `nand`, `nor`, `xnor`, `not` and `buf`, and with
+    /// `always @(posedge clk)` blocks of `if` statements and non blocking register assignments.
+    /// All clocks are considered to be the same clock and do not become inputs.
+    /// Registers become latches, and their initial value comes from `reg q = 1'b0;`, from an
+    /// `initial` block, or from a reset: when an always block is also triggered by a signal like
+    /// `rst` in `always @(posedge clk or posedge rst)` and starts with `if (rst)` that assigns
+    /// constants to registers, then these constants are their initial values.
+    /// The reset stays an input, so asserting it later resets the registers on the next cycle.
+    /// Registers without an initial value, or with `1'bx`, are uninitialized.
+    /// Inputs, registers and output ports get their names as symbols. Output ports whose names
+    /// match one of the patterns become bad state literals and the rest become outputs.
+    /// Wires are not outputs, the ones that drive an output port or a register are listed in
+    /// the comments as `wire <name> <literal>`, and the other wires are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `verilog` - the content of the Verilog file.
+    /// * `bad_output_patterns` - names of output ports that are bad states, where `*` matches
+    ///   any sequence of characters. For example `&["bad_*"]` for a naming convention, or
+    ///   `&["overflow", "deadlock"]` for a list of ports.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// // a register that becomes 1 once the input is 1, it is bad when the register is 1.
+    /// let verilog = "
+    ///     module sticky(clk, rst, x, bad_s);
+    ///         input clk, rst, x;
+    ///         output bad_s;
+    ///         reg s;
+    ///         always @(posedge clk or posedge rst)
+    ///             if (rst) s <= 1'b0;
+    ///             else s <= s | x;
+    ///         assign bad_s = s;
+    ///     endmodule
+    /// ";
+    /// let aig = AndInverterGraph::from_verilog_str(verilog, &["bad_*"]);
+    /// assert_eq!(
+    ///     aig.get_aag_string(),
+    ///     "aag 5 2 1 0 2 1\n2\n4\n6 10\n6\n8 7 5\n10 9 3\ni0 rst\ni1 x\nl0 s\nb0 bad_s\n"
+    /// );
+    /// ```
+    pub fn from_verilog_str(verilog: &str, bad_output_patterns: &[&str]) -> AndInverterGraph {
+        Self::try_from_verilog_str(verilog, bad_output_patterns).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes a flat structural Verilog module and creates a corresponding
+    /// AndInverterGraph object, or reports why the module is not in the supported subset.
+    ///
+    /// # Arguments
+    ///
+    /// * `verilog` - the content of the Verilog file.
+    /// * `bad_output_patterns` - names of output ports that are bad states, see
+    ///   `from_verilog_str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::AndInverterGraph;
+    /// let verilog = "module m(o);\noutput o;\nassign o = a;\nendmodule\n";
+    /// assert_eq!(
+    ///     AndInverterGraph::try_from_verilog_str(verilog, &[]).err(),
+    ///     Some(AigParseError::InvalidLine {
+    ///         line: 3,
+    ///         reason: String::from("Signal a is never assigned.")
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_verilog_str(
+        verilog: &str,
+        bad_output_patterns: &[&str],
+    ) -> Result<AndInverterGraph, AigParseError> {
+        let mut reader = VerilogReader {
+            tokens: VerilogReader::get_tokens(verilog)?,
+            ..Default::default()
+        };
+        reader.read_module()?;
+        reader.build(bad_output_patterns)
+    }
+}
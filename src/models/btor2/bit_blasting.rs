@@ -0,0 +1,223 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::AigBuilder;
+use crate::models::btor2::Btor2Model;
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+// words are vectors of literals of the builder, least significant bit first.
+impl Btor2Model {
+    // ********************************************************************************************
+    // logic
+    // ********************************************************************************************
+
+    pub(super) fn get_inverted_word(a: &[usize]) -> Vec<usize> {
+        a.iter().map(|l| l ^ 1).collect()
+    }
+
+    pub(super) fn get_bitwise_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+        operation: fn(&mut AigBuilder, usize, usize) -> usize,
+    ) -> Vec<usize> {
+        a.iter()
+            .zip(b.iter())
+            .map(|(x, y)| operation(builder, x.to_owned(), y.to_owned()))
+            .collect()
+    }
+
+    pub(super) fn get_reduced_bit(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        initial: usize,
+        operation: fn(&mut AigBuilder, usize, usize) -> usize,
+    ) -> usize {
+        a.iter().fold(initial, |result, x| {
+            operation(builder, result, x.to_owned())
+        })
+    }
+
+    pub(super) fn get_mux_word(
+        builder: &mut AigBuilder,
+        condition: usize,
+        then_word: &[usize],
+        else_word: &[usize],
+    ) -> Vec<usize> {
+        then_word
+            .iter()
+            .zip(else_word.iter())
+            .map(|(t, e)| builder.add_mux(condition, t.to_owned(), e.to_owned()))
+            .collect()
+    }
+
+    // ********************************************************************************************
+    // arithmetic
+    // ********************************************************************************************
+
+    /// ripple carry adder, returns the sum and the carry out of the most significant bit.
+    pub(super) fn get_sum_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+        carry_in: usize,
+    ) -> (Vec<usize>, usize) {
+        let mut carry = carry_in;
+        let mut sum = Vec::with_capacity(a.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            let x_xor_y = builder.add_xor(x.to_owned(), y.to_owned());
+            sum.push(builder.add_xor(x_xor_y, carry));
+            let both = builder.add_and(x.to_owned(), y.to_owned());
+            let propagated = builder.add_and(x_xor_y, carry);
+            carry = builder.add_or(both, propagated);
+        }
+        (sum, carry)
+    }
+
+    pub(super) fn get_difference_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+    ) -> Vec<usize> {
+        Self::get_sum_word(builder, a, &Self::get_inverted_word(b), 1).0
+    }
+
+    pub(super) fn get_product_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+    ) -> Vec<usize> {
+        let width = a.len();
+        let mut product = vec![0; width];
+        for (shift, y) in b.iter().enumerate() {
+            let mut partial = vec![0; shift];
+            for x in a[..(width - shift)].iter() {
+                partial.push(builder.add_and(x.to_owned(), y.to_owned()));
+            }
+            product = Self::get_sum_word(builder, &product, &partial, 0).0;
+        }
+        product
+    }
+
+    /// restoring division, dividing by zero gives a quotient of all ones and the dividend as
+    /// the remainder, like in BTOR2.
+    pub(super) fn get_quotient_and_remainder_words(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+    ) -> (Vec<usize>, Vec<usize>) {
+        let width = a.len();
+        // one more bit than the divisor, since the shifted remainder can be twice as large.
+        let mut divisor = b.to_vec();
+        divisor.push(0);
+        let mut remainder = vec![0; width + 1];
+        let mut quotient = vec![0; width];
+        for index in (0..width).rev() {
+            remainder.pop();
+            remainder.insert(0, a[index]);
+            let is_smaller = Self::get_is_unsigned_less_than(builder, &remainder, &divisor);
+            let difference = Self::get_difference_word(builder, &remainder, &divisor);
+            quotient[index] = is_smaller ^ 1;
+            remainder = Self::get_mux_word(builder, is_smaller, &remainder, &difference);
+        }
+        remainder.pop();
+        (quotient, remainder)
+    }
+
+    // ********************************************************************************************
+    // comparison
+    // ********************************************************************************************
+
+    pub(super) fn get_is_equal(builder: &mut AigBuilder, a: &[usize], b: &[usize]) -> usize {
+        let differences = Self::get_bitwise_word(builder, a, b, AigBuilder::add_xor);
+        Self::get_reduced_bit(builder, &differences, 0, AigBuilder::add_or) ^ 1
+    }
+
+    /// a is smaller than b exactly when computing a - b borrows, meaning there is no carry out
+    /// of a + !b + 1.
+    pub(super) fn get_is_unsigned_less_than(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+    ) -> usize {
+        Self::get_sum_word(builder, a, &Self::get_inverted_word(b), 1).1 ^ 1
+    }
+
+    /// inverting the sign bits turns a signed comparison into an unsigned one.
+    pub(super) fn get_is_signed_less_than(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+    ) -> usize {
+        let mut a = a.to_vec();
+        let mut b = b.to_vec();
+        if let (Some(x), Some(y)) = (a.last_mut(), b.last_mut()) {
+            *x ^= 1;
+            *y ^= 1;
+        }
+        Self::get_is_unsigned_less_than(builder, &a, &b)
+    }
+
+    // ********************************************************************************************
+    // shifts
+    // ********************************************************************************************
+
+    /// barrel shifter, the bits of the shift amount that are too high to be a position fill
+    /// the whole word with `fill`.
+    fn get_shifted_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+        is_left: bool,
+        fill: usize,
+    ) -> Vec<usize> {
+        let width = a.len();
+        let mut result = a.to_vec();
+        let mut is_too_far = 0;
+        for (index, amount_bit) in b.iter().enumerate() {
+            if index >= usize::BITS as usize || (1 << index) >= width {
+                is_too_far = builder.add_or(is_too_far, amount_bit.to_owned());
+                continue;
+            }
+            let distance = 1 << index;
+            let shifted: Vec<usize> = (0..width)
+                .map(|position| {
+                    let source = if is_left {
+                        position.checked_sub(distance)
+                    } else {
+                        Some(position + distance).filter(|p| p < &width)
+                    };
+                    source.map_or(fill, |s| result[s])
+                })
+                .collect();
+            result = Self::get_mux_word(builder, amount_bit.to_owned(), &shifted, &result);
+        }
+        let filled = vec![fill; width];
+        Self::get_mux_word(builder, is_too_far, &filled, &result)
+    }
+
+    pub(super) fn get_shifted_left_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+    ) -> Vec<usize> {
+        Self::get_shifted_word(builder, a, b, true, 0)
+    }
+
+    pub(super) fn get_shifted_right_word(
+        builder: &mut AigBuilder,
+        a: &[usize],
+        b: &[usize],
+        is_arithmetic: bool,
+    ) -> Vec<usize> {
+        let fill = match a.last() {
+            Some(sign) if is_arithmetic => sign.to_owned(),
+            _ => 0,
+        };
+        Self::get_shifted_word(builder, a, b, false, fill)
+    }
+}
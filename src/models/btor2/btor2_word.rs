@@ -0,0 +1,81 @@
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that describes an input or a state of a BTOR2 model, and where its bits are in the
+/// bit blasted AndInverterGraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Btor2Word {
+    // the id of the line that declared the word.
+    id: usize,
+    symbol: String,
+    // positions of the inputs or latches of the AndInverterGraph, least significant bit first.
+    positions: Vec<usize>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl Btor2Word {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    pub(super) fn new(id: usize, symbol: &str, positions: Vec<usize>) -> Self {
+        Self {
+            id,
+            symbol: symbol.to_string(),
+            positions,
+        }
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    pub fn get_id(&self) -> usize {
+        self.id
+    }
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn get_positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    /// Function that gives the value of the word as a binary string, most significant bit
+    /// first, like in BTOR2 witnesses. Unknown bits are written as 'x'.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the word desired.
+    /// * `values` - the values of all the inputs of the AndInverterGraph if this word is an
+    ///   input, or of all the latches if this word is a state.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let model = Btor2Model::from_btor2_str("1 sort bitvec 3\n2 input 1 x\n");
+    /// let word = &model.get_input_words()[0];
+    /// assert_eq!(word.get_positions(), &[0, 1, 2]);
+    /// assert_eq!(word.get_value_string(&[Some(true), Some(false), None]), "x01");
+    /// ```
+    pub fn get_value_string(&self, values: &[Option<bool>]) -> String {
+        self.positions
+            .iter()
+            .rev()
+            .map(|position| match values[position.to_owned()] {
+                Some(true) => '1',
+                Some(false) => '0',
+                None => 'x',
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,679 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::and_inverter_graph::{AigBuilder, AigParseError};
+use crate::models::btor2::{Btor2Model, Btor2Word};
+use std::collections::HashMap;
+use std::fs;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// everything that is known while the lines of a '.btor2' file are read one by one.
+#[derive(Default)]
+struct Btor2Reader {
+    builder: AigBuilder,
+    width_of_sort: HashMap<usize, usize>,
+    // the literals of every node, least significant bit first.
+    word_of_node: HashMap<usize, Vec<usize>>,
+    // maps the id of a state to its position in the states vector.
+    position_of_state: HashMap<usize, usize>,
+    inputs: Vec<Btor2Word>,
+    states: Vec<Btor2Word>,
+    number_of_inputs: usize,
+    number_of_latches: usize,
+    number_of_outputs: usize,
+    has_init: Vec<bool>,
+    has_next: Vec<bool>,
+    // states with initial values that are not constants, and the words of these values.
+    non_constant_initial_values: Vec<(Vec<usize>, Vec<usize>)>,
+    bad: Vec<(usize, String)>,
+}
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl Btor2Reader {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn error(line: usize, reason: String) -> AigParseError {
+        AigParseError::InvalidLine { line, reason }
+    }
+
+    /// a line without an operator is reported as a whole.
+    fn get_token<'a>(
+        tokens: &[&'a str],
+        index: usize,
+        line: usize,
+    ) -> Result<&'a str, AigParseError> {
+        tokens.get(index).copied().ok_or_else(|| {
+            let operator = tokens.get(1).copied().unwrap_or("Line");
+            Self::error(line, format!("{operator} is missing arguments."))
+        })
+    }
+
+    fn get_number(tokens: &[&str], index: usize, line: usize) -> Result<usize, AigParseError> {
+        let token = Self::get_token(tokens, index, line)?;
+        token
+            .parse::<usize>()
+            .map_err(|_| Self::error(line, format!("Expected a number, found {token}.")))
+    }
+
+    fn get_sort_width(
+        &self,
+        tokens: &[&str],
+        index: usize,
+        line: usize,
+    ) -> Result<usize, AigParseError> {
+        let sort = Self::get_number(tokens, index, line)?;
+        self.width_of_sort
+            .get(&sort)
+            .copied()
+            .ok_or_else(|| Self::error(line, format!("Sort {sort} is not defined.")))
+    }
+
+    /// negative ids refer to the bitwise negation of the node.
+    fn get_word(
+        &self,
+        tokens: &[&str],
+        index: usize,
+        line: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        let token = Self::get_token(tokens, index, line)?;
+        let (id, is_negated) = match token.strip_prefix('-') {
+            Some(id) => (id, true),
+            None => (token, false),
+        };
+        let id: usize = id
+            .parse()
+            .map_err(|_| Self::error(line, format!("Expected a node, found {token}.")))?;
+        let word = self
+            .word_of_node
+            .get(&id)
+            .ok_or_else(|| Self::error(line, format!("Node {id} is not defined.")))?;
+        if is_negated {
+            Ok(Btor2Model::get_inverted_word(word))
+        } else {
+            Ok(word.to_owned())
+        }
+    }
+
+    fn get_bit(&self, tokens: &[&str], index: usize, line: usize) -> Result<usize, AigParseError> {
+        let word = self.get_word(tokens, index, line)?;
+        if word.len() == 1 {
+            Ok(word[0])
+        } else {
+            Err(Self::error(
+                line,
+                format!("Node {} should have width 1.", tokens[index]),
+            ))
+        }
+    }
+
+    fn get_two_words(
+        &self,
+        tokens: &[&str],
+        line: usize,
+    ) -> Result<(Vec<usize>, Vec<usize>), AigParseError> {
+        let a = self.get_word(tokens, 3, line)?;
+        let b = self.get_word(tokens, 4, line)?;
+        if a.len() == b.len() {
+            Ok((a, b))
+        } else {
+            Err(Self::error(
+                line,
+                String::from("Arguments should have the same width."),
+            ))
+        }
+    }
+
+    fn get_state_position(&self, tokens: &[&str], line: usize) -> Result<usize, AigParseError> {
+        let id = Self::get_number(tokens, 3, line)?;
+        self.position_of_state
+            .get(&id)
+            .copied()
+            .ok_or_else(|| Self::error(line, format!("Node {id} is not a state.")))
+    }
+
+    fn get_bit_symbol(symbol: &str, width: usize, index: usize) -> String {
+        if width == 1 {
+            symbol.to_string()
+        } else {
+            format!("{symbol}[{index}]")
+        }
+    }
+
+    fn get_constant_word(bits: &[bool]) -> Vec<usize> {
+        bits.iter().map(|b| usize::from(b.to_owned())).collect()
+    }
+
+    // ********************************************************************************************
+    // reading lines
+    // ********************************************************************************************
+
+    fn read_input(&mut self, id: usize, width: usize, symbol: &str) -> Vec<usize> {
+        let positions: Vec<usize> =
+            (self.number_of_inputs..(self.number_of_inputs + width)).collect();
+        self.number_of_inputs += width;
+        let mut word = Vec::with_capacity(width);
+        for index in 0..width {
+            let input = self.builder.add_input();
+            if !symbol.is_empty() {
+                self.builder
+                    .set_input_symbol(input, &Self::get_bit_symbol(symbol, width, index));
+            }
+            word.push(input);
+        }
+        self.inputs.push(Btor2Word::new(id, symbol, positions));
+        word
+    }
+
+    fn read_state(&mut self, id: usize, width: usize, symbol: &str) -> Vec<usize> {
+        let positions: Vec<usize> =
+            (self.number_of_latches..(self.number_of_latches + width)).collect();
+        self.number_of_latches += width;
+        let mut word = Vec::with_capacity(width);
+        for index in 0..width {
+            let latch = self.builder.add_latch(0, None);
+            if !symbol.is_empty() {
+                self.builder
+                    .set_latch_symbol(latch, &Self::get_bit_symbol(symbol, width, index));
+            }
+            word.push(latch);
+        }
+        self.position_of_state.insert(id, self.states.len());
+        self.states.push(Btor2Word::new(id, symbol, positions));
+        self.has_init.push(false);
+        self.has_next.push(false);
+        word
+    }
+
+    fn read_init_or_next(&mut self, tokens: &[&str], line: usize) -> Result<(), AigParseError> {
+        let position = self.get_state_position(tokens, line)?;
+        let state = self.word_of_node[&self.states[position].get_id()].to_owned();
+        let value = self.get_word(tokens, 4, line)?;
+        if value.len() != state.len() {
+            return Err(Self::error(
+                line,
+                String::from("Value should have the width of the state."),
+            ));
+        }
+        let is_init = tokens[1] == "init";
+        let was_set = if is_init {
+            &mut self.has_init[position]
+        } else {
+            &mut self.has_next[position]
+        };
+        if *was_set {
+            return Err(Self::error(
+                line,
+                format!("State {} already has {}.", tokens[3], tokens[1]),
+            ));
+        }
+        *was_set = true;
+
+        if !is_init {
+            for (latch, next) in state.iter().zip(value.iter()) {
+                self.builder
+                    .set_latch_next(latch.to_owned(), next.to_owned());
+            }
+        } else if value.iter().all(|l| l < &2) {
+            for (latch, reset) in state.iter().zip(value.iter()) {
+                self.builder
+                    .set_latch_reset(latch.to_owned(), Some(reset == &1));
+            }
+        } else {
+            self.non_constant_initial_values.push((state, value));
+        }
+        Ok(())
+    }
+
+    fn read_constant(
+        &self,
+        tokens: &[&str],
+        width: usize,
+        line: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        let bits = match tokens[1] {
+            "zero" => Some(vec![false; width]),
+            "one" => Some((0..width).map(|i| i == 0).collect()),
+            "ones" => Some(vec![true; width]),
+            "const" => Btor2Model::get_bits_of_binary(Self::get_token(tokens, 3, line)?, width),
+            "constd" => Btor2Model::get_bits_of_decimal(Self::get_token(tokens, 3, line)?, width),
+            _ => Btor2Model::get_bits_of_hexadecimal(Self::get_token(tokens, 3, line)?, width),
+        };
+        bits.map(|b| Self::get_constant_word(&b))
+            .ok_or_else(|| Self::error(line, format!("Invalid constant {}.", tokens[3])))
+    }
+
+    fn read_operation(
+        &mut self,
+        tokens: &[&str],
+        width: usize,
+        line: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        let word = match tokens[1] {
+            "not" => Btor2Model::get_inverted_word(&self.get_word(tokens, 3, line)?),
+            "inc" | "dec" | "neg" => {
+                let a = self.get_word(tokens, 3, line)?;
+                match tokens[1] {
+                    "inc" => {
+                        Btor2Model::get_sum_word(&mut self.builder, &a, &vec![0; a.len()], 1).0
+                    }
+                    "dec" => {
+                        Btor2Model::get_sum_word(&mut self.builder, &a, &vec![1; a.len()], 0).0
+                    }
+                    _ => {
+                        let inverted = Btor2Model::get_inverted_word(&a);
+                        Btor2Model::get_sum_word(&mut self.builder, &inverted, &vec![0; a.len()], 1)
+                            .0
+                    }
+                }
+            }
+            "redand" | "redor" | "redxor" => {
+                let a = self.get_word(tokens, 3, line)?;
+                let bit = match tokens[1] {
+                    "redand" => {
+                        Btor2Model::get_reduced_bit(&mut self.builder, &a, 1, AigBuilder::add_and)
+                    }
+                    "redor" => {
+                        Btor2Model::get_reduced_bit(&mut self.builder, &a, 0, AigBuilder::add_or)
+                    }
+                    _ => Btor2Model::get_reduced_bit(&mut self.builder, &a, 0, AigBuilder::add_xor),
+                };
+                vec![bit]
+            }
+            "uext" | "sext" => {
+                let mut a = self.get_word(tokens, 3, line)?;
+                let extension = Self::get_number(tokens, 4, line)?;
+                let fill = match a.last() {
+                    Some(sign) if tokens[1] == "sext" => sign.to_owned(),
+                    _ => 0,
+                };
+                a.resize(a.len() + extension, fill);
+                a
+            }
+            "slice" => {
+                let a = self.get_word(tokens, 3, line)?;
+                let upper = Self::get_number(tokens, 4, line)?;
+                let lower = Self::get_number(tokens, 5, line)?;
+                if lower > upper || upper >= a.len() {
+                    return Err(Self::error(line, String::from("Slice is out of range.")));
+                }
+                a[lower..=upper].to_vec()
+            }
+            "concat" => {
+                let a = self.get_word(tokens, 3, line)?;
+                let mut b = self.get_word(tokens, 4, line)?;
+                // the first argument is the most significant part.
+                b.extend(a);
+                b
+            }
+            "ite" => {
+                let condition = self.get_bit(tokens, 3, line)?;
+                let a = self.get_word(tokens, 4, line)?;
+                let b = self.get_word(tokens, 5, line)?;
+                if a.len() != b.len() {
+                    return Err(Self::error(
+                        line,
+                        String::from("Arguments should have the same width."),
+                    ));
+                }
+                Btor2Model::get_mux_word(&mut self.builder, condition, &a, &b)
+            }
+            _ => {
+                let (a, b) = self.get_two_words(tokens, line)?;
+                self.read_binary_operation(tokens[1], &a, &b, line)?
+            }
+        };
+        if word.len() == width {
+            Ok(word)
+        } else {
+            Err(Self::error(
+                line,
+                format!(
+                    "Result has width {} but its sort has width {width}.",
+                    word.len()
+                ),
+            ))
+        }
+    }
+
+    fn read_binary_operation(
+        &mut self,
+        operator: &str,
+        a: &[usize],
+        b: &[usize],
+        line: usize,
+    ) -> Result<Vec<usize>, AigParseError> {
+        let builder = &mut self.builder;
+        let bitwise = |builder: &mut AigBuilder, operation| {
+            Btor2Model::get_bitwise_word(builder, a, b, operation)
+        };
+        let word = match operator {
+            "and" => bitwise(builder, AigBuilder::add_and),
+            "or" => bitwise(builder, AigBuilder::add_or),
+            "xor" => bitwise(builder, AigBuilder::add_xor),
+            "nand" => Btor2Model::get_inverted_word(&bitwise(builder, AigBuilder::add_and)),
+            "nor" => Btor2Model::get_inverted_word(&bitwise(builder, AigBuilder::add_or)),
+            "xnor" | "iff" => Btor2Model::get_inverted_word(&bitwise(builder, AigBuilder::add_xor)),
+            "implies" => {
+                let not_a = Btor2Model::get_inverted_word(a);
+                Btor2Model::get_bitwise_word(builder, &not_a, b, AigBuilder::add_or)
+            }
+            "add" => Btor2Model::get_sum_word(builder, a, b, 0).0,
+            "sub" => Btor2Model::get_difference_word(builder, a, b),
+            "mul" => Btor2Model::get_product_word(builder, a, b),
+            "udiv" => Btor2Model::get_quotient_and_remainder_words(builder, a, b).0,
+            "urem" => Btor2Model::get_quotient_and_remainder_words(builder, a, b).1,
+            "sll" => Btor2Model::get_shifted_left_word(builder, a, b),
+            "srl" => Btor2Model::get_shifted_right_word(builder, a, b, false),
+            "sra" => Btor2Model::get_shifted_right_word(builder, a, b, true),
+            "eq" => vec![Btor2Model::get_is_equal(builder, a, b)],
+            "neq" => vec![Btor2Model::get_is_equal(builder, a, b) ^ 1],
+            "ult" => vec![Btor2Model::get_is_unsigned_less_than(builder, a, b)],
+            "ulte" => vec![Btor2Model::get_is_unsigned_less_than(builder, b, a) ^ 1],
+            "ugt" => vec![Btor2Model::get_is_unsigned_less_than(builder, b, a)],
+            "ugte" => vec![Btor2Model::get_is_unsigned_less_than(builder, a, b) ^ 1],
+            "slt" => vec![Btor2Model::get_is_signed_less_than(builder, a, b)],
+            "slte" => vec![Btor2Model::get_is_signed_less_than(builder, b, a) ^ 1],
+            "sgt" => vec![Btor2Model::get_is_signed_less_than(builder, b, a)],
+            "sgte" => vec![Btor2Model::get_is_signed_less_than(builder, a, b) ^ 1],
+            _ => {
+                return Err(Self::error(
+                    line,
+                    format!("Unsupported operator {operator}."),
+                ))
+            }
+        };
+        Ok(word)
+    }
+
+    fn read_line(&mut self, tokens: &[&str], line: usize) -> Result<(), AigParseError> {
+        let id = Self::get_number(tokens, 0, line)?;
+        let operator = Self::get_token(tokens, 1, line)?;
+        let symbol = |index: usize| tokens.get(index).copied().unwrap_or("");
+        let word = match operator {
+            "sort" => {
+                match (
+                    Self::get_token(tokens, 2, line)?,
+                    Self::get_number(tokens, 3, line)?,
+                ) {
+                    ("bitvec", width) if width > 0 => self.width_of_sort.insert(id, width),
+                    ("bitvec", _) => {
+                        return Err(Self::error(line, String::from("Width should be positive.")))
+                    }
+                    (sort, _) => {
+                        return Err(Self::error(line, format!("Unsupported sort {sort}.")))
+                    }
+                };
+                return Ok(());
+            }
+            "input" => {
+                let width = self.get_sort_width(tokens, 2, line)?;
+                self.read_input(id, width, symbol(3))
+            }
+            "state" => {
+                let width = self.get_sort_width(tokens, 2, line)?;
+                self.read_state(id, width, symbol(3))
+            }
+            "init" | "next" => return self.read_init_or_next(tokens, line),
+            "bad" => {
+                let bit = self.get_bit(tokens, 2, line)?;
+                self.bad.push((bit, symbol(3).to_string()));
+                return Ok(());
+            }
+            "constraint" => {
+                let bit = self.get_bit(tokens, 2, line)?;
+                self.builder.add_constraint(bit);
+                return Ok(());
+            }
+            "output" => {
+                let word = self.get_word(tokens, 2, line)?;
+                for (index, bit) in word.iter().enumerate() {
+                    self.builder.add_output(bit.to_owned());
+                    if !symbol(3).is_empty() {
+                        let position = self.number_of_outputs;
+                        let bit_symbol = Self::get_bit_symbol(symbol(3), word.len(), index);
+                        self.builder.set_output_symbol(position, &bit_symbol);
+                    }
+                    self.number_of_outputs += 1;
+                }
+                return Ok(());
+            }
+            "fair" | "justice" => {
+                return Err(Self::error(
+                    line,
+                    format!("Unsupported operator {operator}."),
+                ))
+            }
+            "zero" | "one" | "ones" | "const" | "constd" | "consth" => {
+                let width = self.get_sort_width(tokens, 2, line)?;
+                self.read_constant(tokens, width, line)?
+            }
+            _ => {
+                let width = self.get_sort_width(tokens, 2, line)?;
+                self.read_operation(tokens, width, line)?
+            }
+        };
+        if self.word_of_node.insert(id, word).is_some() {
+            return Err(Self::error(
+                line,
+                format!("Node {id} is defined more than once."),
+            ));
+        }
+        Ok(())
+    }
+
+    /// states without a next state can change freely, so their next state is a new input.
+    /// States with initial values that are not constants start uninitialized, and the bad
+    /// state literals only count if these states had their initial values in the first cycle.
+    fn finish(mut self) -> Btor2Model {
+        for (position, state) in self.states.iter().enumerate() {
+            if !self.has_next[position] {
+                for latch in self.word_of_node[&state.get_id()].iter() {
+                    let input = self.builder.add_input();
+                    self.builder.set_latch_next(latch.to_owned(), input);
+                }
+            }
+        }
+
+        let mut is_initialized = 1;
+        if !self.non_constant_initial_values.is_empty() {
+            let mut are_values_initial = 1;
+            for (state, value) in self.non_constant_initial_values.iter() {
+                let is_equal = Btor2Model::get_is_equal(&mut self.builder, state, value);
+                are_values_initial = self.builder.add_and(are_values_initial, is_equal);
+            }
+            let is_first_cycle = self.builder.add_latch(0, Some(true));
+            let was_initialized = self.builder.add_latch(0, Some(false));
+            is_initialized =
+                self.builder
+                    .add_mux(is_first_cycle, are_values_initial, was_initialized);
+            self.builder.set_latch_next(was_initialized, is_initialized);
+        }
+        for (index, (bit, symbol)) in self.bad.iter().enumerate() {
+            let bad = self.builder.add_and(bit.to_owned(), is_initialized);
+            self.builder.add_bad(bad);
+            if !symbol.is_empty() {
+                self.builder.set_bad_symbol(index, symbol);
+            }
+        }
+
+        Btor2Model {
+            aig: self.builder.build(),
+            inputs: self.inputs,
+            states: self.states,
+        }
+    }
+}
+
+impl Btor2Model {
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_bits_of_binary(binary: &str, width: usize) -> Option<Vec<bool>> {
+        if binary.len() != width || binary.chars().any(|c| c != '0' && c != '1') {
+            return None;
+        }
+        Some(binary.chars().rev().map(|c| c == '1').collect())
+    }
+
+    /// negative numbers are in two's complement, and numbers that are too large are truncated.
+    fn get_bits_of_decimal(decimal: &str, width: usize) -> Option<Vec<bool>> {
+        let (digits, is_negative) = match decimal.strip_prefix('-') {
+            Some(digits) => (digits, true),
+            None => (decimal, false),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let mut bits = vec![false; width];
+        for digit in digits.chars() {
+            // multiply by 10 and add the digit, one bit at a time.
+            let mut carry = digit.to_digit(10)?;
+            for bit in bits.iter_mut() {
+                let value = u32::from(*bit) * 10 + carry;
+                *bit = (value & 1) == 1;
+                carry = value >> 1;
+            }
+        }
+        if is_negative {
+            let mut carry = true;
+            for bit in bits.iter_mut() {
+                let inverted = !*bit;
+                *bit = inverted ^ carry;
+                carry = inverted && carry;
+            }
+        }
+        Some(bits)
+    }
+
+    fn get_bits_of_hexadecimal(hexadecimal: &str, width: usize) -> Option<Vec<bool>> {
+        if hexadecimal.is_empty() {
+            return None;
+        }
+        let mut bits = vec![false; width];
+        for (index, c) in hexadecimal.chars().rev().enumerate() {
+            let digit = c.to_digit(16)?;
+            for offset in 0..4 {
+                if let Some(bit) = bits.get_mut(4 * index + offset) {
+                    *bit = ((digit >> offset) & 1) == 1;
+                }
+            }
+        }
+        Some(bits)
+    }
+
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that takes path to '.btor2' file and creates a corresponding Btor2Model
+    /// object, see `try_from_btor2_str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.btor2' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let file_path = std::env::temp_dir().join("toggle.btor2");
+    /// std::fs::write(&file_path, "1 sort bitvec 1\n2 state 1 t\n3 next 1 2 -2\n4 bad 2\n").unwrap();
+    /// let model = Btor2Model::from_btor2_path(file_path.to_str().unwrap());
+    /// assert_eq!(model.get_aig().get_latch_information(), vec![(2, 3, 2)]);
+    /// ```
+    pub fn from_btor2_path(file_path: &str) -> Btor2Model {
+        Self::try_from_btor2_path(file_path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes path to '.btor2' file and creates a corresponding Btor2Model
+    /// object, or reports why the file could not be read, see `try_from_btor2_str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path to the '.btor2' file desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let missing = Btor2Model::try_from_btor2_path("tests/examples/ours/missing.btor2");
+    /// assert!(matches!(missing, Err(AigParseError::UnreadableFile { .. })));
+    /// ```
+    pub fn try_from_btor2_path(file_path: &str) -> Result<Btor2Model, AigParseError> {
+        let btor2 = fs::read_to_string(file_path).map_err(|e| AigParseError::UnreadableFile {
+            path: file_path.to_string(),
+            reason: e.to_string(),
+        })?;
+        Self::try_from_btor2_str(&btor2)
+    }
+
+    /// Function that takes the content of a '.btor2' file and creates a corresponding
+    /// Btor2Model object, see `try_from_btor2_str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `btor2` - the content of the '.btor2' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::Btor2Model;
+    /// // a 2 bit counter that starts at 0 and is bad when it reaches 3.
+    /// let btor2 = "1 sort bitvec 2\n2 zero 1\n3 state 1 count\n4 init 1 3 2\n5 one 1\n6 add 1 3 5\n7 next 1 3 6\n8 ones 1\n9 sort bitvec 1\n10 eq 9 3 8\n11 bad 10\n";
+    /// let model = Btor2Model::from_btor2_str(btor2);
+    /// let aig = model.get_aig();
+    /// assert_eq!(aig.get_latch_information(), vec![(2, 3, 0), (4, 11, 0)]);
+    /// assert_eq!(aig.get_bad_information(), vec![12]);
+    /// assert_eq!(model.get_state_words()[0].get_symbol(), "count");
+    /// ```
+    pub fn from_btor2_str(btor2: &str) -> Btor2Model {
+        Self::try_from_btor2_str(btor2).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Function that takes the content of a '.btor2' file and bit blasts it into an
+    /// AndInverterGraph, or reports why the content is not a valid '.btor2' file.
+    /// Only bit vector sorts are supported, and every bit of an input or a state becomes an
+    /// input or a latch with the symbol `name[i]`, where bit 0 is the least significant one.
+    /// Bad properties, constraints and outputs keep their order, and every bit of an output
+    /// becomes an output.
+    /// States without a next state get a new input (with no symbol) as their next state, and
+    /// states with initial values that are not constants are handled by making the bad state
+    /// literals false in traces that do not start at these initial values.
+    ///
+    /// # Arguments
+    ///
+    /// * `btor2` - the content of the '.btor2' file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::and_inverter_graph::AigParseError;
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let model = Btor2Model::try_from_btor2_str("; comment\n1 sort bitvec 4\n2 input 1 x\n3 udiv 1 2 4\n");
+    /// assert_eq!(
+    ///     model.err(),
+    ///     Some(AigParseError::InvalidLine {
+    ///         line: 4,
+    ///         reason: String::from("Node 4 is not defined.")
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_btor2_str(btor2: &str) -> Result<Btor2Model, AigParseError> {
+        let mut reader = Btor2Reader::default();
+        for (index, line) in btor2.lines().enumerate() {
+            let line_without_comment = line.split(';').next().unwrap_or("");
+            let tokens: Vec<&str> = line_without_comment.split_whitespace().collect();
+            if !tokens.is_empty() {
+                reader.read_line(&tokens, index + 1)?;
+            }
+        }
+        Ok(reader.finish())
+    }
+}
@@ -0,0 +1,71 @@
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::btor2::{Btor2Model, Btor2Word};
+use crate::models::AndInverterGraph;
+
+// ************************************************************************************************
+// impl
+// ************************************************************************************************
+
+impl Btor2Model {
+    // ********************************************************************************************
+    // api functions
+    // ********************************************************************************************
+
+    /// Function that gives the bit blasted AndInverterGraph.
+    /// Bad state literals and invariant constraints are in the order they appear in the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the Btor2Model desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let model = Btor2Model::from_btor2_str("1 sort bitvec 1\n2 input 1\n3 bad 2\n");
+    /// assert_eq!(model.get_aig().get_bad_information(), vec![2]);
+    /// ```
+    pub fn get_aig(&self) -> &AndInverterGraph {
+        &self.aig
+    }
+
+    /// Function that gives the words of the inputs, in the order they appear in the file.
+    /// Their positions are positions in the inputs of the AndInverterGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the Btor2Model desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let model = Btor2Model::from_btor2_str("1 sort bitvec 2\n2 input 1 a\n3 input 1 b\n");
+    /// let words = model.get_input_words();
+    /// assert_eq!(words[1].get_symbol(), "b");
+    /// assert_eq!(words[1].get_positions(), &[2, 3]);
+    /// ```
+    pub fn get_input_words(&self) -> &[Btor2Word] {
+        &self.inputs
+    }
+
+    /// Function that gives the words of the states, in the order they appear in the file.
+    /// Their positions are positions in the latches of the AndInverterGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - the Btor2Model desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::Btor2Model;
+    /// let model = Btor2Model::from_btor2_str("1 sort bitvec 2\n2 state 1 s\n");
+    /// let words = model.get_state_words();
+    /// assert_eq!(words[0].get_id(), 2);
+    /// assert_eq!(words[0].get_width(), 2);
+    /// ```
+    pub fn get_state_words(&self) -> &[Btor2Word] {
+        &self.states
+    }
+}
@@ -0,0 +1,38 @@
+//! object for reading word level '.btor2' files by bit blasting them into an AndInverterGraph,
+//! while remembering which bits of the AndInverterGraph belong to which word.
+
+// ************************************************************************************************
+// use
+// ************************************************************************************************
+
+use crate::models::AndInverterGraph;
+
+// ************************************************************************************************
+// struct
+// ************************************************************************************************
+
+/// Struct that describes a bit blasted BTOR2 model.
+///
+/// implementations of many additional features can be found in sub-modules.
+pub struct Btor2Model {
+    aig: AndInverterGraph,
+    // the words of the inputs and states, in the order they appear in the file.
+    inputs: Vec<Btor2Word>,
+    states: Vec<Btor2Word>,
+}
+
+// ************************************************************************************************
+// rust submodule declaration, they get searched in their respective file  names
+// ************************************************************************************************
+
+pub mod btor2_word;
+pub mod construction;
+pub mod getting;
+
+mod bit_blasting;
+
+// ************************************************************************************************
+// re-exports of structs in these modules to simplify paths for other imports
+// ************************************************************************************************
+
+pub use btor2_word::Btor2Word;
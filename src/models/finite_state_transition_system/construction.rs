@@ -90,6 +90,27 @@ impl FiniteStateTransitionSystem {
         Cube::new(&cube_literals)
     }
 
+    fn create_invariant_constraints_cnf(aig: &AndInverterGraph) -> CNF {
+        let constraint_literals = aig.get_constraints_information();
+        let mut cnf =
+            Self::get_cnf_that_describes_wire_values_as_a_function_of_latch_values_for_specific_wires(
+                aig,
+                &constraint_literals,
+            );
+
+        // every constraint must hold, a constraint that is constant 0 blocks every state.
+        for constraint_literal in constraint_literals {
+            if constraint_literal == 0 {
+                cnf.add_clause(&Clause::new(&[]));
+            } else if constraint_literal != 1 {
+                let c_lit = Self::get_literal_from_aig_literal(constraint_literal);
+                cnf.add_clause(&Clause::new(&[c_lit]));
+            }
+        }
+
+        cnf
+    }
+
     fn create_transition_cnf(aig: &AndInverterGraph, max_variable_number: VariableType) -> CNF {
         // propagate new latch values
         let mut latches_to_wires = CNF::new();
@@ -125,42 +146,38 @@ impl FiniteStateTransitionSystem {
                 &wires_we_care_about,
             )
         );
+
+        // the constraints hold both before and after the transition.
+        let invariant_constraints = Self::create_invariant_constraints_cnf(aig);
+        latches_to_wires.append(&invariant_constraints);
+        latches_to_wires.append(&Self::bump_all_cnf_variables_by_some_number(
+            &invariant_constraints,
+            max_variable_number,
+        ));
         latches_to_wires
     }
 
     fn create_state_to_safety_translation(
         aig: &AndInverterGraph,
-        assume_output_is_bad: bool,
+        property_literals: &[usize],
     ) -> CNF {
-        let mut important_wires = Vec::new();
-        important_wires.append(&mut aig.get_bad_information());
-
-        // this should be empty because constrained problem are not supported as of now.
-        important_wires.append(&mut aig.get_constraints_information());
-
-        // this is here because sometimes we consider output to be bad
-        if assume_output_is_bad {
-            important_wires.append(&mut aig.get_output_information());
-        }
+        let mut cnf =
+            Self::get_cnf_that_describes_wire_values_as_a_function_of_latch_values_for_specific_wires(
+                aig,
+                property_literals,
+            );
 
-        Self::get_cnf_that_describes_wire_values_as_a_function_of_latch_values_for_specific_wires(
-            aig,
-            &important_wires,
-        )
+        // a state is only considered if it satisfies the constraints.
+        cnf.append(&Self::create_invariant_constraints_cnf(aig));
+        cnf
     }
 
-    fn create_unsafety_property(aig: &AndInverterGraph, assume_output_is_bad: bool) -> Clause {
-        // take all bad into consideration
-        let mut bad_info = aig.get_bad_information();
-        if assume_output_is_bad {
-            bad_info.append(&mut aig.get_output_information());
-        }
-
+    fn create_unsafety_property(property_literals: &[usize]) -> Clause {
         // split to 2 cases, depending on if empty or not.
-        if !bad_info.is_empty() {
+        if !property_literals.is_empty() {
             let mut unsafe_literals = Vec::new();
-            for bad_literal in bad_info {
-                let b_lit = Self::get_literal_from_aig_literal(bad_literal);
+            for bad_literal in property_literals {
+                let b_lit = Self::get_literal_from_aig_literal(bad_literal.to_owned());
                 unsafe_literals.push(b_lit);
             }
             Clause::new(&unsafe_literals)
@@ -195,35 +212,31 @@ impl FiniteStateTransitionSystem {
         (input_literals, state_literals)
     }
 
-    // ********************************************************************************************
-    // aig api functions
-    // ********************************************************************************************
-
-    /// Function that converts an AndInverterGraph into a FiniteStateTransitionSystem.
-    ///
-    /// # Arguments
-    ///
-    /// * `aig: &AndInverterGraph` - the AndInverterGraph desired.
-    ///
-    /// # Examples
-    /// ```
-    /// use rust_formal_verification::models::{AndInverterGraph, FiniteStateTransitionSystem};
-    /// let file_path = "tests/examples/ours/counter.aig";
-    /// let aig = AndInverterGraph::from_aig_path(file_path);
-    /// let fsts = FiniteStateTransitionSystem::from_aig(&aig, false);
-    /// assert_eq!(fsts.get_initial_relation().to_string(), "p cnf 3 3\n-1 0\n-2 0\n-3 0");
-    /// ```
-    pub fn from_aig(aig: &AndInverterGraph, assume_output_is_bad: bool) -> Self {
+    /// converts the selected safety properties, ignoring justice properties and fairness
+    /// constraints.
+    fn from_aig_for_selected_properties(
+        aig: &AndInverterGraph,
+        assume_output_is_bad: bool,
+        property_indices: &[usize],
+    ) -> Self {
         // perform some checks first
         let max_variable_number_as_usize = aig.get_highest_variable_number();
         assert!(
             max_variable_number_as_usize < (u32::MAX >> 1).try_into().unwrap(),
             "AIG has variables with numbers that are too high (too many variables)."
         );
-        assert!(
-            aig.get_constraints_information().is_empty(),
-            "Making 'FiniteStateTransitionSystem' from aig with constraints is not supported.\nTry folding the AIG with another tool First"
-        );
+
+        let all_property_literals = Self::get_property_literals_of_aig(aig, assume_output_is_bad);
+        let property_literals: Vec<usize> = property_indices
+            .iter()
+            .map(|i| {
+                assert!(
+                    i < &all_property_literals.len(),
+                    "There is no property {i}."
+                );
+                all_property_literals[i.to_owned()]
+            })
+            .collect();
 
         // make formulas
         let max_literal_number: VariableType = max_variable_number_as_usize.try_into().unwrap();
@@ -231,11 +244,13 @@ impl FiniteStateTransitionSystem {
         let initial_states: Cube = Self::create_initial_cnf(aig);
         let transition: CNF = Self::create_transition_cnf(aig, max_literal_number);
         let state_to_safety_translation: CNF =
-            Self::create_state_to_safety_translation(aig, assume_output_is_bad);
-        let unsafety_property: Clause = Self::create_unsafety_property(aig, assume_output_is_bad);
+            Self::create_state_to_safety_translation(aig, &property_literals);
+        let unsafety_property: Clause = Self::create_unsafety_property(&property_literals);
         let initial_literals = initial_states.iter().map(|l| l.to_owned()).collect();
 
-        let cone_of_safety = get_all_variable_numbers_in_cnf(&state_to_safety_translation);
+        // the unsafety property is added since a bad literal can be a latch without any wires.
+        let mut cone_of_safety = get_all_variable_numbers_in_cnf(&state_to_safety_translation);
+        cone_of_safety.extend(unsafety_property.iter().map(|l| l.get_number()));
         let cone_of_transition = get_all_variable_numbers_in_cnf(&transition);
 
         let cone_of_safety_only_latches: HashSet<VariableType> = cone_of_safety
@@ -265,4 +280,124 @@ impl FiniteStateTransitionSystem {
             cone_of_transition_only_latches,
         }
     }
+
+    // ********************************************************************************************
+    // aig api functions
+    // ********************************************************************************************
+
+    /// Function that gets the literals of the properties of an AndInverterGraph, these are
+    /// the bad state literals followed by the outputs if `assume_output_is_bad` is true.
+    /// Property indices, like the ones given to `from_aig_for_properties`, are positions in
+    /// this vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig: &AndInverterGraph` - the AndInverterGraph desired.
+    /// * `assume_output_is_bad: bool` - whether the outputs are properties as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::{AndInverterGraph, FiniteStateTransitionSystem};
+    /// let aig = AndInverterGraph::from_aag_str("aag 2 2 0 1 0 1\n2\n4\n2\n5\n");
+    /// assert_eq!(FiniteStateTransitionSystem::get_property_literals_of_aig(&aig, false), vec![5]);
+    /// assert_eq!(FiniteStateTransitionSystem::get_property_literals_of_aig(&aig, true), vec![5, 2]);
+    /// ```
+    pub fn get_property_literals_of_aig(
+        aig: &AndInverterGraph,
+        assume_output_is_bad: bool,
+    ) -> Vec<usize> {
+        // take all bad into consideration
+        let mut property_literals = aig.get_bad_information();
+        // this is here because sometimes we consider output to be bad
+        if assume_output_is_bad {
+            property_literals.append(&mut aig.get_output_information());
+        }
+        property_literals
+    }
+
+    /// Function that converts an AndInverterGraph into a FiniteStateTransitionSystem.
+    /// Invariant constraints of the AndInverterGraph are assumed in every state, both by the
+    /// transition relation and by the state to safety translation.
+    /// Panics if the AndInverterGraph has justice properties or fairness constraints, since
+    /// the result only describes its safety properties. Use `liveness_to_safety` to check
+    /// them, or `from_aig_ignoring_liveness` to check only the safety properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig: &AndInverterGraph` - the AndInverterGraph desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::{AndInverterGraph, FiniteStateTransitionSystem};
+    /// let file_path = "tests/examples/ours/counter.aig";
+    /// let aig = AndInverterGraph::from_aig_path(file_path);
+    /// let fsts = FiniteStateTransitionSystem::from_aig(&aig, false);
+    /// assert_eq!(fsts.get_initial_relation().to_string(), "p cnf 3 3\n-1 0\n-2 0\n-3 0");
+    /// ```
+    pub fn from_aig(aig: &AndInverterGraph, assume_output_is_bad: bool) -> Self {
+        let number_of_properties =
+            Self::get_property_literals_of_aig(aig, assume_output_is_bad).len();
+        let all_properties: Vec<usize> = (0..number_of_properties).collect();
+        Self::from_aig_for_properties(aig, assume_output_is_bad, &all_properties)
+    }
+
+    /// Function that converts an AndInverterGraph into a FiniteStateTransitionSystem like
+    /// `from_aig`, but ignores the justice properties and fairness constraints of the
+    /// AndInverterGraph instead of panicking. Fairness constraints only restrict the traces
+    /// that violate justice properties, so the safety properties are checked as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig: &AndInverterGraph` - the AndInverterGraph desired.
+    /// * `assume_output_is_bad: bool` - whether the outputs are properties as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::{AndInverterGraph, FiniteStateTransitionSystem};
+    /// // an input that is bad, and a justice property that the input is 1 infinitely often.
+    /// let aig = AndInverterGraph::from_aag_str("aag 1 1 0 0 0 1 0 1\n2\n2\n1\n2\n");
+    /// let fsts = FiniteStateTransitionSystem::from_aig_ignoring_liveness(&aig, false);
+    /// assert_eq!(fsts.get_unsafety_property().len(), 1);
+    /// ```
+    pub fn from_aig_ignoring_liveness(aig: &AndInverterGraph, assume_output_is_bad: bool) -> Self {
+        let number_of_properties =
+            Self::get_property_literals_of_aig(aig, assume_output_is_bad).len();
+        let all_properties: Vec<usize> = (0..number_of_properties).collect();
+        Self::from_aig_for_selected_properties(aig, assume_output_is_bad, &all_properties)
+    }
+
+    /// Function that converts an AndInverterGraph into a FiniteStateTransitionSystem that is
+    /// unsafe only when one of the selected properties is violated, so a counter example
+    /// belongs to one of them and the other properties are ignored.
+    /// Panics like `from_aig` if the AndInverterGraph has justice properties or fairness
+    /// constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `aig: &AndInverterGraph` - the AndInverterGraph desired.
+    /// * `assume_output_is_bad: bool` - whether the outputs are properties as well.
+    /// * `property_indices: &[usize]` - the positions of the selected properties in
+    ///   `get_property_literals_of_aig`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_formal_verification::models::{AndInverterGraph, FiniteStateTransitionSystem};
+    /// let file_path = "tests/examples/ours/counter_with_2_bad_assertions.aig";
+    /// let aig = AndInverterGraph::from_aig_path(file_path);
+    /// let both = FiniteStateTransitionSystem::from_aig(&aig, false);
+    /// let second = FiniteStateTransitionSystem::from_aig_for_properties(&aig, false, &[1]);
+    /// assert_eq!(both.get_unsafety_property().len(), 2);
+    /// assert_eq!(second.get_unsafety_property().len(), 1);
+    /// ```
+    pub fn from_aig_for_properties(
+        aig: &AndInverterGraph,
+        assume_output_is_bad: bool,
+        property_indices: &[usize],
+    ) -> Self {
+        assert!(
+            aig.get_justice_information().is_empty() && aig.get_fairness_information().is_empty(),
+            "AIG has justice properties or fairness constraints, use liveness_to_safety to check them or from_aig_ignoring_liveness to ignore them."
+        );
+        Self::from_aig_for_selected_properties(aig, assume_output_is_bad, property_indices)
+    }
 }
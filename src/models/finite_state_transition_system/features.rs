@@ -34,7 +34,7 @@ impl FiniteStateTransitionSystem {
         Clause::new(&literals)
     }
 
-    fn bump_all_cnf_variables_by_some_number(
+    pub(super) fn bump_all_cnf_variables_by_some_number(
         original_cnf: &CNF,
         number_to_bump: VariableType,
     ) -> CNF {
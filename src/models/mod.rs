@@ -1,4 +1,4 @@
-//! models like AndInverterGraph and FiniteStateTransitionSystem.
+//! models like AndInverterGraph, Btor2Model and FiniteStateTransitionSystem.
 
 // ************************************************************************************************
 // rust submodule decleration, they get searched in their respective file  names
@@ -8,10 +8,13 @@ pub mod finite_state_transition_system;
 // requires folder in this directory with the name 'finite_state_transition_system'
 pub mod and_inverter_graph;
 // requires folder in this directory with the name 'and_inverter_graph'
+pub mod btor2;
+// requires folder in this directory with the name 'btor2'
 
 // ************************************************************************************************
 // re-exports of structs in these modules to simplify paths for other imports
 // ************************************************************************************************
 
 pub use and_inverter_graph::AndInverterGraph;
+pub use btor2::Btor2Model;
 pub use finite_state_transition_system::FiniteStateTransitionSystem;
@@ -0,0 +1,115 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::models::{
+        and_inverter_graph::AIGBitParallelSimulationResult, AndInverterGraph,
+    };
+    use std::collections::HashMap;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_value_of_literal(state: &[bool], literal: usize) -> bool {
+        state[literal >> 1] ^ ((literal & 1) == 1)
+    }
+
+    fn compare_lane_to_simulate(
+        aig: &AndInverterGraph,
+        result: &AIGBitParallelSimulationResult,
+        lane: usize,
+    ) {
+        let input_vars: Vec<usize> = aig.get_input_information();
+        let latches = aig.get_latch_information();
+
+        let mut inputs = Vec::new();
+        for cycle in 0..result.get_number_of_cycles() {
+            let mut clk_inputs = HashMap::new();
+            for (index, input_literal) in input_vars.iter().enumerate() {
+                let value = result.get_input_value(cycle, index, lane);
+                clk_inputs.insert(input_literal >> 1, value);
+            }
+            inputs.push(clk_inputs);
+        }
+        let mut initial_latches = HashMap::new();
+        for (index, (latch_literal, _, _)) in latches.iter().enumerate() {
+            let value = result.get_initial_latch_value(index, lane);
+            initial_latches.insert(latch_literal >> 1, value);
+        }
+
+        let states = aig.simulate(&inputs, &initial_latches);
+        for (cycle, state) in states.iter().enumerate() {
+            for (index, literal) in aig.get_output_information().iter().enumerate() {
+                let expected = get_value_of_literal(state, literal.to_owned());
+                assert_eq!(result.get_output_value(cycle, index, lane), expected);
+            }
+            for (index, literal) in aig.get_bad_information().iter().enumerate() {
+                let expected = get_value_of_literal(state, literal.to_owned());
+                assert_eq!(result.get_bad_value(cycle, index, lane), expected);
+            }
+            for (index, literal) in aig.get_constraints_information().iter().enumerate() {
+                let expected = get_value_of_literal(state, literal.to_owned());
+                assert_eq!(result.get_constraint_value(cycle, index, lane), expected);
+            }
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn bit_parallel_simulation_with_seeded_latches() {
+        // l0 becomes 1 once the input is 1, l1 becomes 1 once l0 is 1, bad when both are 1.
+        let aag = "aag 6 1 2 0 3 1\n2\n4 9\n6 11\n12\n8 5 3\n10 7 5\n12 6 4\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        // the input is 1 in the lanes of the first word and 0 in the lanes of the second word.
+        let inputs = vec![vec![u64::MAX, 0]; 3];
+
+        // both latches start at 1 in the lanes of the second word.
+        let mut initial_latches = HashMap::new();
+        initial_latches.insert(2, vec![0, u64::MAX]);
+        initial_latches.insert(3, vec![0, u64::MAX]);
+        let result = aig.simulate_bit_parallel(&inputs, &initial_latches, 2);
+        assert_eq!(result.get_number_of_lanes(), 128);
+        assert_eq!(result.get_bad_words(0, 0), &[0, u64::MAX]);
+        assert_eq!(result.get_bad_words(1, 0), &[0, u64::MAX]);
+        assert_eq!(result.get_bad_words(2, 0), &[u64::MAX, u64::MAX]);
+        assert!(result.get_bad_value(2, 0, 5));
+        assert!(!result.get_bad_value(1, 0, 5));
+    }
+
+    #[test]
+    fn bit_parallel_simulation_agrees_with_simulate_on_hwmcc20() {
+        let run_test = true;
+        let probability_of_testing_each_file = 0.05;
+        if run_test {
+            let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+            for (aig_file_path, _) in file_paths {
+                if common::_true_with_probability(probability_of_testing_each_file) {
+                    println!("{}", aig_file_path);
+                    let aig = AndInverterGraph::from_aig_path(&aig_file_path);
+                    let result = aig.simulate_bit_parallel_randomly(4, 2);
+                    for lane in [0, 63, 64, 127] {
+                        compare_lane_to_simulate(&aig, &result, lane);
+                    }
+                }
+            }
+        }
+    }
+}
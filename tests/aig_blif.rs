@@ -0,0 +1,197 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rand::Rng;
+    use rust_formal_verification::models::{and_inverter_graph::AigParseError, AndInverterGraph};
+    use std::collections::HashMap;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the outputs of the copy should behave like the outputs and then the bad state literals of
+    /// the original, when both get the same inputs and initial latch values.
+    fn check_blif_round_trip(aig: &AndInverterGraph) {
+        if !aig.get_constraints_information().is_empty()
+            || !aig.get_justice_information().is_empty()
+            || !aig.get_fairness_information().is_empty()
+        {
+            return;
+        }
+        let copy = AndInverterGraph::from_blif_str(&aig.get_blif_string());
+        assert_eq!(
+            copy.get_input_information().len(),
+            aig.get_input_information().len()
+        );
+        assert_eq!(
+            copy.get_latch_information().len(),
+            aig.get_latch_information().len()
+        );
+        let number_of_outputs = aig.get_output_information().len();
+        assert_eq!(
+            copy.get_output_information().len(),
+            number_of_outputs + aig.get_bad_information().len()
+        );
+
+        let mut rng = rand::thread_rng();
+        let number_of_cycles = 5;
+        let inputs: Vec<Vec<u64>> = (0..number_of_cycles)
+            .map(|_| {
+                (0..aig.get_input_information().len())
+                    .map(|_| rng.gen())
+                    .collect()
+            })
+            .collect();
+        let mut aig_latches = HashMap::new();
+        let mut copy_latches = HashMap::new();
+        let latch_pairs = aig
+            .get_latch_information()
+            .into_iter()
+            .zip(copy.get_latch_information());
+        for ((aig_latch, _, aig_reset), (copy_latch, _, copy_reset)) in latch_pairs {
+            assert_eq!(aig_reset < 2, copy_reset < 2);
+            if aig_reset < 2 {
+                assert_eq!(aig_reset, copy_reset);
+            } else {
+                let word: u64 = rng.gen();
+                aig_latches.insert(aig_latch >> 1, vec![word]);
+                copy_latches.insert(copy_latch >> 1, vec![word]);
+            }
+        }
+
+        let aig_result = aig.simulate_bit_parallel(&inputs, &aig_latches, 1);
+        let copy_result = copy.simulate_bit_parallel(&inputs, &copy_latches, 1);
+        for cycle in 0..number_of_cycles {
+            for index in 0..number_of_outputs {
+                assert_eq!(
+                    copy_result.get_output_words(cycle, index),
+                    aig_result.get_output_words(cycle, index)
+                );
+            }
+            for index in 0..aig.get_bad_information().len() {
+                assert_eq!(
+                    copy_result.get_output_words(cycle, number_of_outputs + index),
+                    aig_result.get_bad_words(cycle, index)
+                );
+            }
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn blif_round_trip_of_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            check_blif_round_trip(&AndInverterGraph::from_aig_path(&aig_path));
+        }
+    }
+
+    #[test]
+    fn blif_round_trip_of_hwmcc_examples() {
+        let probability_of_testing_each_file = 0.05;
+        let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+        for (aig_file_path, _) in file_paths {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                check_blif_round_trip(&AndInverterGraph::from_aig_path(&aig_file_path));
+            }
+        }
+    }
+
+    #[test]
+    fn blif_symbols_with_spaces_and_collisions() {
+        // the input and the latch have the same symbol, and the bad literal has a space.
+        let aag = "aag 2 1 1 0 0 1\n2\n4 2 1\n4\ni0 s\nl0 s\nb0 is set\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let blif = aig.get_blif_string();
+        assert!(blif.contains(".inputs s\n"));
+        assert!(blif.contains(".outputs is_set\n"));
+        assert!(blif.contains(".latch s s_1 1\n"));
+        check_blif_round_trip(&aig);
+    }
+
+    #[test]
+    fn blif_reader_features() {
+        // comments, line continuations, off-set covers, constants and unknown initial values.
+        let blif = "# header comment\n\
+            .model m\n\
+            .inputs a \\\n b\n\
+            .outputs nand one\n\
+            .latch zero q re clk 3\n\
+            .names a b nand # comment\n\
+            11 0\n\
+            .names one\n\
+            1\n\
+            .names zero\n\
+            .end\n\
+            .model ignored\n";
+        let aig = AndInverterGraph::from_blif_str(blif);
+        assert_eq!(aig.get_input_information(), vec![2, 4]);
+        assert_eq!(aig.get_latch_information(), vec![(6, 0, 6)]);
+        assert_eq!(aig.get_output_information(), vec![9, 1]);
+        assert_eq!(
+            aig.get_aag_string(),
+            "aag 4 2 1 2 1\n2\n4\n6 0 6\n9\n1\n8 4 2\ni0 a\ni1 b\nl0 q\no0 nand\no1 one\n"
+        );
+    }
+
+    #[test]
+    fn blif_reader_errors() {
+        let check = |blif: &str, line: usize, reason: &str| {
+            assert_eq!(
+                AndInverterGraph::try_from_blif_str(blif).err(),
+                Some(AigParseError::InvalidLine {
+                    line,
+                    reason: reason.to_string()
+                })
+            );
+        };
+        check(
+            ".inputs a\n.outputs a\n.names a a\n1 1\n",
+            3,
+            "Signal a is defined more than once.",
+        );
+        check(
+            ".outputs x\n.names y x\n1 1\n.names x y\n1 1\n",
+            2,
+            "Signal x depends on itself.",
+        );
+        check(
+            ".inputs a b\n.names a b c\n11 1\n00 0\n",
+            4,
+            "All cubes of a cover should have the same output value.",
+        );
+        check(
+            ".inputs a\n.names a c\n11 1\n",
+            3,
+            "Input plane should have 1 characters that are 0, 1 or -.",
+        );
+        check(
+            ".inputs a\n.latch a q 5\n",
+            2,
+            "Initial value of latch should be 0, 1, 2 or 3, found 5.",
+        );
+        check(".inputs a\n11 1\n", 2, "Cube is not part of any cover.");
+        check(
+            ".inputs a\n.subckt and2 A=a\n",
+            2,
+            "Unsupported BLIF command .subckt.",
+        );
+    }
+}
@@ -0,0 +1,90 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use rust_formal_verification::models::{and_inverter_graph::AigBuilder, AndInverterGraph};
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn build_counter_example() {
+        let mut builder = AigBuilder::new();
+        let l0 = builder.add_latch(0, Some(false));
+        let l1 = builder.add_latch(l0, Some(false));
+        let l2 = builder.add_latch(l1, Some(false));
+        let not_l2_and_not_l1 = builder.add_and(l2 ^ 1, l1 ^ 1);
+        let next_of_l0 = builder.add_and(not_l2_and_not_l1, l0 ^ 1);
+        builder.set_latch_next(l0, next_of_l0);
+        builder.add_output(next_of_l0);
+
+        let aig = builder.build();
+        let expected = AndInverterGraph::from_aag_path("tests/examples/ours/counter.aag");
+        assert_eq!(aig.get_aag_string(), expected.get_aag_string());
+        assert_eq!(
+            aig.to_aig_bytes(),
+            std::fs::read("tests/examples/ours/counter.aig").unwrap()
+        );
+    }
+
+    #[test]
+    fn build_with_structural_hashing_and_constant_folding() {
+        let mut builder = AigBuilder::new();
+        let x = builder.add_input();
+        let y = builder.add_input();
+
+        // constants and trivial gates are folded.
+        assert_eq!(builder.add_and(x, 0), 0);
+        assert_eq!(builder.add_and(1, y), y);
+        assert_eq!(builder.add_and(y, y), y);
+        assert_eq!(builder.add_and(y ^ 1, y), 0);
+
+        // the same gate is only created once.
+        let g = builder.add_and(x ^ 1, y);
+        assert_eq!(builder.add_and(y, x ^ 1), g);
+        assert_ne!(builder.add_and(x, y), g);
+        builder.add_bad(g);
+        builder.add_constraint(x ^ 1);
+
+        let aig = builder.build();
+        assert_eq!(
+            aig.get_aag_string(),
+            "aag 4 2 0 0 2 1 1\n2\n4\n6\n3\n6 4 3\n8 4 2\n"
+        );
+    }
+
+    #[test]
+    fn build_with_gates_before_latches() {
+        // inputs, latches and and gates are renumbered to be in this order.
+        let mut builder = AigBuilder::new();
+        let x = builder.add_input();
+        let y = builder.add_input();
+        let x_and_y = builder.add_and(x, y);
+        let latch = builder.add_latch(x_and_y, None);
+        let z = builder.add_input();
+        let bad = builder.add_and(latch, z);
+        builder.add_bad(bad);
+
+        let aig = builder.build();
+        assert_eq!(
+            aig.get_aag_string(),
+            "aag 6 3 1 0 2 1\n2\n4\n6\n8 10 8\n12\n10 4 2\n12 8 6\n"
+        );
+        let from_binary = AndInverterGraph::try_from_aig_bytes(&aig.to_aig_bytes()).unwrap();
+        assert_eq!(from_binary.get_aag_string(), aig.get_aag_string());
+    }
+}
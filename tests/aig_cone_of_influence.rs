@@ -0,0 +1,130 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{
+        algorithms::{bmc::BMCResult, BMC},
+        models::{and_inverter_graph::AigBuilder, AndInverterGraph, FiniteStateTransitionSystem},
+        solvers::sat::stateless::CaDiCalSolver,
+    };
+    use std::time::Duration;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn get_depth_of_counter_example(aig: &AndInverterGraph) -> Option<u32> {
+        let fin_state = FiniteStateTransitionSystem::from_aig(aig, false);
+        let bmc = BMC::<CaDiCalSolver>::new(false);
+        match bmc.search(&fin_state, 10, Duration::from_secs(10)) {
+            BMCResult::NoCTX { depth_reached: _ } => None,
+            BMCResult::CTX {
+                assignment: _,
+                depth,
+            } => Some(depth),
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn coi_reduce_counter_with_2_bad_assertions() {
+        let aig = AndInverterGraph::from_aig_path(
+            "tests/examples/ours/counter_with_2_bad_assertions.aig",
+        );
+
+        // every latch affects both properties, only the output is removed.
+        let first = aig.coi_reduce(&[0]);
+        assert_eq!(
+            first.get_aag_string(),
+            "aag 5 0 3 0 2 1\n2 10\n4 2\n6 4\n4\n8 7 5\n10 8 3\n"
+        );
+        assert_eq!(get_depth_of_counter_example(&first), Some(2));
+
+        let second = aig.coi_reduce(&[1]);
+        assert_eq!(
+            second.get_aag_string(),
+            "aag 5 0 3 0 2 1\n2 10\n4 2\n6 4\n6\n8 7 5\n10 8 3\n"
+        );
+        assert_eq!(get_depth_of_counter_example(&second), Some(3));
+
+        let both = aig.coi_reduce(&[1, 0]);
+        assert_eq!(both.get_bad_information(), vec![6, 4]);
+        assert_eq!(get_depth_of_counter_example(&both), Some(2));
+    }
+
+    #[test]
+    fn coi_reduce_removes_unrelated_logic() {
+        let mut builder = AigBuilder::new();
+        let x = builder.add_input();
+        let y = builder.add_input();
+        let enable = builder.add_input();
+        builder.set_input_symbol(enable, "enable");
+        let unrelated = builder.add_latch(0, Some(false));
+        let next_of_unrelated = builder.add_and(x, y);
+        builder.set_latch_next(unrelated, next_of_unrelated);
+        let counter = builder.add_latch(0, None);
+        builder.set_latch_symbol(counter, "counter");
+        let next_of_counter = builder.add_and(counter ^ 1, enable);
+        builder.set_latch_next(counter, next_of_counter);
+        builder.add_bad(unrelated);
+        builder.add_bad(counter);
+        builder.set_bad_symbol(1, "counter is 1");
+        builder.add_constraint(y ^ 1);
+        builder.add_output(x);
+        let aig = builder.build();
+
+        let reduced = aig.coi_reduce(&[1]);
+        assert_eq!(
+            reduced.get_aag_string(),
+            "aag 4 2 1 0 1 1 1\n2\n4\n6 8 6\n6\n3\n8 7 4\ni1 enable\nl0 counter\nb0 counter is 1\n"
+        );
+        assert_eq!(
+            reduced.coi_reduce(&[0]).get_aag_string(),
+            reduced.get_aag_string()
+        );
+    }
+
+    #[test]
+    fn coi_reduce_all_properties_of_hwmcc20() {
+        let run_test = true;
+        let probability_of_testing_each_file = 0.05;
+        if run_test {
+            let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+            for (aig_file_path, _) in file_paths {
+                if common::_true_with_probability(probability_of_testing_each_file) {
+                    println!("{}", aig_file_path);
+                    let aig = AndInverterGraph::from_aig_path(&aig_file_path);
+                    let all: Vec<usize> = (0..aig.get_bad_information().len()).collect();
+                    let reduced = aig.coi_reduce(&all);
+                    assert!(
+                        reduced.get_latch_information().len() <= aig.get_latch_information().len()
+                    );
+                    assert_eq!(
+                        reduced.coi_reduce(&all).get_aag_string(),
+                        reduced.get_aag_string()
+                    );
+                    let from_binary =
+                        AndInverterGraph::try_from_aig_bytes(&reduced.to_aig_bytes()).unwrap();
+                    assert_eq!(from_binary.get_aag_string(), reduced.get_aag_string());
+                }
+            }
+        }
+    }
+}
@@ -16,8 +16,8 @@ mod tests {
     // ********************************************************************************************
 
     use crate::common;
-    use rust_formal_verification::models::AndInverterGraph;
-    use std::{cmp::max, fs};
+    use rust_formal_verification::models::{and_inverter_graph::AigParseError, AndInverterGraph};
+    use std::{cmp::max, fs, path::Path};
 
     // ********************************************************************************************
     // helpers
@@ -107,4 +107,252 @@ mod tests {
         });
         print_table(&table, max_size_of_file_path);
     }
+
+    #[test]
+    fn read_all_aag_files_from_hwmcc20() {
+        let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+        let probability_of_testing_each_file = 0.05;
+
+        for (aig_file_path, aag_file_path) in file_paths {
+            // make the test faster by only doing this with 5% of the files
+            if common::_true_with_probability(probability_of_testing_each_file)
+                && Path::new(&aag_file_path).exists()
+            {
+                println!("file_path = {}", aag_file_path);
+                let aig_from_aag = AndInverterGraph::from_aag_path(&aag_file_path);
+                let true_aag = fs::read_to_string(&aag_file_path).unwrap();
+                common::_assert_long_string_eq(&true_aag, &aig_from_aag.get_aag_string());
+
+                let aig_from_aig = AndInverterGraph::from_aig_path(&aig_file_path);
+                common::_assert_long_string_eq(
+                    &aig_from_aig.get_aag_string(),
+                    &aig_from_aag.get_aag_string(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn read_aag_with_nodes_out_of_order() {
+        // inputs, latches and and gates may have any variable number in the ascii format.
+        let aag = "aag 4 1 1 1 2\n6\n2 8\n4\n8 6 3\n4 3 1\ni0 enable\nl0 state\no0 out\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        assert_eq!(aig.get_input_information(), vec![6]);
+        assert_eq!(aig.get_latch_information(), vec![(2, 8, 0)]);
+        assert_eq!(aig.get_output_information(), vec![4]);
+        let mut ands = aig.get_and_information_in_cone_of_influence(&[4, 8]);
+        ands.sort();
+        assert_eq!(ands, vec![(4, 3, 1), (8, 6, 3)]);
+        assert_eq!(aig.get_aag_string(), aag);
+    }
+
+    #[test]
+    fn read_aag_with_and_gate_out_of_topological_order() {
+        // the first and gate uses the second one, so the two are renumbered while reading.
+        let aag = "aag 4 1 1 1 2\n2\n4 8\n6\n6 8 4\n8 2 5\ni0 x\nl0 y\no0 out\n";
+        let renumbered = "aag 4 1 1 1 2\n2\n4 6\n8\n8 6 4\n6 2 5\ni0 x\nl0 y\no0 out\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        assert_eq!(aig.get_aag_string(), renumbered);
+        assert_eq!(
+            AndInverterGraph::from_aag_str(renumbered).get_aag_string(),
+            renumbered
+        );
+        let from_binary = AndInverterGraph::try_from_aig_bytes(&aig.to_aig_bytes()).unwrap();
+        assert_eq!(
+            from_binary.get_aag_string(),
+            "aag 4 1 1 1 2\n2\n4 6\n8\n6 5 2\n8 6 4\ni0 x\nl0 y\no0 out\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "And gate 4 is on a cycle.")]
+    fn read_aag_with_cycle_of_and_gates() {
+        AndInverterGraph::from_aag_str("aag 3 1 0 1 2\n2\n4\n4 6 2\n6 4 3\n");
+    }
+
+    // ********************************************************************************************
+    // aig writing test
+    // ********************************************************************************************
+
+    #[test]
+    fn write_all_aig_files_from_hwmcc20() {
+        let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+        let probability_of_testing_each_file = 0.05;
+
+        for (aig_file_path, _) in file_paths {
+            // make the test faster by only doing this with 5% of the files
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                println!("file_path = {}", aig_file_path);
+                let aig = AndInverterGraph::from_aig_path(&aig_file_path);
+                let true_aig = fs::read(&aig_file_path).unwrap();
+                assert!(aig.to_aig_bytes() == true_aig);
+            }
+        }
+    }
+
+    #[test]
+    fn write_aig_of_aag_with_nodes_out_of_order() {
+        let aag = "aag 4 1 1 1 2\n6\n2 8\n4\n8 6 3\n4 3 1\ni0 enable\nl0 state\no0 out\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+
+        // variables are renumbered: input 6 -> 2, latch 2 -> 4, and 4 -> 6, and 8 -> 8.
+        let path = std::env::temp_dir().join("write_aig_of_aag_with_nodes_out_of_order.aig");
+        let path = path.to_str().unwrap();
+        aig.write_aig_path(path);
+        let renumbered = AndInverterGraph::from_aig_path(path);
+        assert_eq!(
+            renumbered.get_aag_string(),
+            "aag 4 1 1 1 2\n2\n4 8\n6\n6 5 1\n8 5 2\ni0 enable\nl0 state\no0 out\n"
+        );
+    }
+
+    #[test]
+    fn read_and_write_aag_with_justice_and_fairness() {
+        let aag = "aag 3 1 1 0 1 0 0 2 1\n2\n4 6\n1\n2\n4\n6\n3\n2\n6 5 2\ni0 x\nl0 y\nj1 live\nf0 fair\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        assert_eq!(aig.get_justice_information(), vec![vec![4], vec![6, 3]]);
+        assert_eq!(aig.get_fairness_information(), vec![2]);
+        assert_eq!(aig.get_aag_string(), aag);
+
+        let from_binary = AndInverterGraph::try_from_aig_bytes(&aig.to_aig_bytes()).unwrap();
+        assert_eq!(from_binary.get_aag_string(), aag);
+    }
+
+    // ********************************************************************************************
+    // aig parsing errors test
+    // ********************************************************************************************
+
+    #[test]
+    fn try_reading_corrupt_aig_files() {
+        let missing = AndInverterGraph::try_from_aig_path("tests/examples/ours/missing.aig");
+        assert!(matches!(missing, Err(AigParseError::UnreadableFile { .. })));
+
+        let wrong_format = AndInverterGraph::try_from_aig_path("tests/examples/ours/counter.aag");
+        assert_eq!(
+            wrong_format.err(),
+            Some(AigParseError::InvalidLine {
+                line: 1,
+                reason: String::from(
+                    "The parameter line (first line in aig file) must start with the word 'aig'."
+                )
+            })
+        );
+
+        let wrong_count = AndInverterGraph::try_from_aig_bytes(b"aig 4 0 3 1 2\n");
+        assert_eq!(
+            wrong_count.err(),
+            Some(AigParseError::InvalidLine {
+                line: 1,
+                reason: String::from("The number of variables does not add up.")
+            })
+        );
+
+        let bad_number = AndInverterGraph::try_from_aig_bytes(b"aig 1 0 1 0 0\nx\n");
+        assert_eq!(
+            bad_number.err(),
+            Some(AigParseError::InvalidLine {
+                line: 2,
+                reason: String::from("'x' is not a valid number.")
+            })
+        );
+
+        let bad_reset = AndInverterGraph::try_from_aig_bytes(b"aig 1 0 1 0 0\n2 3\n");
+        assert_eq!(
+            bad_reset.err(),
+            Some(AigParseError::InvalidLine {
+                line: 2,
+                reason: String::from(
+                    "Latch reset may be 0, 1, or equal to literal designated for latch."
+                )
+            })
+        );
+
+        // lhs = 4, delta of 5 would make rhs0 negative.
+        let bad_delta = AndInverterGraph::try_from_aig_bytes(b"aig 2 1 0 0 1\n\x05\x00");
+        assert_eq!(
+            bad_delta.err(),
+            Some(AigParseError::InvalidBytes {
+                byte_offset: 14,
+                reason: String::from("Error (lhs > rhs0 >= rhs1) does not hold for and gate 4.")
+            })
+        );
+
+        let non_utf8_symbol = AndInverterGraph::try_from_aig_bytes(b"aig 1 1 0 0 0\ni0 \xff\n");
+        assert_eq!(
+            non_utf8_symbol.err(),
+            Some(AigParseError::InvalidBytes {
+                byte_offset: 14,
+                reason: String::from("Symbol line is not valid UTF-8.")
+            })
+        );
+
+        let too_many_variables =
+            AndInverterGraph::try_from_aig_bytes(b"aig 1000000000000000 1000000000000000 0 0 0\n");
+        assert_eq!(
+            too_many_variables.err(),
+            Some(AigParseError::InvalidLine {
+                line: 1,
+                reason: String::from("There is not enough memory for the number of variables.")
+            })
+        );
+
+        let too_short = AndInverterGraph::try_from_aag_str("aag 1000000000 0 0 0 1000000000\n");
+        assert_eq!(
+            too_short.err(),
+            Some(AigParseError::InvalidLine {
+                line: 1,
+                reason: String::from("The file is too short for the parameter line.")
+            })
+        );
+
+        let too_short = AndInverterGraph::try_from_aig_bytes(b"aig 5 0 0 0 5\n\x01\x01");
+        assert_eq!(
+            too_short.err(),
+            Some(AigParseError::InvalidLine {
+                line: 1,
+                reason: String::from("The file is too short for the parameter line.")
+            })
+        );
+
+        let missing_symbol_target =
+            AndInverterGraph::try_from_aag_str("aag 1 1 0 0 0\n2\ni0 a\ni1 b\n");
+        assert_eq!(
+            missing_symbol_target.err(),
+            Some(AigParseError::InvalidLine {
+                line: 4,
+                reason: String::from("There is no i1 to name.")
+            })
+        );
+    }
+
+    #[test]
+    fn comments_that_are_not_utf8_are_kept() {
+        let bytes = b"aig 1 1 0 0 0\ni0 a\nc\n\xff\x00comment\n";
+        let aig = AndInverterGraph::try_from_aig_bytes(bytes).unwrap();
+        assert_eq!(aig.to_aig_bytes(), bytes);
+    }
+
+    #[test]
+    fn try_reading_truncated_aig_files_from_hwmcc20() {
+        let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+        let probability_of_testing_each_file = 0.05;
+
+        for (aig_file_path, _) in file_paths {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                println!("file_path = {}", aig_file_path);
+                let aig_bytes = fs::read(&aig_file_path).unwrap();
+                // cutting the file anywhere must not panic.
+                for cut_position in [
+                    aig_bytes.len() / 4,
+                    aig_bytes.len() / 2,
+                    aig_bytes.len() - 1,
+                ] {
+                    let result = AndInverterGraph::try_from_aig_bytes(&aig_bytes[..cut_position]);
+                    if let Err(e) = result {
+                        println!("error = {}", e);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,90 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::models::AndInverterGraph;
+    use std::collections::HashSet;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn count_lines(dot: &str, pattern: &str) -> usize {
+        dot.lines().filter(|line| line.contains(pattern)).count()
+    }
+
+    fn check_dot_of_aig(aig: &AndInverterGraph) {
+        let dot = aig.to_dot();
+        assert!(dot.starts_with("digraph aig {\n"));
+        assert!(dot.ends_with("}\n"));
+        let number_of_inputs = aig.get_input_information().len();
+        let number_of_latches = aig.get_latch_information().len();
+        let aag = aig.get_aag_string();
+        let header: Vec<&str> = aag.lines().next().unwrap().split(' ').collect();
+        let number_of_ands: usize = header[5].parse().unwrap();
+        let number_of_roots = aig.get_output_information().len()
+            + aig.get_bad_information().len()
+            + aig.get_constraints_information().len();
+        assert_eq!(count_lines(&dot, "shape=triangle"), number_of_inputs);
+        assert_eq!(count_lines(&dot, "label=\"next\""), number_of_latches);
+        assert_eq!(count_lines(&dot, "shape=ellipse"), number_of_ands);
+        assert_eq!(count_lines(&dot, "shape=invtriangle"), number_of_roots);
+        let number_of_initialized_latches = aig
+            .get_latch_information()
+            .iter()
+            .filter(|(_, _, reset)| reset < &2)
+            .count();
+        assert_eq!(
+            count_lines(&dot, " -> "),
+            number_of_latches
+                + number_of_initialized_latches
+                + 2 * number_of_ands
+                + number_of_roots
+        );
+
+        // the cone of influence of everything is the whole drawing.
+        let mut all_literals: Vec<usize> = aig.get_output_information();
+        all_literals.append(&mut aig.get_bad_information());
+        all_literals.append(&mut aig.get_constraints_information());
+        let cone_dot = aig.to_dot_of_cone_of_influence(&all_literals);
+        assert!(cone_dot.lines().count() <= dot.lines().count());
+        let lines: HashSet<&str> = dot.lines().collect();
+        assert!(cone_dot.lines().all(|line| lines.contains(line)));
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn dot_of_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            check_dot_of_aig(&AndInverterGraph::from_aig_path(&aig_path));
+        }
+    }
+
+    #[test]
+    fn dot_of_hwmcc_examples() {
+        let probability_of_testing_each_file = 0.05;
+        let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+        for (aig_file_path, _) in file_paths {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                check_dot_of_aig(&AndInverterGraph::from_aig_path(&aig_file_path));
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{
+        models::{and_inverter_graph::AigBuilder, AndInverterGraph},
+        solvers::sat::stateful::CaDiCalSolver,
+    };
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn fraig_test(aig_path: &str, maximum_number_of_ands: usize) {
+        let aig = AndInverterGraph::from_aig_path(aig_path);
+        if common::_get_number_of_ands(&aig) > maximum_number_of_ands {
+            return;
+        }
+        let fraig = aig.fraig::<CaDiCalSolver>();
+        println!(
+            "{aig_path} has {} and gates, after fraiging {}",
+            common::_get_number_of_ands(&aig),
+            common::_get_number_of_ands(&fraig)
+        );
+        assert!(common::_get_number_of_ands(&fraig) <= common::_get_number_of_ands(&aig));
+        assert_eq!(
+            fraig.get_latch_information().len(),
+            aig.get_latch_information().len()
+        );
+        common::_assert_same_simulation(&aig, &fraig);
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn fraig_merges_equivalent_and_gates() {
+        // x ^ y as !(x & y) & !(!x & !y), and as !(x & !y) & !(!x & y), which is x ^ !y.
+        let aag = "aag 8 2 0 2 6\n2\n4\n10\n16\n6 4 2\n8 5 3\n10 9 7\n12 5 2\n14 4 3\n16 15 13\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let fraig = aig.fraig::<CaDiCalSolver>();
+        assert_eq!(common::_get_number_of_ands(&fraig), 3);
+        let outputs = fraig.get_output_information();
+        assert_eq!(outputs[0], outputs[1] ^ 1);
+        common::_assert_same_simulation(&aig, &fraig);
+    }
+
+    #[test]
+    fn fraig_finds_constants() {
+        // (x & y) & !x is always 0, and a latch that is and-ed with its own negation.
+        let aag =
+            "aag 6 2 1 1 3 1\n2\n4\n6 12\n10\n12\n8 4 2\n10 8 3\n12 7 6\ni0 x\nl0 l\nb0 zero\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let fraig = aig.fraig::<CaDiCalSolver>();
+        assert_eq!(
+            fraig.get_aag_string(),
+            "aag 3 2 1 1 0 1\n2\n4\n6 0\n0\n0\ni0 x\nl0 l\nb0 zero\n"
+        );
+    }
+
+    #[test]
+    fn fraig_keeps_different_and_gates() {
+        // x & y and x & !y, both outputs of a graph that cannot be reduced.
+        let aag = "aag 4 2 0 2 2\n2\n4\n6\n8\n6 4 2\n8 5 2\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        assert_eq!(aig.fraig::<CaDiCalSolver>().get_aag_string(), aag);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fraig does not support justice properties or fairness constraints.")]
+    fn fraig_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        aig.fraig::<CaDiCalSolver>();
+    }
+
+    #[test]
+    fn fraig_merges_after_many_counter_examples() {
+        // wide conjunctions look like the constant 0 in random simulation, so more than 64
+        // counter examples are needed to split their class. Every one of them is built twice.
+        let mut builder = AigBuilder::new();
+        let inputs: Vec<usize> = (0..30).map(|_| builder.add_input()).collect();
+        for skipped in 0..30 {
+            let conjuncts: Vec<usize> = (0..25).map(|i| inputs[(skipped + 5 + i) % 30]).collect();
+            let left = conjuncts.iter().fold(1, |a, c| builder.add_and(a, *c));
+            let right = conjuncts
+                .iter()
+                .rev()
+                .fold(1, |a, c| builder.add_and(*c, a));
+            builder.add_output(left);
+            builder.add_output(right);
+        }
+        let aig = builder.build();
+        let fraig = aig.fraig::<CaDiCalSolver>();
+        let outputs = fraig.get_output_information();
+        for pair in outputs.chunks(2) {
+            assert_ne!(pair[0], 0);
+            assert_eq!(pair[0], pair[1]);
+        }
+        assert!(common::_get_number_of_ands(&fraig) <= 30 * 24);
+    }
+
+    #[test]
+    fn fraig_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            fraig_test(&aig_path, usize::MAX);
+        }
+    }
+
+    #[test]
+    fn fraig_hwmcc20() {
+        let probability_of_testing_each_file = 0.02;
+        for (aig_path, _) in common::_get_paths_to_all_aig_and_corresponding_aag_files() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                // every candidate is checked by the sat solver, so big graphs take too long.
+                fraig_test(&aig_path, 5000);
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::models::{
+        and_inverter_graph::AigLatchDependencyGraph, AndInverterGraph,
+    };
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// checks that the components split the latches, are in topological order, and that the
+    /// dependents are the reverse of the dependencies.
+    fn assert_consistent(graph: &AigLatchDependencyGraph) {
+        let components = graph.get_strongly_connected_components();
+        let mut latches: Vec<usize> = components.iter().flatten().copied().collect();
+        latches.sort();
+        assert_eq!(
+            latches,
+            (0..graph.get_number_of_latches()).collect::<Vec<usize>>()
+        );
+        for (index, component) in components.iter().enumerate() {
+            for latch in component.iter() {
+                assert_eq!(graph.get_component_of_latch(*latch), index);
+            }
+        }
+        for latch in 0..graph.get_number_of_latches() {
+            for dependency in graph.get_dependencies(latch) {
+                assert!(
+                    graph.get_component_of_latch(*dependency)
+                        <= graph.get_component_of_latch(latch)
+                );
+                assert!(graph.get_dependents(*dependency).contains(&latch));
+            }
+            for dependent in graph.get_dependents(latch) {
+                assert!(graph.get_dependencies(*dependent).contains(&latch));
+            }
+        }
+        for latch in graph.get_feed_forward_latches() {
+            let index = graph.get_component_of_latch(latch);
+            assert!(!graph.is_component_cyclic(index));
+            assert_eq!(components[index], vec![latch]);
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn pipeline_has_no_cycles() {
+        // an input shifted through four latches.
+        let aag = "aag 5 1 4 0 0 1\n2\n4 2\n6 4\n8 6\n10 8\n10\n";
+        let graph = AndInverterGraph::from_aag_str(aag).get_latch_dependency_graph();
+        assert_consistent(&graph);
+        assert_eq!(
+            graph.get_strongly_connected_components(),
+            &[vec![0], vec![1], vec![2], vec![3]]
+        );
+        assert_eq!(graph.get_feed_forward_latches(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn rings_are_components_in_topological_order() {
+        // a ring of two latches that feeds a ring of three latches through an and gate, and a
+        // latch that reads the second ring.
+        let aag = "aag 8 1 6 0 1 1\n2\n4 6\n6 4\n8 16\n10 8\n12 10\n14 12\n14\n16 12 4\n";
+        let graph = AndInverterGraph::from_aag_str(aag).get_latch_dependency_graph();
+        assert_consistent(&graph);
+        assert_eq!(graph.get_dependencies(2), &[0, 4]);
+        assert_eq!(
+            graph.get_strongly_connected_components(),
+            &[vec![0, 1], vec![2, 3, 4], vec![5]]
+        );
+        assert!(graph.is_component_cyclic(0));
+        assert!(graph.is_component_cyclic(1));
+        assert!(!graph.is_component_cyclic(2));
+        assert_eq!(graph.get_feed_forward_latches(), vec![5]);
+    }
+
+    #[test]
+    fn latch_dependency_graph_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            let aig = AndInverterGraph::from_aig_path(&aig_path);
+            let graph = aig.get_latch_dependency_graph();
+            assert_eq!(
+                graph.get_number_of_latches(),
+                aig.get_latch_information().len()
+            );
+            assert_consistent(&graph);
+        }
+    }
+
+    #[test]
+    fn latch_dependency_graph_hwmcc20() {
+        let probability_of_testing_each_file = 0.02;
+        for (aig_path, _) in common::_get_paths_to_all_aig_and_corresponding_aag_files() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                let aig = AndInverterGraph::from_aig_path(&aig_path);
+                let graph = aig.get_latch_dependency_graph();
+                assert_eq!(
+                    graph.get_number_of_latches(),
+                    aig.get_latch_information().len()
+                );
+                assert_consistent(&graph);
+            }
+        }
+    }
+}
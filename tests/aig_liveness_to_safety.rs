@@ -0,0 +1,121 @@
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use rust_formal_verification::{
+        algorithms::{
+            proof::{IC3Stateful, ProofResult, PDR},
+            AigerWitness, BMC,
+        },
+        models::{AndInverterGraph, FiniteStateTransitionSystem},
+        solvers::sat::{stateful, stateless},
+    };
+    use std::time::Duration;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn is_live(aig: &AndInverterGraph) -> bool {
+        let (safety, _) = aig.liveness_to_safety();
+        let fin_state = FiniteStateTransitionSystem::from_aig(&safety, false);
+        let ic3_result = IC3Stateful::<stateful::CaDiCalSolver>::new(&fin_state, false).prove();
+        let pdr_result = PDR::<stateful::CaDiCalSolver>::new(&fin_state, false).prove();
+        let is_ic3_proof = matches!(ic3_result, ProofResult::Proof { .. });
+        assert_eq!(
+            is_ic3_proof,
+            matches!(pdr_result, ProofResult::Proof { .. })
+        );
+        is_ic3_proof
+    }
+
+    /// the lasso of a counter example of the safety graph, as the inputs of the original
+    /// graph in every cycle and the cycle in which the loop starts.
+    fn get_lasso(aig: &AndInverterGraph) -> (Vec<Vec<bool>>, usize) {
+        let (safety, trace_map) = aig.liveness_to_safety();
+        let fin_state = FiniteStateTransitionSystem::from_aig(&safety, false);
+        let res = BMC::<stateless::CaDiCalSolver>::new(false).search(
+            &fin_state,
+            10,
+            Duration::from_secs(10),
+        );
+        let witness = AigerWitness::from_bmc_result(&safety, &fin_state, &res).unwrap();
+        assert!(witness.check(&safety));
+        let (initial_latches, inputs) =
+            trace_map.map_trace(witness.get_initial_latches(), witness.get_inputs());
+        assert_eq!(initial_latches.len(), aig.get_latch_information().len());
+        assert_eq!(inputs.len(), witness.get_inputs().len());
+        let loop_start = trace_map.get_loop_start(witness.get_inputs()).unwrap();
+        (inputs, loop_start)
+    }
+
+    // a latch that toggles, it is 1 infinitely often.
+    const TOGGLE_IS_ONE: &str = "aag 1 0 1 0 0 0 0 1 0\n2 3\n1\n2\n";
+    // a latch that stays 0, it is never 1.
+    const ZERO_IS_ONE: &str = "aag 1 0 1 0 0 0 0 1 0\n2 2\n1\n2\n";
+    // a latch that is set by an input and stays 1, it can be 0 forever.
+    const STICKY_IS_ZERO: &str = "aag 3 1 1 0 1 0 0 1 0\n2\n4 7\n1\n5\n6 5 3\n";
+    // the same, but the input is 1 infinitely often, so the latch is eventually 1 forever.
+    const FAIR_STICKY_IS_ZERO: &str = "aag 3 1 1 0 1 0 0 1 1\n2\n4 7\n1\n5\n2\n6 5 3\n";
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn liveness_to_safety_structure() {
+        let aig = AndInverterGraph::from_aag_str(FAIR_STICKY_IS_ZERO);
+        let (safety, _) = aig.liveness_to_safety();
+        // the save input, and latches for saved, the shadow, the fairness and the justice.
+        assert_eq!(safety.get_input_information().len(), 2);
+        assert_eq!(safety.get_latch_information().len(), 5);
+        assert_eq!(safety.get_bad_information().len(), 1);
+        assert!(safety.get_fairness_information().is_empty());
+        assert!(safety.get_justice_information().is_empty());
+        assert!(safety
+            .get_latch_information()
+            .iter()
+            .all(|(_, _, reset)| reset == &0));
+    }
+
+    #[test]
+    fn liveness_to_safety_results() {
+        assert!(!is_live(&AndInverterGraph::from_aag_str(TOGGLE_IS_ONE)));
+        assert!(is_live(&AndInverterGraph::from_aag_str(ZERO_IS_ONE)));
+        assert!(!is_live(&AndInverterGraph::from_aag_str(STICKY_IS_ZERO)));
+        assert!(is_live(&AndInverterGraph::from_aag_str(
+            FAIR_STICKY_IS_ZERO
+        )));
+    }
+
+    #[test]
+    fn liveness_to_safety_lasso() {
+        // the toggling latch needs a loop of 2 cycles, both of its states are on the loop.
+        let aig = AndInverterGraph::from_aag_str(TOGGLE_IS_ONE);
+        let (inputs, loop_start) = get_lasso(&aig);
+        assert_eq!(inputs.len() - loop_start, 3);
+
+        // the sticky latch is 0 forever when the input is 0 in the loop.
+        let aig = AndInverterGraph::from_aag_str(STICKY_IS_ZERO);
+        let (inputs, loop_start) = get_lasso(&aig);
+        assert!(inputs[loop_start..inputs.len() - 1]
+            .iter()
+            .all(|inputs| !inputs[0]));
+    }
+
+    #[test]
+    fn liveness_to_safety_keeps_constraints() {
+        // the toggling latch, but it must always be 0, so it can only stay in its initial state.
+        let aig = AndInverterGraph::from_aag_str("aag 1 0 1 0 0 0 1 1 0\n2 3\n3\n1\n2\n");
+        let (safety, _) = aig.liveness_to_safety();
+        assert_eq!(safety.get_constraints_information().len(), 1);
+        assert!(is_live(&aig));
+    }
+}
@@ -0,0 +1,125 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rand::Rng;
+    use rust_formal_verification::{
+        algorithms::proof::{ProofResult, PDR},
+        models::{AndInverterGraph, FiniteStateTransitionSystem},
+        solvers::sat::stateful::CaDiCalSolver,
+    };
+    use std::collections::HashMap;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn is_safe(miter: &AndInverterGraph) -> bool {
+        let fin_state = FiniteStateTransitionSystem::from_aig(miter, false);
+        let mut prover = PDR::<CaDiCalSolver>::new(&fin_state, false);
+        matches!(prover.prove(), ProofResult::Proof { .. })
+    }
+
+    /// the bad state literal of a combinational miter on 64 random assignments to its inputs.
+    fn get_bad_word(miter: &AndInverterGraph) -> u64 {
+        assert!(miter.get_latch_information().is_empty());
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<u64> = (0..miter.get_input_information().len())
+            .map(|_| rng.gen())
+            .collect();
+        let result = miter.simulate_bit_parallel(&[inputs], &HashMap::new(), 1);
+        result.get_bad_words(0, 0)[0]
+    }
+
+    fn get_identity_mapping(amount: usize) -> Vec<(usize, usize)> {
+        (0..amount).map(|i| (i, i)).collect()
+    }
+
+    // two toggling latches, the first outputs its latch and the second starts at 1 and
+    // outputs the negation of its latch, so they have the same outputs.
+    const TOGGLE: &str = "aag 1 0 1 1 0\n2 3\n2\n";
+    const NEGATED_TOGGLE: &str = "aag 1 0 1 1 0\n2 3 1\n3\n";
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn miter_of_our_examples_with_themselves() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            let aig = AndInverterGraph::from_aig_path(&aig_path);
+            let input_mapping = get_identity_mapping(aig.get_input_information().len());
+            let output_mapping = get_identity_mapping(aig.get_output_information().len());
+            let miter = AndInverterGraph::miter(&aig, &aig, &input_mapping, &output_mapping);
+            assert_eq!(
+                miter.get_input_information().len(),
+                aig.get_input_information().len()
+            );
+            assert_eq!(
+                miter.get_latch_information().len(),
+                2 * aig.get_latch_information().len()
+            );
+            assert!(is_safe(&miter), "{aig_path}");
+        }
+    }
+
+    #[test]
+    fn miter_of_different_state_encodings() {
+        let a = AndInverterGraph::from_aag_str(TOGGLE);
+        let b = AndInverterGraph::from_aag_str(NEGATED_TOGGLE);
+        assert!(is_safe(&AndInverterGraph::miter(&a, &b, &[], &[(0, 0)])));
+
+        // when both latches start at 0, the outputs differ from the first cycle.
+        let c = AndInverterGraph::from_aag_str("aag 1 0 1 1 0\n2 3\n3\n");
+        assert!(!is_safe(&AndInverterGraph::miter(&a, &c, &[], &[(0, 0)])));
+
+        // matching the latches is wrong here, since one is the negation of the other.
+        let miter = AndInverterGraph::combinational_miter(&a, &b, &[], &[(0, 0)], &[(0, 0)]);
+        assert_eq!(miter.get_input_information().len(), 1);
+        assert_eq!(get_bad_word(&miter), u64::MAX);
+    }
+
+    #[test]
+    fn combinational_miter_of_xor_implementations() {
+        // x ^ y as !(x & y) & !(!x & !y), and as (x & !y) | (!x & y).
+        let a = AndInverterGraph::from_aag_str("aag 5 2 0 1 3\n2\n4\n10\n6 4 2\n8 5 3\n10 9 7\n");
+        let b = AndInverterGraph::from_aag_str("aag 5 2 0 1 3\n2\n4\n11\n6 5 2\n8 4 3\n10 9 7\n");
+        let miter =
+            AndInverterGraph::combinational_miter(&a, &b, &[(0, 1), (1, 0)], &[(0, 0)], &[]);
+        assert_eq!(miter.get_input_information().len(), 2);
+        assert_eq!(get_bad_word(&miter), 0);
+
+        // without an input mapping the inputs are independent, so the outputs can differ.
+        let miter = AndInverterGraph::combinational_miter(&a, &b, &[], &[(0, 0)], &[]);
+        assert_eq!(miter.get_input_information().len(), 4);
+        assert_ne!(get_bad_word(&miter), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "There is no output 1 in the first graph.")]
+    fn miter_with_wrong_output_mapping() {
+        let a = AndInverterGraph::from_aag_str(TOGGLE);
+        AndInverterGraph::miter(&a, &a, &[], &[(1, 0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Miter does not support justice properties or fairness constraints.")]
+    fn miter_with_justice_property() {
+        let a = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        AndInverterGraph::miter(&a, &a, &[(0, 0)], &[]);
+    }
+}
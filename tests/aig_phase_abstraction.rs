@@ -0,0 +1,127 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{algorithms::AigerWitness, models::AndInverterGraph};
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    // a clock that toggles, a latch that stores the input when the clock is 1, and a latch
+    // that stores the first latch when the clock is 0. It is bad when both are 1 while the
+    // clock is 1, which happens after 3 cycles.
+    const CLOCKED_SHIFT: &str = "aag 11 1 3 0 7 1\n2\n4 5\n6 15\n8 19\n22\n10 4 2\n12 6 5\n14 11 13\n16 8 4\n18 13 17\n20 6 4\n22 20 8\n";
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn unfold_keeps_verdict() {
+        for aag in [
+            common::_SHIFT,
+            common::_SHIFT_WITH_CONSTRAINT_ON_FIRST_LATCH,
+            common::_SHIFT_WITH_CONSTRAINT_ON_INPUT,
+            common::_BAD_INPUT_WITH_CONSTRAINT,
+            CLOCKED_SHIFT,
+        ] {
+            let aig = AndInverterGraph::from_aag_str(aag);
+            for number_of_phases in 1..4 {
+                let (unfolded, trace_map) = aig.unfold(number_of_phases);
+                assert_eq!(
+                    unfolded.get_input_information().len(),
+                    number_of_phases * aig.get_input_information().len()
+                );
+                assert_eq!(trace_map.get_number_of_cycles_per_cycle(), number_of_phases);
+                common::_assert_same_verdict(&aig, &unfolded, &trace_map);
+            }
+        }
+    }
+
+    #[test]
+    fn unfold_respects_constraints_in_last_phase() {
+        // the input must be 1, and in the second phase a 0 would make the graph bad.
+        let aig = AndInverterGraph::from_aag_str(common::_SHIFT_WITH_CONSTRAINT_ON_INPUT);
+        let (unfolded, trace_map) = aig.unfold(2);
+        let witness = common::_bmc_witness(&unfolded, 5, 10).unwrap();
+        let mapped = AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+        assert_eq!(mapped.get_inputs(), &[vec![true], vec![true], vec![true]]);
+        assert!(mapped.check(&aig));
+    }
+
+    #[test]
+    fn phase_abstraction_finds_clock() {
+        let aig = AndInverterGraph::from_aag_str(CLOCKED_SHIFT);
+        // the other latches become X after 3 cycles, but the clock oscillates from the start.
+        assert_eq!(aig.get_phase_period(10), Some((3, 2)));
+        let (unfolded, trace_map) = aig.phase_abstraction(10).unwrap();
+        assert_eq!(trace_map.get_number_of_prefix_cycles(), 3);
+        assert_eq!(trace_map.get_number_of_cycles_per_cycle(), 2);
+        // the clock is 1 at the start of every cycle of the unfolded graph.
+        let clock = unfolded.get_latch_information()[0].0 >> 1;
+        let constant_latches = unfolded.get_constant_latches(10).unwrap();
+        assert_eq!(constant_latches.get(&clock), Some(&true));
+        common::_assert_same_verdict(&aig, &unfolded, &trace_map);
+    }
+
+    #[test]
+    fn phase_abstraction_removes_cycles_before_the_loop() {
+        // a latch that becomes 1, and a latch that toggles after that.
+        let aig =
+            AndInverterGraph::from_aag_str("aag 5 0 2 0 3 1\n2 1\n4 11\n4\n6 5 2\n8 4 3\n10 9 7\n");
+        let (prefix, period) = aig.get_phase_period(10).unwrap();
+        assert_eq!((prefix, period), (1, 2));
+        let (decomposed, decomposition_map) = aig.temporal_decomposition(prefix);
+        let (unfolded, unfold_map) = decomposed.unfold(period);
+        let (abstracted, trace_map) = aig.phase_abstraction(10).unwrap();
+        assert_eq!(abstracted.get_aag_string(), unfolded.get_aag_string());
+        assert_eq!(trace_map, decomposition_map.compose(&unfold_map));
+
+        let witness = common::_bmc_witness(&unfolded, 5, 10).unwrap();
+        let decomposed_witness =
+            AigerWitness::from_transformed_witness(&decomposed, &unfold_map, &witness).unwrap();
+        assert!(decomposed_witness.check(&decomposed));
+        let mapped = AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+        assert!(mapped.check(&aig));
+        assert_eq!(mapped.get_inputs().len(), 3);
+        common::_assert_same_verdict(&aig, &unfolded, &trace_map);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Unfolding does not support justice properties or fairness constraints."
+    )]
+    fn unfold_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        aig.unfold(2);
+    }
+
+    #[test]
+    fn unfold_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            let aig = AndInverterGraph::from_aig_path(&aig_path);
+            if aig.get_bad_information().is_empty() {
+                continue;
+            }
+            for number_of_phases in 1..4 {
+                let (unfolded, trace_map) = aig.unfold(number_of_phases);
+                common::_assert_same_verdict(&aig, &unfolded, &trace_map);
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{algorithms::AigerWitness, models::AndInverterGraph};
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn assert_resets_are_zero(aig: &AndInverterGraph) {
+        for (_, _, reset) in aig.get_latch_information() {
+            assert_eq!(reset, 0);
+        }
+    }
+
+    // a latch that starts at 1 and stays 1 while the input is 1, bad when it is 0.
+    const ONE_RESET_LATCH: &str = "aag 3 1 1 0 1 1\n2\n4 6 1\n5\n6 4 2\n";
+    // a latch that starts at 1 and stays 1, bad when it is 0.
+    const SAFE_ONE_RESET_LATCH: &str = "aag 1 0 1 0 0 1\n2 2 1\n3\n";
+    // an uninitialized latch that keeps its value, and a latch that starts at 1 and becomes 0
+    // when the first is 1, bad when the second is 0.
+    const UNINITIALIZED_AND_ONE_RESET_LATCHES: &str = "aag 3 0 2 0 1 1\n2 2 2\n4 6 1\n5\n6 4 3\n";
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn normalize_resets_keeps_verdict() {
+        for aag in [
+            ONE_RESET_LATCH,
+            SAFE_ONE_RESET_LATCH,
+            common::_UNINITIALIZED_LATCH,
+            UNINITIALIZED_AND_ONE_RESET_LATCHES,
+        ] {
+            let aig = AndInverterGraph::from_aag_str(aag);
+            let (normalized, trace_map) = aig.normalize_resets();
+            assert_resets_are_zero(&normalized);
+            // the normalized graph needs no extra cycles.
+            if let Some((witness, mapped)) =
+                common::_assert_same_verdict(&aig, &normalized, &trace_map)
+            {
+                assert_eq!(mapped.get_inputs().len(), witness.get_inputs().len());
+            }
+        }
+    }
+
+    #[test]
+    fn normalize_resets_adds_initialized_latch() {
+        let aig = AndInverterGraph::from_aag_str(UNINITIALIZED_AND_ONE_RESET_LATCHES);
+        let (normalized, trace_map) = aig.normalize_resets();
+        // the initial value of the first latch is a new input.
+        assert_eq!(normalized.get_input_information().len(), 1);
+        assert_eq!(normalized.get_latch_information().len(), 3);
+        let witness = common::_bmc_witness(&normalized, 5, 10).unwrap();
+        let mapped = AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+        assert_eq!(mapped.get_initial_latches(), &[Some(true), Some(true)]);
+        assert_eq!(mapped.get_inputs().len(), 2);
+        assert!(mapped.check(&aig));
+    }
+
+    #[test]
+    fn normalize_resets_keeps_normalized_graph() {
+        let aag = "aag 3 1 2 0 0 1\n2\n4 2\n6 4\n6\ni0 x\nl0 y\nl1 z\nb0 p\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let (normalized, trace_map) = aig.normalize_resets();
+        assert_eq!(normalized.get_aag_string(), aag);
+        assert_eq!(trace_map.get_number_of_cycles_per_cycle(), 1);
+        assert_eq!(trace_map.get_number_of_prefix_cycles(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Reset normalization does not support justice properties or fairness constraints."
+    )]
+    fn normalize_resets_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        aig.normalize_resets();
+    }
+
+    #[test]
+    fn normalize_resets_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            let aig = AndInverterGraph::from_aig_path(&aig_path);
+            let (normalized, trace_map) = aig.normalize_resets();
+            assert_resets_are_zero(&normalized);
+            if !aig.get_bad_information().is_empty() {
+                if let Some((witness, mapped)) =
+                    common::_assert_same_verdict(&aig, &normalized, &trace_map)
+                {
+                    assert_eq!(mapped.get_inputs().len(), witness.get_inputs().len());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn normalize_resets_hwmcc20() {
+        let probability_of_testing_each_file = 0.02;
+        for (aig_path, _) in common::_get_paths_to_all_aig_and_corresponding_aag_files() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                println!("file_path = {}", aig_path);
+                let aig = AndInverterGraph::from_aig_path(&aig_path);
+                let (normalized, trace_map) = aig.normalize_resets();
+                assert_resets_are_zero(&normalized);
+                if let Some(witness) = common::_bmc_witness(&normalized, 3, 10) {
+                    let mapped =
+                        AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+                    assert!(mapped.check(&aig));
+                }
+            }
+        }
+    }
+}
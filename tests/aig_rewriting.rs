@@ -0,0 +1,151 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::models::AndInverterGraph;
+    use std::collections::HashMap;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// the largest number of and gates on a path from an input or a latch.
+    fn get_depth(aig: &AndInverterGraph) -> usize {
+        let all_literals: Vec<usize> = (0..=aig.get_highest_variable_number())
+            .map(|v| v << 1)
+            .collect();
+        let mut ands = aig.get_and_information_in_cone_of_influence(&all_literals);
+        ands.sort();
+        let mut depths: HashMap<usize, usize> = HashMap::new();
+        for (lhs, rhs0, rhs1) in ands {
+            let depth_of_rhs0 = depths.get(&(rhs0 >> 1)).copied().unwrap_or(0);
+            let depth_of_rhs1 = depths.get(&(rhs1 >> 1)).copied().unwrap_or(0);
+            depths.insert(lhs >> 1, depth_of_rhs0.max(depth_of_rhs1) + 1);
+        }
+        depths.values().max().copied().unwrap_or(0)
+    }
+
+    fn rewrite_and_balance_test(aig_path: &str, maximum_number_of_ands: usize) {
+        let aig = AndInverterGraph::from_aig_path(aig_path);
+        if common::_get_number_of_ands(&aig) > maximum_number_of_ands {
+            return;
+        }
+        let rewritten = aig.rewrite();
+        let balanced = aig.balance();
+        println!(
+            "{aig_path} has {} and gates and depth {}, after rewriting {} and after balancing {}",
+            common::_get_number_of_ands(&aig),
+            get_depth(&aig),
+            common::_get_number_of_ands(&rewritten),
+            get_depth(&balanced)
+        );
+        assert!(common::_get_number_of_ands(&rewritten) <= common::_get_number_of_ands(&aig));
+        assert!(get_depth(&balanced) <= get_depth(&aig));
+        for result in [&rewritten, &balanced] {
+            assert_eq!(
+                result.get_latch_information().len(),
+                aig.get_latch_information().len()
+            );
+            common::_assert_same_simulation(&aig, result);
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn rewrite_reduces_and_gates() {
+        // (x & y) | (x & z) with three and gates, which is x & (y | z) with two.
+        let aag = "aag 6 3 0 1 3\n2\n4\n6\n13\n8 4 2\n10 6 2\n12 11 9\ni0 x\ni1 y\ni2 z\no0 f\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let rewritten = aig.rewrite();
+        assert_eq!(common::_get_number_of_ands(&rewritten), 2);
+        assert!(rewritten
+            .get_aag_string()
+            .ends_with("i0 x\ni1 y\ni2 z\no0 f\n"));
+        common::_assert_same_simulation(&aig, &rewritten);
+    }
+
+    #[test]
+    fn rewrite_keeps_shared_and_gates() {
+        // x & y is used by an output and by the latch, so nothing can be saved.
+        let aag = "aag 4 2 1 2 1\n2\n4\n6 8\n8\n6\n8 4 2\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        assert_eq!(aig.rewrite().get_aag_string(), aag);
+    }
+
+    #[test]
+    fn balance_reduces_depth() {
+        // a chain of 8 inputs and-ed one after the other has depth 7, a tree has depth 3.
+        let mut aag = String::from("aag 15 8 0 1 7\n2\n4\n6\n8\n10\n12\n14\n16\n30\n18 4 2\n");
+        for i in 0..6 {
+            aag.push_str(&format!("{} {} {}\n", 20 + 2 * i, 18 + 2 * i, 6 + 2 * i));
+        }
+        let aig = AndInverterGraph::from_aag_str(&aag);
+        assert_eq!(get_depth(&aig), 7);
+        let balanced = aig.balance();
+        assert_eq!(get_depth(&balanced), 3);
+        assert_eq!(common::_get_number_of_ands(&balanced), 7);
+        common::_assert_same_simulation(&aig, &balanced);
+    }
+
+    #[test]
+    fn balance_keeps_shared_and_gates() {
+        // x & y is used twice, so it stays a separate and gate.
+        let aag = "aag 5 3 0 2 2\n2\n4\n6\n8\n10\n8 4 2\n10 8 6\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        assert_eq!(aig.balance().get_aag_string(), aag);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Rewriting does not support justice properties or fairness constraints."
+    )]
+    fn rewrite_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        aig.rewrite();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Balancing does not support justice properties or fairness constraints."
+    )]
+    fn balance_with_fairness_constraint() {
+        // a justice property with a fairness constraint that the input is 1 infinitely often.
+        let aig = AndInverterGraph::from_aag_str("aag 1 1 0 0 0 0 0 1 1\n2\n1\n2\n2\n");
+        aig.balance();
+    }
+
+    #[test]
+    fn rewrite_and_balance_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            rewrite_and_balance_test(&aig_path, usize::MAX);
+        }
+    }
+
+    #[test]
+    fn rewrite_and_balance_hwmcc20() {
+        let probability_of_testing_each_file = 0.02;
+        for (aig_path, _) in common::_get_paths_to_all_aig_and_corresponding_aag_files() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                // cuts are enumerated for every and gate, so big graphs take too long.
+                rewrite_and_balance_test(&aig_path, 20000);
+            }
+        }
+    }
+}
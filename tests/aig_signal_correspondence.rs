@@ -0,0 +1,119 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{
+        models::AndInverterGraph, solvers::sat::stateful::CaDiCalSolver,
+    };
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn signal_correspondence_test(aig_path: &str, maximum_number_of_latches: usize) {
+        let aig = AndInverterGraph::from_aig_path(aig_path);
+        if aig.get_latch_information().len() > maximum_number_of_latches {
+            return;
+        }
+        let reduced = aig.signal_correspondence::<CaDiCalSolver>(1);
+        println!(
+            "{aig_path} has {} latches, after signal correspondence {}",
+            aig.get_latch_information().len(),
+            reduced.get_latch_information().len()
+        );
+        assert!(reduced.get_latch_information().len() <= aig.get_latch_information().len());
+        // simulation needs the initial values of all latches.
+        if aig
+            .get_latch_information()
+            .iter()
+            .all(|(_, _, reset)| reset <= &1)
+        {
+            common::_assert_same_simulation_from_initial_states(&aig, &reduced);
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn signal_correspondence_merges_equal_latches() {
+        // two latches that store the same input, and a bad state literal when they differ.
+        let aag = "aag 6 1 2 0 3 1\n2\n4 2\n6 2\n13\n8 6 5\n10 7 4\n12 11 9\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let reduced = aig.signal_correspondence::<CaDiCalSolver>(1);
+        assert_eq!(reduced.get_aag_string(), "aag 2 1 1 0 0 1\n2\n4 2\n0\n");
+    }
+
+    #[test]
+    fn signal_correspondence_merges_opposite_latches() {
+        // a latch that stores the input and one that stores its negation, with opposite
+        // initial values, so they are never 1 together.
+        let aag = "aag 4 1 2 0 1 1\n2\n4 2\n6 3 1\n8\n8 6 4\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let reduced = aig.signal_correspondence::<CaDiCalSolver>(1);
+        assert_eq!(reduced.get_aag_string(), "aag 2 1 1 0 0 1\n2\n4 2\n0\n");
+    }
+
+    #[test]
+    fn signal_correspondence_finds_constant_latches() {
+        // a latch that starts at 0 and is and-ed with the input, so it stays 0.
+        let aag = "aag 3 1 1 0 1 1\n2\n4 6\n4\n6 4 2\ni0 x\nb0 never\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let reduced = aig.signal_correspondence::<CaDiCalSolver>(1);
+        assert_eq!(
+            reduced.get_aag_string(),
+            "aag 1 1 0 0 0 1\n2\n0\ni0 x\nb0 never\n"
+        );
+    }
+
+    #[test]
+    fn signal_correspondence_keeps_different_latches() {
+        // a latch that toggles and a latch that stores the input, both are outputs.
+        let aag = "aag 3 1 2 2 0\n2\n4 5\n6 2\n4\n6\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let reduced = aig.signal_correspondence::<CaDiCalSolver>(2);
+        assert_eq!(reduced.get_aag_string(), aag);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Signal correspondence does not support justice properties or fairness constraints."
+    )]
+    fn signal_correspondence_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        aig.signal_correspondence::<CaDiCalSolver>(1);
+    }
+
+    #[test]
+    fn signal_correspondence_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            signal_correspondence_test(&aig_path, usize::MAX);
+        }
+    }
+
+    #[test]
+    fn signal_correspondence_hwmcc20() {
+        let probability_of_testing_each_file = 0.02;
+        for (aig_path, _) in common::_get_paths_to_all_aig_and_corresponding_aag_files() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                // every refinement needs a sat call, so graphs with many latches take too long.
+                signal_correspondence_test(&aig_path, 1000);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use rust_formal_verification::models::{
+        and_inverter_graph::{AigBuilder, AigSimulationError},
+        AndInverterGraph,
+    };
+    use std::collections::HashMap;
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn simulation_result_of_counter_with_2_bad_assertions() {
+        let aig = AndInverterGraph::from_aig_path(
+            "tests/examples/ours/counter_with_2_bad_assertions.aig",
+        );
+        let result = aig
+            .try_simulate(&vec![HashMap::new(); 4], &HashMap::new())
+            .unwrap();
+        assert_eq!(result.get_number_of_cycles(), 4);
+        assert_eq!(result.get_first_bad_cycle(), Some(2));
+        assert!(!result.get_bad_value(2, 1));
+        assert!(result.get_bad_value(2, 0));
+        assert!(result.get_bad_value(3, 1));
+        // all latches start at 0, after that exactly one latch of the counter is 1.
+        for cycle in 0..4 {
+            let latches_that_are_1 = (0..3).filter(|i| result.get_latch_value(cycle, *i)).count();
+            assert_eq!(latches_that_are_1, if cycle == 0 { 0 } else { 1 });
+        }
+        for cycle in 0..3 {
+            assert_eq!(
+                result.get_output_value(cycle, 0),
+                result.get_latch_value(cycle + 1, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn simulation_result_by_symbol() {
+        let mut builder = AigBuilder::new();
+        let request = builder.add_input();
+        builder.set_input_symbol(request, "request");
+        let busy = builder.add_latch(request, None);
+        builder.set_latch_symbol(busy, "busy");
+        let collision = builder.add_and(request, busy);
+        builder.add_output(busy);
+        builder.set_output_symbol(0, "busy out");
+        builder.add_bad(collision);
+        builder.set_bad_symbol(0, "collision");
+        builder.add_constraint(request ^ 1);
+        builder.set_constraint_symbol(0, "no request");
+        let aig = builder.build();
+
+        let inputs: Vec<HashMap<usize, bool>> = [true, false, true]
+            .iter()
+            .map(|value| HashMap::from([(request >> 1, *value)]))
+            .collect();
+        let result = aig
+            .try_simulate(&inputs, &HashMap::from([(busy >> 1, false)]))
+            .unwrap();
+        assert_eq!(result.get_first_bad_cycle(), None);
+        assert_eq!(result.get_latch_value_by_symbol(1, "busy"), Some(true));
+        assert_eq!(
+            result.get_output_value_by_symbol(2, "busy out"),
+            Some(false)
+        );
+        assert_eq!(
+            result.get_constraint_value_by_symbol(1, "no request"),
+            Some(true)
+        );
+        assert_eq!(
+            result.get_constraint_value_by_symbol(2, "no request"),
+            Some(false)
+        );
+        assert_eq!(result.get_bad_value_by_symbol(0, "collision"), Some(false));
+        assert_eq!(result.get_input_value_by_symbol(0, "busy"), None);
+        assert_eq!(
+            result.get_states(),
+            &aig.simulate(&inputs, &HashMap::from([(2, false)]))
+        );
+    }
+
+    #[test]
+    fn simulation_errors() {
+        let aag = "aag 3 1 1 0 1 1\n2\n4 6 4\n6\n6 4 2\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let inputs = vec![HashMap::from([(1, true)])];
+        let latches = HashMap::from([(2, true)]);
+        assert!(aig.try_simulate(&inputs, &latches).is_ok());
+
+        assert_eq!(
+            aig.try_simulate(&[], &latches).err(),
+            Some(AigSimulationError::EmptyInputs)
+        );
+        let too_many_inputs = vec![
+            HashMap::from([(1, true)]),
+            HashMap::from([(1, true), (3, true)]),
+        ];
+        assert_eq!(
+            aig.try_simulate(&too_many_inputs, &latches).err(),
+            Some(AigSimulationError::WrongInputVariables { cycle: 1 })
+        );
+        assert_eq!(
+            aig.try_simulate(&inputs, &HashMap::new()).err(),
+            Some(AigSimulationError::MissingInitialLatch { variable: 2 })
+        );
+        assert_eq!(
+            aig.try_simulate(&inputs, &HashMap::from([(2, true), (3, true)]))
+                .err(),
+            Some(AigSimulationError::NotALatch { variable: 3 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Initial value of latch 2 is unknown, it must be provided.")]
+    fn simulate_panics_on_missing_initial_latch() {
+        let aig = AndInverterGraph::from_aag_str("aag 2 1 1 0 0\n2\n4 2 4\n");
+        aig.simulate(&[HashMap::from([(1, true)])], &HashMap::new());
+    }
+}
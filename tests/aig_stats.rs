@@ -0,0 +1,112 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::models::AndInverterGraph;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn stats_test(aig_path: &str) {
+        let aig = AndInverterGraph::from_aig_path(aig_path);
+        let stats = aig.get_stats();
+        println!("{aig_path}:\n{stats}");
+        let [maximum_variable_index, inputs, latches, outputs, ands, bad, constraints, justice, fairness] =
+            stats.get_header_counts();
+        assert_eq!(maximum_variable_index, aig.get_highest_variable_number());
+        assert_eq!(inputs, aig.get_input_information().len());
+        assert_eq!(latches, aig.get_latch_information().len());
+        assert_eq!(outputs, aig.get_output_information().len());
+        assert_eq!(maximum_variable_index, inputs + latches + ands);
+        assert_eq!(bad, aig.get_bad_information().len());
+        assert_eq!(constraints, aig.get_constraints_information().len());
+        assert_eq!(justice, aig.get_justice_information().len());
+        assert_eq!(fairness, aig.get_fairness_information().len());
+
+        assert_eq!(stats.get_latch_scc_sizes().iter().sum::<usize>(), latches);
+        assert!(stats.get_number_of_cyclic_latch_sccs() <= stats.get_latch_scc_sizes().len());
+        assert_eq!(stats.get_latches_in_cone_of_influence().len(), bad);
+        for (index, latches_in_cone) in stats.get_latches_in_cone_of_influence().iter().enumerate()
+        {
+            // the reduced graph also keeps the latches of the constraints.
+            let reduced = aig.coi_reduce(&[index]);
+            assert!(latches_in_cone <= &reduced.get_latch_information().len());
+        }
+        assert!(stats.get_number_of_unused_nodes() <= maximum_variable_index);
+        assert!(stats.get_number_of_uninitialized_latches() <= latches);
+        assert!(stats.get_average_fanout() <= stats.get_maximum_fanout() as f64);
+
+        let json = stats.get_json_string();
+        assert!(json.starts_with("{\n") && json.ends_with("\n}\n"));
+        assert_eq!(json.lines().count(), stats.to_string().lines().count() + 2);
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn stats_of_latch_cycles() {
+        // three latches in a ring, and a latch that reads the ring.
+        let aag = "aag 4 0 4 0 0 1\n2 6\n4 2\n6 4\n8 6\n8\n";
+        let stats = AndInverterGraph::from_aag_str(aag).get_stats();
+        assert_eq!(stats.get_latch_scc_sizes(), &[3, 1]);
+        assert_eq!(stats.get_number_of_cyclic_latch_sccs(), 1);
+        assert_eq!(stats.get_latches_in_cone_of_influence(), &[4]);
+        assert_eq!(stats.get_number_of_levels(), 0);
+        assert_eq!(stats.get_maximum_fanout(), 2);
+    }
+
+    #[test]
+    fn stats_of_constant_and_unused_nodes() {
+        // a latch stuck at 0, an and gate of an input and its negation, an and gate of the
+        // two, an unused input and an uninitialized latch that stores the first input.
+        let aag = "aag 6 2 2 1 2 1\n2\n4\n6 6\n8 2 8\n12\n13\n10 3 2\n12 10 6\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let stats = aig.get_stats();
+        assert_eq!(stats.get_number_of_constant_nodes(), 3);
+        // the unused input and the uninitialized latch.
+        assert_eq!(stats.get_number_of_unused_nodes(), 2);
+        assert_eq!(stats.get_number_of_uninitialized_latches(), 1);
+        assert_eq!(stats.get_number_of_levels(), 2);
+        assert_eq!(stats.get_latches_in_cone_of_influence(), &[1]);
+        assert!(stats
+            .to_string()
+            .contains("number of constant nodes: 3\nnumber of unused nodes: 2\n"));
+        assert!(stats
+            .get_json_string()
+            .contains("  \"number_of_uninitialized_latches\": 1\n"));
+    }
+
+    #[test]
+    fn stats_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            stats_test(&aig_path);
+        }
+    }
+
+    #[test]
+    fn stats_hwmcc20() {
+        let probability_of_testing_each_file = 0.02;
+        for (aig_path, _) in common::_get_paths_to_all_aig_and_corresponding_aag_files() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                stats_test(&aig_path);
+            }
+        }
+    }
+}
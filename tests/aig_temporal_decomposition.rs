@@ -0,0 +1,94 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{algorithms::AigerWitness, models::AndInverterGraph};
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn temporal_decomposition_keeps_verdict() {
+        for aag in [
+            common::_SHIFT,
+            common::_SHIFT_WITH_CONSTRAINT_ON_FIRST_LATCH,
+            common::_SHIFT_WITH_CONSTRAINT_ON_INPUT,
+            common::_BAD_INPUT_WITH_CONSTRAINT,
+            common::_UNINITIALIZED_LATCH,
+        ] {
+            let aig = AndInverterGraph::from_aag_str(aag);
+            for number_of_cycles in 0..4 {
+                let (decomposed, trace_map) = aig.temporal_decomposition(number_of_cycles);
+                assert_eq!(trace_map.get_number_of_prefix_cycles(), number_of_cycles);
+                assert_eq!(
+                    decomposed.get_latch_information().len(),
+                    aig.get_latch_information().len() + 1
+                );
+                common::_assert_same_verdict(&aig, &decomposed, &trace_map);
+            }
+        }
+    }
+
+    #[test]
+    fn temporal_decomposition_finds_bad_state_in_first_cycles() {
+        // the second latch is set after 2 cycles, so the counter example is in the first cycle.
+        let aig = AndInverterGraph::from_aag_str(common::_SHIFT_WITH_CONSTRAINT_ON_INPUT);
+        let (decomposed, trace_map) = aig.temporal_decomposition(3);
+        let witness = common::_bmc_witness(&decomposed, 5, 10).unwrap();
+        assert_eq!(witness.get_inputs().len(), 1);
+        let mapped = AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+        assert_eq!(mapped.get_inputs(), &[vec![true], vec![true], vec![true]]);
+        assert!(mapped.check(&aig));
+    }
+
+    #[test]
+    fn temporal_decomposition_maps_initial_values() {
+        let aig = AndInverterGraph::from_aag_str(common::_UNINITIALIZED_LATCH);
+        let (decomposed, trace_map) = aig.temporal_decomposition(1);
+        // the input of the first cycle and the initial value of the latch are new inputs.
+        assert_eq!(decomposed.get_input_information().len(), 3);
+        let witness = common::_bmc_witness(&decomposed, 5, 10).unwrap();
+        let mapped = AigerWitness::from_transformed_witness(&aig, &trace_map, &witness).unwrap();
+        assert_eq!(mapped.get_initial_latches(), &[Some(true)]);
+        assert_eq!(mapped.get_inputs().len(), 1);
+        assert!(mapped.check(&aig));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Temporal decomposition does not support justice properties or fairness constraints."
+    )]
+    fn temporal_decomposition_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        aig.temporal_decomposition(1);
+    }
+
+    #[test]
+    fn temporal_decomposition_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            let aig = AndInverterGraph::from_aig_path(&aig_path);
+            if aig.get_bad_information().is_empty() {
+                continue;
+            }
+            for number_of_cycles in 0..4 {
+                let (decomposed, trace_map) = aig.temporal_decomposition(number_of_cycles);
+                common::_assert_same_verdict(&aig, &decomposed, &trace_map);
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::models::{
+        and_inverter_graph::{AigBuilder, TernaryValue},
+        AndInverterGraph,
+    };
+    use std::collections::HashMap;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn check_ternary_simulation_covers_random_simulation(aig: &AndInverterGraph) {
+        let number_of_cycles = 4;
+        let result = aig.simulate_bit_parallel_randomly(number_of_cycles, 1);
+        let input_literals = aig.get_input_information();
+        let latches = aig.get_latch_information();
+
+        // inputs that are 1 in lane 0 are kept, the rest are X.
+        let inputs: Vec<HashMap<usize, TernaryValue>> = (0..number_of_cycles)
+            .map(|cycle| {
+                let mut map = HashMap::new();
+                for (index, literal) in input_literals.iter().enumerate() {
+                    if result.get_input_value(cycle, index, 0) {
+                        map.insert(literal >> 1, TernaryValue::One);
+                    }
+                }
+                map
+            })
+            .collect();
+        let states = aig.simulate_ternary(&inputs, &HashMap::new());
+
+        for cycle in 0..number_of_cycles {
+            for (index, literal) in aig.get_bad_information().iter().enumerate() {
+                let ternary = states[cycle][literal >> 1];
+                let ternary = if (literal & 1) == 1 {
+                    !ternary
+                } else {
+                    ternary
+                };
+                let binary = result.get_bad_value(cycle, index, 0);
+                assert!(ternary == TernaryValue::X || ternary == TernaryValue::from(binary));
+            }
+            if cycle == 0 {
+                for (index, (latch_literal, _, _)) in latches.iter().enumerate() {
+                    let ternary = states[0][latch_literal >> 1];
+                    let binary = result.get_initial_latch_value(index, 0);
+                    assert!(ternary == TernaryValue::X || ternary == TernaryValue::from(binary));
+                }
+            }
+        }
+    }
+
+    fn check_constant_latches_with_random_simulation(aig: &AndInverterGraph) {
+        if let Some(constant_latches) = aig.get_constant_latches(100) {
+            let number_of_cycles = 20;
+            let random_run = aig.simulate_bit_parallel_randomly(number_of_cycles, 1);
+            let input_literals = aig.get_input_information();
+            let latches = aig.get_latch_information();
+
+            // replay lane 0 of the random run and check the latches in every cycle.
+            let inputs: Vec<HashMap<usize, bool>> = (0..number_of_cycles)
+                .map(|cycle| {
+                    input_literals
+                        .iter()
+                        .enumerate()
+                        .map(|(i, l)| (l >> 1, random_run.get_input_value(cycle, i, 0)))
+                        .collect()
+                })
+                .collect();
+            let initial_latches: HashMap<usize, bool> = latches
+                .iter()
+                .enumerate()
+                .filter(|(_, (l, _, r))| r == l)
+                .map(|(i, (l, _, _))| (l >> 1, random_run.get_initial_latch_value(i, 0)))
+                .collect();
+            let result = aig.try_simulate(&inputs, &initial_latches).unwrap();
+            for (index, (latch_literal, _, _)) in latches.iter().enumerate() {
+                if let Some(value) = constant_latches.get(&(latch_literal >> 1)) {
+                    for cycle in 0..number_of_cycles {
+                        assert_eq!(result.get_latch_value(cycle, index), value.to_owned());
+                    }
+                }
+            }
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn ternary_values() {
+        let values = [TernaryValue::Zero, TernaryValue::One, TernaryValue::X];
+        for a in values {
+            assert_eq!(a & TernaryValue::Zero, TernaryValue::Zero);
+            assert_eq!(a & TernaryValue::One, a);
+            assert_eq!(!!a, a);
+        }
+        assert_eq!(TernaryValue::X & TernaryValue::X, TernaryValue::X);
+        assert_eq!(!TernaryValue::X, TernaryValue::X);
+        assert_eq!(TernaryValue::from(true), TernaryValue::One);
+    }
+
+    #[test]
+    fn constant_latches_of_built_circuit() {
+        let mut builder = AigBuilder::new();
+        let x = builder.add_input();
+        // stuck is 1 forever, toggle alternates, free is uninitialized and never changes.
+        let stuck = builder.add_latch(0, Some(true));
+        let toggle = builder.add_latch(0, Some(false));
+        let free = builder.add_latch(0, None);
+        let sticky = builder.add_latch(0, Some(false));
+        let stuck_or_x = builder.add_and(stuck ^ 1, x ^ 1) ^ 1;
+        builder.set_latch_next(stuck, stuck_or_x);
+        builder.set_latch_next(toggle, toggle ^ 1);
+        builder.set_latch_next(free, free);
+        let sticky_and_x = builder.add_and(sticky, x);
+        builder.set_latch_next(sticky, sticky_and_x);
+        let aig = builder.build();
+
+        let states = aig.get_ternary_latch_values_until_stable(10).unwrap();
+        assert_eq!(states.len(), 2);
+        assert_eq!(
+            states[1],
+            vec![
+                TernaryValue::One,
+                TernaryValue::One,
+                TernaryValue::X,
+                TernaryValue::Zero
+            ]
+        );
+        let constant_latches = aig.get_constant_latches(10).unwrap();
+        assert_eq!(
+            constant_latches,
+            HashMap::from([(stuck >> 1, true), (sticky >> 1, false)])
+        );
+    }
+
+    #[test]
+    fn ternary_simulation_on_hwmcc20() {
+        let run_test = true;
+        let probability_of_testing_each_file = 0.05;
+        if run_test {
+            let file_paths = common::_get_paths_to_all_aig_and_corresponding_aag_files();
+            for (aig_file_path, _) in file_paths {
+                if common::_true_with_probability(probability_of_testing_each_file) {
+                    println!("{}", aig_file_path);
+                    let aig = AndInverterGraph::from_aig_path(&aig_file_path);
+                    check_ternary_simulation_covers_random_simulation(&aig);
+                    check_constant_latches_with_random_simulation(&aig);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use rand::Rng;
+    use rust_formal_verification::{
+        algorithms::{bmc::BMCResult, BMC},
+        models::{
+            and_inverter_graph::AigParseError, AndInverterGraph, FiniteStateTransitionSystem,
+        },
+        solvers::sat::stateless::CaDiCalSolver,
+    };
+    use std::{collections::HashMap, time::Duration};
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    /// simulates a combinational graph on random inputs, and returns the inputs and outputs.
+    fn simulate_once(aig: &AndInverterGraph) -> (Vec<u64>, Vec<u64>) {
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<u64> = (0..aig.get_input_information().len())
+            .map(|_| rng.gen())
+            .collect();
+        let result = aig.simulate_bit_parallel(&[inputs.to_owned()], &HashMap::new(), 1);
+        let outputs = (0..aig.get_output_information().len())
+            .map(|index| result.get_output_words(0, index)[0])
+            .collect();
+        (inputs, outputs)
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn verilog_operators_and_gates() {
+        let verilog = "
+            module ops(a, b, c, o_and, o_or, o_xor, o_xnor, o_not, o_mux, o_prec, g_nand, g_nor, g_xnor, g_buf);
+                input a, b, c;
+                output o_and, o_or, o_xor, o_xnor, o_not, o_mux, o_prec;
+                output g_nand, g_nor, g_xnor, g_buf;
+                assign o_and = a & b, o_or = a || b;
+                assign o_xor = a ^ b;
+                assign o_xnor = a ~^ b;
+                assign o_not = !a;
+                assign o_mux = c ? a : b;
+                // & binds stronger than ^, which binds stronger than |.
+                assign o_prec = a | b ^ c & a;
+                nand g1 (g_nand, a, b, c);
+                nor (g_nor, a, b), (g_xnor_inverted, a, b);
+                xnor g3 (g_xnor, a, b);
+                buf g4 (g_buf, g_nor_of_inverted);
+                not g5 (g_nor_of_inverted, g_xnor_inverted);
+            endmodule
+        ";
+        let aig = AndInverterGraph::from_verilog_str(verilog, &[]);
+        assert_eq!(aig.get_input_information().len(), 3);
+        let (inputs, outputs) = simulate_once(&aig);
+        let (a, b, c) = (inputs[0], inputs[1], inputs[2]);
+        let expected = vec![
+            a & b,
+            a | b,
+            a ^ b,
+            !(a ^ b),
+            !a,
+            (c & a) | (!c & b),
+            a | (b ^ (c & a)),
+            !(a & b & c),
+            !(a | b),
+            !(a ^ b),
+            a | b,
+        ];
+        assert_eq!(outputs, expected);
+    }
+
+    #[test]
+    fn verilog_declarations_and_comments() {
+        // ANSI ports, vectors, escaped names, attributes, directives and out of order wires.
+        let verilog = "
+            `timescale 1ns / 1ps
+            (* top = 1 *)
+            module m(input [1:0] a, input \\b.c , output [1:0] y, output bad_0);
+                /* a block
+                   comment */
+                wire w = y[1];
+                assign y[1] = a[0] & \\b.c ;
+                assign y[0] = a[1];
+                reg q = 1'b1;
+                reg r;
+                initial begin
+                    r = 1'bx;
+                end
+                always @(negedge clock) begin
+                    q <= w;
+                    r <= q;
+                end
+                assign bad_0 = r;
+            endmodule
+            module ignored(); endmodule
+        ";
+        let aig = AndInverterGraph::from_verilog_str(verilog, &["bad_*"]);
+        assert_eq!(
+            aig.get_aag_string(),
+            "aag 6 3 2 2 1 1\n2\n4\n6\n8 12 1\n10 8 10\n4\n12\n10\n12 6 2\n\
+            i0 a[0]\ni1 a[1]\ni2 b.c\nl0 q\nl1 r\no0 y[0]\no1 y[1]\nb0 bad_0\nc\nwire w 12\n"
+        );
+
+        // only output ports become outputs, the names of the used wires are kept as comments.
+        let verilog = "module m(a, b, y); input a, b; output y;
+            wire n = a & b;
+            wire m = ~n;
+            assign y = n | a; endmodule";
+        let aig = AndInverterGraph::from_verilog_str(verilog, &[]);
+        assert_eq!(
+            aig.get_aag_string(),
+            "aag 4 2 0 1 2\n2\n4\n9\n6 4 2\n8 7 3\ni0 a\ni1 b\no0 y\nc\nwire n 6\n"
+        );
+    }
+
+    #[test]
+    fn verilog_bad_outputs_from_a_list() {
+        let verilog = "module m(a, p, q, r); input a; output p, q, r;
+            assign p = a; assign q = ~a; assign r = 1'b1; endmodule";
+        let aig = AndInverterGraph::from_verilog_str(verilog, &["r", "p"]);
+        assert_eq!(aig.get_output_information(), vec![3]);
+        assert_eq!(aig.get_bad_information(), vec![2, 1]);
+        let aig = AndInverterGraph::from_verilog_str(verilog, &["*"]);
+        assert_eq!(aig.get_bad_information().len(), 3);
+        let aig = AndInverterGraph::from_verilog_str(verilog, &["x*"]);
+        assert_eq!(aig.get_output_information().len(), 3);
+    }
+
+    #[test]
+    fn verilog_counter_with_synchronous_and_asynchronous_resets() {
+        // a 2 bit counter that is bad when it reaches 3, which takes 3 cycles after the reset.
+        let verilog = "
+            module counter(clk, rst_n, bad_three);
+                input clk, rst_n;
+                output bad_three;
+                reg [1:0] count;
+                always @(posedge clk or negedge rst_n) begin
+                    if (!rst_n) begin
+                        count[0] <= 1'b0;
+                        count[1] <= 1'b0;
+                    end else begin
+                        count[0] <= ~count[0];
+                        count[1] <= count[1] ^ count[0];
+                    end
+                end
+                assign bad_three = count[0] & count[1];
+            endmodule
+        ";
+        let aig = AndInverterGraph::from_verilog_str(verilog, &["bad_*"]);
+        assert_eq!(aig.get_input_information().len(), 1);
+        let latches = aig.get_latch_information();
+        assert_eq!(latches.len(), 2);
+        assert!(latches
+            .iter()
+            .all(|(latch, _, reset)| reset == &0 && latch > &2));
+        let fin_state = FiniteStateTransitionSystem::from_aig(&aig, false);
+        let res = BMC::<CaDiCalSolver>::new(false).search(&fin_state, 10, Duration::from_secs(10));
+        assert!(matches!(res, BMCResult::CTX { depth: 3, .. }));
+
+        // a synchronous reset is just logic, so the registers are uninitialized.
+        let synchronous = verilog.replace(" or negedge rst_n", "");
+        let aig = AndInverterGraph::from_verilog_str(&synchronous, &["bad_*"]);
+        assert!(aig
+            .get_latch_information()
+            .iter()
+            .all(|(latch, _, reset)| reset == latch));
+    }
+
+    #[test]
+    fn verilog_errors() {
+        let check = |verilog: &str, line: usize, reason: &str| {
+            assert_eq!(
+                AndInverterGraph::try_from_verilog_str(verilog, &[]).err(),
+                Some(AigParseError::InvalidLine {
+                    line,
+                    reason: reason.to_string()
+                })
+            );
+        };
+        check(
+            "module m(o);\noutput o;\nwire x;\nassign o = x;\nassign x = o;\nendmodule\n",
+            4,
+            "Signal o depends on itself.",
+        );
+        check(
+            "module m(a, o);\ninput a;\noutput o;\nassign o = a;\nassign o = ~a;\nendmodule\n",
+            5,
+            "Signal o is assigned more than once.",
+        );
+        check(
+            "module m(clk, o);\ninput clk;\noutput o;\nreg q;\nalways @(posedge clk) q <= q;\nassign o = clk;\nendmodule\n",
+            6,
+            "Clock clk cannot be used as a signal.",
+        );
+        check(
+            "module m(a, o);\ninput [1:0] a;\noutput o;\nassign o = a;\nendmodule\n",
+            4,
+            "Vector a should be used one bit at a time.",
+        );
+        check(
+            "module m(a, o);\ninput a;\noutput o;\nsub s(.x(a), .y(o));\nendmodule\n",
+            4,
+            "Unsupported item sub, module instances are not supported.",
+        );
+        check(
+            "module m(a, o);\ninput a;\noutput o;\nalways @(*) o = a;\nendmodule\n",
+            4,
+            "Only always blocks triggered by clock edges are supported.",
+        );
+        check(
+            "module m(a, o);\ninput a;\noutput o;\nassign o = a + 1'b1;\nendmodule\n",
+            4,
+            "Expected ;, found +.",
+        );
+        check(
+            "module m(a, o);\ninput a;\noutput o;\nassign o = 2'b10;\nendmodule\n",
+            4,
+            "Constant 2'b10 is not a single bit.",
+        );
+        check(
+            "module m(a, o);\ninput a;\noutput o;\nassign o = 4'b0001;\nendmodule\n",
+            4,
+            "Constant 4'b0001 is not a single bit.",
+        );
+        check(
+            "module m(clk, a);\ninput clk, a;\nreg q;\nalways @(posedge clk) q = a;\nendmodule\n",
+            4,
+            "Blocking assignment to q, registers should be assigned with <=.",
+        );
+        check(
+            "module m(clk, rst, a);\ninput clk, rst, a;\nreg q;\nalways @(posedge clk or posedge rst)\nif (!rst) q <= 1'b0;\nelse q <= a;\nendmodule\n",
+            5,
+            "Reset rst is triggered by posedge so it should be checked when it is 1.",
+        );
+        check(
+            "module m(clk, rst_n, a);\ninput clk, rst_n, a;\nreg q;\nalways @(posedge clk or negedge rst_n)\nif (rst_n) q <= 1'b0;\nelse q <= a;\nendmodule\n",
+            5,
+            "Reset rst_n is triggered by negedge so it should be checked when it is 0.",
+        );
+        check(
+            "module m(a);\ninput a;\nreg q = a;\nendmodule\n",
+            3,
+            "Initial value of q should be a constant.",
+        );
+        check("module m(a);\ninput a;\n", 2, "Unexpected end of file.");
+        check(
+            "module m(a);\n/* a comment\n",
+            2,
+            "Comment is never closed.",
+        );
+    }
+}
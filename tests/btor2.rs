@@ -0,0 +1,265 @@
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use rand::Rng;
+    use rust_formal_verification::{
+        algorithms::{AigerWitness, BMC},
+        models::{and_inverter_graph::AigParseError, Btor2Model, FiniteStateTransitionSystem},
+        solvers::sat::stateless::CaDiCalSolver,
+    };
+    use std::{collections::HashMap, time::Duration};
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    const WIDTH: usize = 8;
+
+    fn get_lane_value(words: &[u64], lane: usize) -> u64 {
+        words
+            .iter()
+            .enumerate()
+            .map(|(bit, word)| ((word >> lane) & 1) << bit)
+            .sum()
+    }
+
+    /// checks a binary operator on 8 bit words against the expected function on 64 random
+    /// pairs of arguments.
+    fn check_binary_operator(operator: &str, result_width: usize, expected: fn(u64, u64) -> u64) {
+        let btor2 = format!(
+            "1 sort bitvec {WIDTH}\n2 sort bitvec {result_width}\n3 input 1 a\n4 input 1 b\n5 {operator} 2 3 4\n6 output 5 result\n"
+        );
+        let model = Btor2Model::from_btor2_str(&btor2);
+        let aig = model.get_aig();
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<u64> = (0..(2 * WIDTH)).map(|_| rng.gen()).collect();
+        let result = aig.simulate_bit_parallel(&[inputs.to_owned()], &HashMap::new(), 1);
+        let mask = (1 << result_width) - 1;
+        for lane in 0..64 {
+            let a = get_lane_value(&inputs[..WIDTH], lane);
+            let b = get_lane_value(&inputs[WIDTH..], lane);
+            let outputs: Vec<u64> = (0..result_width)
+                .map(|index| result.get_output_words(0, index)[0])
+                .collect();
+            assert_eq!(
+                get_lane_value(&outputs, lane),
+                expected(a, b) & mask,
+                "{operator} {a} {b}"
+            );
+        }
+    }
+
+    fn to_signed(a: u64) -> i64 {
+        (a as u8 as i8).into()
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn btor2_arithmetic_and_logic() {
+        check_binary_operator("and", WIDTH, |a, b| a & b);
+        check_binary_operator("or", WIDTH, |a, b| a | b);
+        check_binary_operator("xor", WIDTH, |a, b| a ^ b);
+        check_binary_operator("nand", WIDTH, |a, b| !(a & b));
+        check_binary_operator("nor", WIDTH, |a, b| !(a | b));
+        check_binary_operator("xnor", WIDTH, |a, b| !(a ^ b));
+        check_binary_operator("implies", WIDTH, |a, b| !a | b);
+        check_binary_operator("add", WIDTH, |a, b| a.wrapping_add(b));
+        check_binary_operator("sub", WIDTH, |a, b| a.wrapping_sub(b));
+        check_binary_operator("mul", WIDTH, |a, b| a.wrapping_mul(b));
+        check_binary_operator("udiv", WIDTH, |a, b| a.checked_div(b).unwrap_or(u64::MAX));
+        check_binary_operator("urem", WIDTH, |a, b| a.checked_rem(b).unwrap_or(a));
+        check_binary_operator("sll", WIDTH, |a, b| a.checked_shl(b as u32).unwrap_or(0));
+        check_binary_operator("srl", WIDTH, |a, b| a.checked_shr(b as u32).unwrap_or(0));
+        check_binary_operator("sra", WIDTH, |a, b| {
+            (to_signed(a) >> b.min(WIDTH as u64 - 1)) as u64
+        });
+        check_binary_operator("concat", 2 * WIDTH, |a, b| (a << WIDTH) | b);
+    }
+
+    #[test]
+    fn btor2_comparisons() {
+        check_binary_operator("eq", 1, |a, b| (a == b).into());
+        check_binary_operator("neq", 1, |a, b| (a != b).into());
+        check_binary_operator("ult", 1, |a, b| (a < b).into());
+        check_binary_operator("ulte", 1, |a, b| (a <= b).into());
+        check_binary_operator("ugt", 1, |a, b| (a > b).into());
+        check_binary_operator("ugte", 1, |a, b| (a >= b).into());
+        check_binary_operator("slt", 1, |a, b| (to_signed(a) < to_signed(b)).into());
+        check_binary_operator("slte", 1, |a, b| (to_signed(a) <= to_signed(b)).into());
+        check_binary_operator("sgt", 1, |a, b| (to_signed(a) > to_signed(b)).into());
+        check_binary_operator("sgte", 1, |a, b| (to_signed(a) >= to_signed(b)).into());
+    }
+
+    #[test]
+    fn btor2_unary_operators_and_constants() {
+        let btor2 = "1 sort bitvec 8\n\
+            2 sort bitvec 1\n\
+            3 sort bitvec 4\n\
+            4 sort bitvec 12\n\
+            5 input 1 a\n\
+            6 neg 1 5\n\
+            7 output 6\n\
+            8 slice 3 5 6 3\n\
+            9 sext 4 8 8\n\
+            10 output 9\n\
+            11 redxor 2 5\n\
+            12 output 11\n\
+            13 constd 1 -3\n\
+            14 consth 1 a7\n\
+            15 const 1 00010010\n\
+            16 ite 1 11 13 -14\n\
+            17 output 16\n\
+            18 add 1 15 -5\n\
+            19 output 18\n";
+        let model = Btor2Model::from_btor2_str(btor2);
+        let aig = model.get_aig();
+        assert_eq!(aig.get_output_information().len(), 8 + 12 + 1 + 8 + 8);
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<u64> = (0..8).map(|_| rng.gen()).collect();
+        let result = aig.simulate_bit_parallel(&[inputs.to_owned()], &HashMap::new(), 1);
+        let outputs: Vec<u64> = (0..aig.get_output_information().len())
+            .map(|index| result.get_output_words(0, index)[0])
+            .collect();
+        for lane in 0..64 {
+            let a = get_lane_value(&inputs, lane);
+            let parity = u64::from(a.count_ones() % 2);
+            let expected_ite = if parity == 1 { 256 - 3 } else { !0xa7 & 0xff };
+            assert_eq!(
+                get_lane_value(&outputs[0..8], lane),
+                a.wrapping_neg() & 0xff
+            );
+            let slice = ((a >> 3) & 0xf) as u8;
+            let extended = (((slice << 4) as i8) >> 4) as i16 as u64 & 0xfff;
+            assert_eq!(get_lane_value(&outputs[8..20], lane), extended);
+            assert_eq!(get_lane_value(&outputs[20..21], lane), parity);
+            assert_eq!(get_lane_value(&outputs[21..29], lane), expected_ite);
+            assert_eq!(
+                get_lane_value(&outputs[29..37], lane),
+                0x12_u64.wrapping_add(!a) & 0xff
+            );
+        }
+    }
+
+    #[test]
+    fn btor2_counter_witness() {
+        // a 3 bit counter that starts at 1 and is bad when it reaches 5, enabled by an input.
+        let btor2 = "1 sort bitvec 3\n\
+            2 sort bitvec 1\n\
+            3 input 2 enable\n\
+            4 state 1 count\n\
+            5 one 1\n\
+            6 init 1 4 5\n\
+            7 add 1 4 5\n\
+            8 ite 1 3 7 4\n\
+            9 next 1 4 8\n\
+            10 constd 1 5\n\
+            11 eq 2 4 10\n\
+            12 bad 11 reached_five\n";
+        let model = Btor2Model::from_btor2_str(btor2);
+        let aig = model.get_aig();
+        assert_eq!(aig.get_latch_information().len(), 3);
+        let fin_state = FiniteStateTransitionSystem::from_aig(aig, false);
+        let res = BMC::<CaDiCalSolver>::new(false).search(&fin_state, 10, Duration::from_secs(10));
+        let witness = AigerWitness::from_bmc_result(aig, &fin_state, &res).unwrap();
+        assert!(witness.check(aig));
+        assert_eq!(witness.get_inputs().len(), 5);
+        let btor2_witness = witness.get_btor2_witness_string(&model);
+        let enabled_cycles: String = (0..4).map(|c| format!("@{c}\n0 1 enable\n")).collect();
+        let expected_start = format!("sat\nb0\n#0\n0 001 count\n{enabled_cycles}@4\n");
+        assert!(btor2_witness.starts_with(&expected_start));
+    }
+
+    #[test]
+    fn btor2_non_constant_initial_value() {
+        // b starts at the initial value of a, which is uninitialized, so they are always equal.
+        let btor2 = "1 sort bitvec 2\n\
+            2 sort bitvec 1\n\
+            3 state 1 a\n\
+            4 state 1 b\n\
+            5 init 1 4 3\n\
+            6 input 1 in\n\
+            7 next 1 3 6\n\
+            8 next 1 4 6\n\
+            9 neq 2 3 4\n\
+            10 bad 9\n";
+        let model = Btor2Model::from_btor2_str(btor2);
+        let aig = model.get_aig();
+        // the two latches that check the initial values come after the states.
+        assert_eq!(aig.get_latch_information().len(), 4 + 2);
+        let fin_state = FiniteStateTransitionSystem::from_aig(aig, false);
+        let res = BMC::<CaDiCalSolver>::new(false).search(&fin_state, 5, Duration::from_secs(10));
+        assert!(AigerWitness::from_bmc_result(aig, &fin_state, &res).is_none());
+
+        // without the initial value, a and b can differ in the first cycle.
+        let without_init = btor2.replace("5 init 1 4 3\n", "");
+        let model = Btor2Model::from_btor2_str(&without_init);
+        let aig = model.get_aig();
+        let fin_state = FiniteStateTransitionSystem::from_aig(aig, false);
+        let res = BMC::<CaDiCalSolver>::new(false).search(&fin_state, 5, Duration::from_secs(10));
+        let witness = AigerWitness::from_bmc_result(aig, &fin_state, &res).unwrap();
+        assert_eq!(witness.get_inputs().len(), 1);
+    }
+
+    #[test]
+    fn btor2_errors() {
+        let check = |btor2: &str, line: usize, reason: &str| {
+            assert_eq!(
+                Btor2Model::try_from_btor2_str(btor2).err(),
+                Some(AigParseError::InvalidLine {
+                    line,
+                    reason: reason.to_string()
+                })
+            );
+        };
+        check("1\n", 1, "Line is missing arguments.");
+        check("1 sort bitvec 2\n2\n", 2, "Line is missing arguments.");
+        check("1 sort array 2 3\n", 1, "Unsupported sort array.");
+        check("1 sort bitvec 2\n2 input 3\n", 2, "Sort 3 is not defined.");
+        check(
+            "1 sort bitvec 2\n2 input 1\n3 add 1 2\n",
+            3,
+            "add is missing arguments.",
+        );
+        check(
+            "1 sort bitvec 2\n2 sort bitvec 1\n3 input 1\n4 input 2\n5 and 1 3 4\n",
+            5,
+            "Arguments should have the same width.",
+        );
+        check(
+            "1 sort bitvec 2\n2 input 1\n3 bad 2\n",
+            3,
+            "Node 2 should have width 1.",
+        );
+        check(
+            "1 sort bitvec 2\n2 input 1\n3 next 1 2 2\n",
+            3,
+            "Node 2 is not a state.",
+        );
+        check(
+            "1 sort bitvec 2\n2 input 1\n3 sdiv 1 2 2\n",
+            3,
+            "Unsupported operator sdiv.",
+        );
+        check(
+            "1 sort bitvec 2\n2 sort bitvec 1\n3 input 1\n4 add 2 3 3\n",
+            4,
+            "Result has width 2 but its sort has width 1.",
+        );
+        check(
+            "1 sort bitvec 2\n2 const 1 102\n",
+            2,
+            "Invalid constant 102.",
+        );
+    }
+}
@@ -3,9 +3,37 @@
 // ********************************************************************************************
 
 use rand::Rng;
+use rust_formal_verification::{
+    algorithms::{
+        proof::{FiniteStateTransitionSystemProver, IC3Stateful, ProofResult},
+        AigerWitness, BMC,
+    },
+    models::{and_inverter_graph::AigTraceMap, AndInverterGraph, FiniteStateTransitionSystem},
+    solvers::sat::{stateful, stateless},
+};
 use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fs;
+use std::time::Duration;
 use walkdir::WalkDir;
 
+// ********************************************************************************************
+// small examples
+// ********************************************************************************************
+
+// two latches that shift an input, the second one is bad.
+pub const _SHIFT: &str = "aag 3 1 2 0 0 1\n2\n4 2\n6 4\n6\n";
+// the same, but the first latch must always be 0, so the input can never reach the second.
+pub const _SHIFT_WITH_CONSTRAINT_ON_FIRST_LATCH: &str = "aag 3 1 2 0 0 1 1\n2\n4 2\n6 4\n6\n5\n";
+// the same, but the input must always be 1, so the second latch is set after 2 cycles.
+pub const _SHIFT_WITH_CONSTRAINT_ON_INPUT: &str = "aag 3 1 2 0 0 1 1\n2\n4 2\n6 4\n6\n2\n";
+// the input is bad, but it must be 0 in every cycle including the last one.
+pub const _BAD_INPUT_WITH_CONSTRAINT: &str = "aag 1 1 0 0 0 1 1\n2\n2\n3\n";
+// a latch that stores the input, it is uninitialized and bad.
+pub const _UNINITIALIZED_LATCH: &str = "aag 2 1 1 0 0 1\n2\n4 2 4\n4\n";
+// an input that is bad, and a justice property that the input is 1 infinitely often.
+pub const _BAD_INPUT_WITH_JUSTICE: &str = "aag 1 1 0 0 0 1 0 1\n2\n2\n1\n2\n";
+
 // ********************************************************************************************
 // helper functions to helper functions
 // ********************************************************************************************
@@ -100,8 +128,174 @@ pub fn _get_paths_to_all_our_example_aig_files() -> Vec<String> {
         .collect()
 }
 
+pub fn _get_paths_to_hwmcc20_constrained() -> Vec<String> {
+    // the number of constraints is the eighth entry of the header, it is missing when there are none.
+    _get_paths_to_all_aig_and_corresponding_aag_files()
+        .iter()
+        .map(|t| t.to_owned().0)
+        .filter(|aig_file_path| {
+            let bytes = fs::read(aig_file_path).unwrap();
+            let header_end = bytes.iter().position(|b| *b == b'\n').unwrap();
+            let header = String::from_utf8_lossy(&bytes[..header_end]).to_string();
+            header
+                .split_whitespace()
+                .nth(7)
+                .is_some_and(|c| c.parse::<usize>().unwrap() > 0)
+        })
+        .collect()
+}
+
 pub fn _get_paths_to_hwmcc20_unconstrained() -> Vec<String> {
     let result = vec!["tests/examples/hwmcc20/2019/goel/crafted/paper_v3/paper_v3.aig", "tests/examples/hwmcc20/2019/goel/opensource/vcegar_QF_BV_itc99_b13_p10/vcegar_QF_BV_itc99_b13_p10.aig", "tests/examples/hwmcc20/2020/mann/simple_alu.aig", "tests/examples/hwmcc20/2019/goel/opensource/vis_arrays_bufferAlloc/vis_arrays_bufferAlloc.aig", "tests/examples/hwmcc20/2019/goel/opensource/vis_arrays_buf_bug/vis_arrays_buf_bug.aig", "tests/examples/hwmcc20/2019/goel/opensource/vis_arrays_am2910_p2/vis_arrays_am2910_p2.aig", "tests/examples/hwmcc20/2019/goel/opensource/miim/miim.aig", "tests/examples/hwmcc20/2019/goel/industry/cal21/cal21.aig", "tests/examples/hwmcc20/2019/goel/opensource/vis_arrays_am2901/vis_arrays_am2901.aig", "tests/examples/hwmcc20/2019/goel/opensource/vis_arrays_am2910_p1/vis_arrays_am2910_p1.aig", "tests/examples/hwmcc20/2019/goel/opensource/vis_arrays_am2910_p3/vis_arrays_am2910_p3.aig", "tests/examples/hwmcc20/2019/goel/opensource/h_TreeArb/h_TreeArb.aig", "tests/examples/hwmcc20/2019/beem/krebs.3.prop1-func-interl.aig", "tests/examples/hwmcc20/2019/goel/industry/cal41/cal41.aig", "tests/examples/hwmcc20/2019/beem/mcs.3.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/industry/cal4/cal4.aig", "tests/examples/hwmcc20/2019/beem/brp2.2.prop1-func-interl.aig", "tests/examples/hwmcc20/2019/beem/elevator.4.prop1-func-interl.aig", "tests/examples/hwmcc20/2019/beem/at.6.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/opensource/h_RCU/h_RCU.aig", "tests/examples/hwmcc20/2019/beem/anderson.3.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/industry/cal35/cal35.aig", "tests/examples/hwmcc20/2019/goel/industry/cal37/cal37.aig", "tests/examples/hwmcc20/2019/beem/brp2.3.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/beem/brp2.6.prop3-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/opensource/vcegar_arrays_itc99_b12_p2/vcegar_arrays_itc99_b12_p2.aig", "tests/examples/hwmcc20/2019/beem/msmie.3.prop1-func-interl.aig", "tests/examples/hwmcc20/2019/goel/industry/cal33/cal33.aig", "tests/examples/hwmcc20/2019/beem/blocks.4.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/industry/gen44/gen44.aig", "tests/examples/hwmcc20/2019/goel/industry/gen43/gen43.aig", "tests/examples/hwmcc20/2019/goel/industry/gen21/gen21.aig", "tests/examples/hwmcc20/2019/goel/industry/gen10/gen10.aig", "tests/examples/hwmcc20/2019/goel/industry/gen14/gen14.aig", "tests/examples/hwmcc20/2019/goel/industry/gen12/gen12.aig", "tests/examples/hwmcc20/2019/goel/industry/gen39/gen39.aig", "tests/examples/hwmcc20/2019/goel/industry/cal34/cal34.aig", "tests/examples/hwmcc20/2019/goel/industry/gen31/gen31.aig", "tests/examples/hwmcc20/2019/beem/frogs.5.prop1-func-interl.aig", "tests/examples/hwmcc20/2019/goel/industry/gen35/gen35.aig", "tests/examples/hwmcc20/2019/goel/industry/mul1/mul1.aig", "tests/examples/hwmcc20/2019/goel/industry/mul9/mul9.aig", "tests/examples/hwmcc20/2019/beem/pgm_protocol.3.prop5-func-interl.aig", "tests/examples/hwmcc20/2019/beem/peg_solitaire.3.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/industry/cal84/cal84.aig", "tests/examples/hwmcc20/2019/goel/industry/cal2/cal2.aig", "tests/examples/hwmcc20/2019/wolf/2018D/picorv32-pcregs-p2.aig", "tests/examples/hwmcc20/2019/wolf/2018D/picorv32-pcregs-p0.aig", "tests/examples/hwmcc20/2019/wolf/2018D/ponylink-slaveTXlen-unsat.aig", "tests/examples/hwmcc20/2019/goel/industry/cal87/cal87.aig", "tests/examples/hwmcc20/2019/goel/industry/cal90/cal90.aig", "tests/examples/hwmcc20/2019/goel/industry/cal86/cal86.aig", "tests/examples/hwmcc20/2019/goel/industry/cal149/cal149.aig", "tests/examples/hwmcc20/2019/goel/industry/cal142/cal142.aig", "tests/examples/hwmcc20/2019/goel/industry/cal118/cal118.aig", "tests/examples/hwmcc20/2019/goel/industry/cal140/cal140.aig", "tests/examples/hwmcc20/2019/goel/industry/cal117/cal117.aig", "tests/examples/hwmcc20/2019/goel/industry/cal99/cal99.aig", "tests/examples/hwmcc20/2019/goel/industry/cal129/cal129.aig", "tests/examples/hwmcc20/2019/goel/industry/cal123/cal123.aig", "tests/examples/hwmcc20/2019/goel/industry/cal122/cal122.aig", "tests/examples/hwmcc20/2019/goel/industry/cal143/cal143.aig", "tests/examples/hwmcc20/2019/goel/industry/cal125/cal125.aig", "tests/examples/hwmcc20/2019/goel/industry/cal97/cal97.aig", "tests/examples/hwmcc20/2019/goel/industry/cal119/cal119.aig", "tests/examples/hwmcc20/2019/goel/industry/cal106/cal106.aig", "tests/examples/hwmcc20/2019/goel/industry/cal107/cal107.aig", "tests/examples/hwmcc20/2019/goel/industry/cal112/cal112.aig", "tests/examples/hwmcc20/2019/goel/industry/cal102/cal102.aig", "tests/examples/hwmcc20/2019/beem/pgm_protocol.7.prop1-back-serstep.aig", "tests/examples/hwmcc20/2019/goel/opensource/vcegar_QF_BV_ar/vcegar_QF_BV_ar.aig", "tests/examples/hwmcc20/2020/mann/stack-p2.aig", "tests/examples/hwmcc20/2020/mann/stack-p1.aig", "tests/examples/hwmcc20/2019/goel/industry/cal159/cal159.aig", "tests/examples/hwmcc20/2019/goel/industry/cal162/cal162.aig", "tests/examples/hwmcc20/2019/goel/industry/cal161/cal161.aig", "tests/examples/hwmcc20/2019/beem/rushhour.4.prop1-func-interl.aig", "tests/examples/hwmcc20/2020/mann/rast-p21.aig", "tests/examples/hwmcc20/2020/mann/rast-p19.aig", "tests/examples/hwmcc20/2020/mann/rast-p18.aig", "tests/examples/hwmcc20/2020/mann/rast-p17.aig", "tests/examples/hwmcc20/2020/mann/rast-p16.aig", "tests/examples/hwmcc20/2020/mann/rast-p14.aig", "tests/examples/hwmcc20/2020/mann/rast-p11.aig", "tests/examples/hwmcc20/2020/mann/rast-p06.aig", "tests/examples/hwmcc20/2020/mann/rast-p04.aig", "tests/examples/hwmcc20/2020/mann/rast-p03.aig", "tests/examples/hwmcc20/2020/mann/rast-p01.aig", "tests/examples/hwmcc20/2020/mann/rast-p00.aig", "tests/examples/hwmcc20/2019/goel/industry/mul2/mul2.aig", "tests/examples/hwmcc20/2019/goel/industry/cal81/cal81.aig", "tests/examples/hwmcc20/2019/goel/industry/cal209/cal209.aig", "tests/examples/hwmcc20/2019/goel/industry/cal156/cal156.aig", "tests/examples/hwmcc20/2019/goel/industry/cal210/cal210.aig", "tests/examples/hwmcc20/2019/goel/industry/cal192/cal192.aig", "tests/examples/hwmcc20/2019/goel/industry/cal206/cal206.aig", "tests/examples/hwmcc20/2019/goel/industry/cal201/cal201.aig", "tests/examples/hwmcc20/2019/goel/industry/cal176/cal176.aig", "tests/examples/hwmcc20/2019/goel/industry/cal227/cal227.aig", "tests/examples/hwmcc20/2019/goel/industry/cal220/cal220.aig", "tests/examples/hwmcc20/2019/goel/industry/cal234/cal234.aig", "tests/examples/hwmcc20/2019/goel/industry/cal224/cal224.aig", "tests/examples/hwmcc20/2019/goel/industry/mul3/mul3.aig", "tests/examples/hwmcc20/2019/goel/industry/mul7/mul7.aig"];
     assert_eq!(result.len(), 104); // However, not all bit-blasted AIGER models had constraints (actually 104 did not).
     result.iter().map(|s| s.to_string()).collect()
 }
+
+pub fn _get_number_of_ands(aig: &AndInverterGraph) -> usize {
+    aig.get_highest_variable_number()
+        - aig.get_input_information().len()
+        - aig.get_latch_information().len()
+}
+
+/// checks that both graphs have the same outputs, bad state literals and constraints on
+/// the same random inputs and initial latches.
+pub fn _assert_same_simulation(a: &AndInverterGraph, b: &AndInverterGraph) {
+    let number_of_cycles = 5;
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<Vec<u64>> = (0..number_of_cycles)
+        .map(|_| {
+            (0..a.get_input_information().len())
+                .map(|_| rng.gen())
+                .collect()
+        })
+        .collect();
+    let get_initial_latches = |aig: &AndInverterGraph, words: &[u64]| {
+        aig.get_latch_information()
+            .iter()
+            .zip(words.iter())
+            .map(|((latch, _, _), word)| (latch >> 1, vec![word.to_owned()]))
+            .collect::<HashMap<usize, Vec<u64>>>()
+    };
+    let latch_words: Vec<u64> = a
+        .get_latch_information()
+        .iter()
+        .map(|(latch, _, reset)| match reset {
+            0 => 0,
+            1 => u64::MAX,
+            _ => {
+                assert_eq!(latch, reset);
+                rng.gen()
+            }
+        })
+        .collect();
+    let a_result = a.simulate_bit_parallel(&inputs, &get_initial_latches(a, &latch_words), 1);
+    let b_result = b.simulate_bit_parallel(&inputs, &get_initial_latches(b, &latch_words), 1);
+    for cycle in 0..number_of_cycles {
+        for index in 0..a.get_output_information().len() {
+            assert_eq!(
+                a_result.get_output_words(cycle, index),
+                b_result.get_output_words(cycle, index)
+            );
+        }
+        for index in 0..a.get_bad_information().len() {
+            assert_eq!(
+                a_result.get_bad_words(cycle, index),
+                b_result.get_bad_words(cycle, index)
+            );
+        }
+        for index in 0..a.get_constraints_information().len() {
+            assert_eq!(
+                a_result.get_constraint_words(cycle, index),
+                b_result.get_constraint_words(cycle, index)
+            );
+        }
+    }
+}
+
+/// checks that both graphs have the same outputs and bad state literals on the same random
+/// inputs from the initial states, as long as the constraints hold.
+pub fn _assert_same_simulation_from_initial_states(a: &AndInverterGraph, b: &AndInverterGraph) {
+    let number_of_cycles = 10;
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<Vec<u64>> = (0..number_of_cycles)
+        .map(|_| {
+            (0..a.get_input_information().len())
+                .map(|_| rng.gen())
+                .collect()
+        })
+        .collect();
+    let a_result = a.simulate_bit_parallel(&inputs, &HashMap::new(), 1);
+    let b_result = b.simulate_bit_parallel(&inputs, &HashMap::new(), 1);
+    let mut are_constraints_met = u64::MAX;
+    for cycle in 0..number_of_cycles {
+        for index in 0..a.get_constraints_information().len() {
+            are_constraints_met &= a_result.get_constraint_words(cycle, index)[0];
+        }
+        for index in 0..a.get_output_information().len() {
+            let a_words = a_result.get_output_words(cycle, index)[0];
+            let b_words = b_result.get_output_words(cycle, index)[0];
+            assert_eq!((a_words ^ b_words) & are_constraints_met, 0);
+        }
+        for index in 0..a.get_bad_information().len() {
+            let a_words = a_result.get_bad_words(cycle, index)[0];
+            let b_words = b_result.get_bad_words(cycle, index)[0];
+            assert_eq!((a_words ^ b_words) & are_constraints_met, 0);
+        }
+    }
+}
+
+/// proves the system with a prover of type `T`, and checks the invariant of a proof.
+pub fn _is_proved<T: FiniteStateTransitionSystemProver>(
+    fin_state: &FiniteStateTransitionSystem,
+) -> bool {
+    match T::new(fin_state).prove() {
+        ProofResult::Proof { invariant } => {
+            fin_state.check_invariant::<stateless::CaDiCalSolver>(&invariant);
+            true
+        }
+        ProofResult::CTX { .. } => false,
+    }
+}
+
+pub fn _bmc_witness(
+    aig: &AndInverterGraph,
+    search_depth_limit: u32,
+    timeout_in_seconds: u64,
+) -> Option<AigerWitness> {
+    let fin_state = FiniteStateTransitionSystem::from_aig(aig, false);
+    let res = BMC::<stateless::CaDiCalSolver>::new(false).search(
+        &fin_state,
+        search_depth_limit,
+        Duration::from_secs(timeout_in_seconds),
+    );
+    AigerWitness::from_bmc_result(aig, &fin_state, &res)
+}
+
+/// checks that the transformed graph is unsafe exactly when the graph is, and that its
+/// counter examples map back to counter examples of the graph. When it is unsafe, the
+/// witness of the graph and the mapped witness are returned.
+pub fn _assert_same_verdict(
+    aig: &AndInverterGraph,
+    transformed: &AndInverterGraph,
+    map: &AigTraceMap,
+) -> Option<(AigerWitness, AigerWitness)> {
+    let witness = _bmc_witness(aig, 20, 10);
+    let transformed_witness = _bmc_witness(transformed, 20, 10);
+    assert_eq!(witness.is_some(), transformed_witness.is_some());
+    match (witness, transformed_witness) {
+        (Some(witness), Some(transformed_witness)) => {
+            let mapped =
+                AigerWitness::from_transformed_witness(aig, map, &transformed_witness).unwrap();
+            assert!(mapped.check(aig));
+            assert!(mapped.get_inputs().len() >= witness.get_inputs().len());
+            Some((witness, mapped))
+        }
+        _ => {
+            let fin_state = FiniteStateTransitionSystem::from_aig(transformed, false);
+            assert!(_is_proved::<IC3Stateful<stateful::CaDiCalSolver>>(
+                &fin_state
+            ));
+            None
+        }
+    }
+}
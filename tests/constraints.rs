@@ -0,0 +1,96 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{
+        algorithms::{
+            proof::{IC3Stateful, IC3Stateless, PDR},
+            AigerWitness,
+        },
+        models::{AndInverterGraph, FiniteStateTransitionSystem},
+        solvers::sat::{stateful, stateless},
+    };
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn constraints_block_counter_examples() {
+        let unsafe_aig = AndInverterGraph::from_aag_str(common::_SHIFT);
+        assert!(common::_bmc_witness(&unsafe_aig, 5, 10).is_some());
+        let fin_state = FiniteStateTransitionSystem::from_aig(&unsafe_aig, false);
+        assert!(!common::_is_proved::<IC3Stateful<stateful::CaDiCalSolver>>(
+            &fin_state
+        ));
+
+        for aag in [
+            common::_SHIFT_WITH_CONSTRAINT_ON_FIRST_LATCH,
+            common::_BAD_INPUT_WITH_CONSTRAINT,
+        ] {
+            let aig = AndInverterGraph::from_aag_str(aag);
+            assert!(common::_bmc_witness(&aig, 5, 10).is_none());
+            let fin_state = FiniteStateTransitionSystem::from_aig(&aig, false);
+            assert!(common::_is_proved::<IC3Stateful<stateful::CaDiCalSolver>>(
+                &fin_state
+            ));
+            assert!(common::_is_proved::<IC3Stateless<stateless::CaDiCalSolver>>(&fin_state));
+            assert!(common::_is_proved::<PDR<stateful::CaDiCalSolver>>(
+                &fin_state
+            ));
+        }
+    }
+
+    #[test]
+    fn witness_respects_constraints() {
+        let aig = AndInverterGraph::from_aag_str(common::_SHIFT_WITH_CONSTRAINT_ON_INPUT);
+        let witness = common::_bmc_witness(&aig, 5, 10).unwrap();
+        assert_eq!(witness.get_inputs(), &[vec![true], vec![true], vec![true]]);
+        assert!(witness.check(&aig));
+
+        // the input may not be 0 even in the cycle the bad state is reached.
+        let violating = AigerWitness::try_from_cex_str("1\nb0\n00\n1\n1\n0\n.\n").unwrap();
+        assert!(!violating.check(&aig));
+    }
+
+    #[test]
+    #[ignore = "the bmc search takes minutes, run it with --ignored"]
+    fn bmc_on_hwmcc20_constrained_unsafe_problem() {
+        // the counter example has depth 16, and every cycle of it must satisfy the constraints.
+        let aig = AndInverterGraph::from_aig_path(
+            "tests/examples/hwmcc20/2019/mann/data-integrity/unsafe/shift_register_top_w16_d8_e0.aig",
+        );
+        assert!(!aig.get_constraints_information().is_empty());
+        let witness = common::_bmc_witness(&aig, 20, 600).unwrap();
+        assert_eq!(witness.get_inputs().len(), 17);
+        assert!(witness.check(&aig));
+    }
+
+    #[test]
+    fn fsts_on_hwmcc20_constrained_problems() {
+        let probability_of_testing_each_file = 0.05;
+        for aig_file_path in common::_get_paths_to_hwmcc20_constrained() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                println!("file_path = {}", aig_file_path);
+                let aig = AndInverterGraph::from_aig_path(&aig_file_path);
+                if let Some(witness) = common::_bmc_witness(&aig, 3, 10) {
+                    assert!(witness.check(&aig));
+                }
+            }
+        }
+    }
+}
@@ -16,9 +16,12 @@ mod tests {
     // ********************************************************************************************
 
     use rust_formal_verification::{
-        algorithms::formula_logic::is_a_and_b_satisfiable,
+        algorithms::{
+            formula_logic::is_a_and_b_satisfiable,
+            proof::{IC3Stateful, ProofResult, PDR},
+        },
         models::{AndInverterGraph, FiniteStateTransitionSystem},
-        solvers::sat::stateless::CaDiCalSolver,
+        solvers::sat::{stateful, stateless::CaDiCalSolver},
     };
     // use std::fs;
 
@@ -152,6 +155,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bad_latch_is_in_the_cone_of_safety() {
+        // the bad state literal is the second latch itself, so the second latch must stay in
+        // the transition relation even though no and gate reads it.
+        let aig = AndInverterGraph::from_aag_str("aag 2 0 2 0 0 1\n2 1\n4 2\n4\n");
+        let fsts = FiniteStateTransitionSystem::from_aig(&aig, false);
+        let mut pdr = PDR::<stateful::CaDiCalSolver>::new(&fsts, false);
+        assert!(matches!(pdr.prove(), ProofResult::CTX { .. }));
+        let mut ic3 = IC3Stateful::<stateful::CaDiCalSolver>::new(&fsts, false);
+        assert!(matches!(ic3.prove(), ProofResult::CTX { .. }));
+    }
+
+    #[test]
+    fn create_fsts_from_aig_with_justice_property_ignoring_liveness() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        let fsts = FiniteStateTransitionSystem::from_aig_ignoring_liveness(&aig, false);
+        assert_eq!(
+            fsts.get_unsafety_property().to_cnf().to_string(),
+            "p cnf 1 1\n1 0"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "AIG has justice properties or fairness constraints")]
+    fn create_fsts_from_aig_with_justice_property() {
+        let aig = AndInverterGraph::from_aag_str(common::_BAD_INPUT_WITH_JUSTICE);
+        FiniteStateTransitionSystem::from_aig(&aig, false);
+    }
+
     #[test]
     fn read_all_aig_files_from_hwmcc20() {
         let depth_to_test_for = 3;
@@ -164,20 +196,14 @@ mod tests {
                 println!("file_path = {}", aig_file_path);
                 let aig = AndInverterGraph::from_aig_path(&aig_file_path);
 
-                // from_aig only supports unconstrained problems since constraint ones can be folded into unconstrained ones.
-                if aig.get_constraints_information().is_empty() {
-                    let assume_output_is_bad = aig_file_path.contains("_fold");
-                    let fsts = FiniteStateTransitionSystem::from_aig(&aig, assume_output_is_bad);
-                    // check that p and not !p cannot hold at the same time for some depths.
-                    for depth in 0..depth_to_test_for {
-                        assert!(!is_a_and_b_satisfiable::<CaDiCalSolver>(
-                            &fsts.add_tags_to_relation(&fsts.get_safety_property().to_cnf(), depth),
-                            &fsts.add_tags_to_relation(
-                                &fsts.get_unsafety_property().to_cnf(),
-                                depth
-                            )
-                        ));
-                    }
+                let assume_output_is_bad = aig_file_path.contains("_fold");
+                let fsts = FiniteStateTransitionSystem::from_aig(&aig, assume_output_is_bad);
+                // check that p and not !p cannot hold at the same time for some depths.
+                for depth in 0..depth_to_test_for {
+                    assert!(!is_a_and_b_satisfiable::<CaDiCalSolver>(
+                        &fsts.add_tags_to_relation(&fsts.get_safety_property().to_cnf(), depth),
+                        &fsts.add_tags_to_relation(&fsts.get_unsafety_property().to_cnf(), depth)
+                    ));
                 }
             }
         }
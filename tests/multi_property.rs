@@ -0,0 +1,69 @@
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use rust_formal_verification::{
+        algorithms::proof::{IC3Stateful, MultiPropertyProver, ProofResult},
+        models::{AndInverterGraph, FiniteStateTransitionSystem},
+        solvers::sat::stateful::CaDiCalSolver,
+    };
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn fsts_for_selected_properties() {
+        let aig = AndInverterGraph::from_aig_path(
+            "tests/examples/ours/counter_with_2_bad_assertions.aig",
+        );
+        let all = FiniteStateTransitionSystem::from_aig(&aig, true);
+        assert_eq!(all.get_unsafety_property().len(), 3);
+        let first = FiniteStateTransitionSystem::from_aig_for_properties(&aig, true, &[0]);
+        let output = FiniteStateTransitionSystem::from_aig_for_properties(&aig, true, &[2]);
+        assert_eq!(first.get_unsafety_property().len(), 1);
+        assert_ne!(
+            first.get_unsafety_property().to_string(),
+            output.get_unsafety_property().to_string()
+        );
+        let none = FiniteStateTransitionSystem::from_aig_for_properties(&aig, true, &[]);
+        assert!(none.get_unsafety_property().is_empty());
+    }
+
+    #[test]
+    fn multi_property_results() {
+        // a latch that stays 0 and an input, the bad state literals are the latch, the input,
+        // the latch again, the constant 0 and the negated latch.
+        let aag = "aag 2 1 1 0 0 5\n2\n4 4\n4\n2\n4\n0\n5\n";
+        let aig = AndInverterGraph::from_aag_str(aag);
+        let results =
+            MultiPropertyProver::<IC3Stateful<CaDiCalSolver>>::new(false).prove(&aig, false);
+        assert_eq!(results.len(), 5);
+        for index in [0, 2, 3] {
+            assert!(matches!(results[&index], ProofResult::Proof { .. }));
+        }
+        for index in [1, 4] {
+            assert!(matches!(results[&index], ProofResult::CTX { depth: 0 }));
+        }
+    }
+
+    #[test]
+    fn multi_property_on_counter() {
+        // both latches of the counter are eventually set, so each property has its own CTX.
+        let aig = AndInverterGraph::from_aig_path(
+            "tests/examples/ours/counter_with_2_bad_assertions.aig",
+        );
+        let results =
+            MultiPropertyProver::<IC3Stateful<CaDiCalSolver>>::new(false).prove(&aig, false);
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[&0], ProofResult::CTX { .. }));
+        assert!(matches!(results[&1], ProofResult::CTX { .. }));
+    }
+}
@@ -0,0 +1,149 @@
+// ************************************************************************************************
+// mod declaration
+// ************************************************************************************************
+
+mod common;
+
+// ************************************************************************************************
+// test mod declaration
+// ************************************************************************************************
+
+#[cfg(test)]
+mod tests {
+
+    // ********************************************************************************************
+    // use
+    // ********************************************************************************************
+
+    use crate::common;
+    use rust_formal_verification::{
+        algorithms::{AigerWitness, BMC},
+        models::{
+            and_inverter_graph::AigParseError, AndInverterGraph, FiniteStateTransitionSystem,
+        },
+        solvers::sat::stateless::CaDiCalSolver,
+    };
+    use std::time::Duration;
+
+    // ********************************************************************************************
+    // helper functions
+    // ********************************************************************************************
+
+    fn witness_round_trip_test(aig_path: &str, search_depth_limit: u32, timeout_in_seconds: u64) {
+        let aig = AndInverterGraph::from_aig_path(aig_path);
+        let fin_state = FiniteStateTransitionSystem::from_aig(&aig, false);
+        let bmc = BMC::<CaDiCalSolver>::new(false);
+        let res = bmc.search(
+            &fin_state,
+            search_depth_limit,
+            Duration::from_secs(timeout_in_seconds),
+        );
+        if let Some(witness) = AigerWitness::from_bmc_result(&aig, &fin_state, &res) {
+            println!(
+                "{aig_path} has a witness of length {}",
+                witness.get_inputs().len()
+            );
+            assert!(!witness.get_property_indices().is_empty());
+            assert!(witness.check(&aig));
+            let parsed = AigerWitness::try_from_cex_str(&witness.to_string()).unwrap();
+            assert_eq!(parsed, witness);
+            assert!(parsed.check(&aig));
+        }
+    }
+
+    // ********************************************************************************************
+    // tests
+    // ********************************************************************************************
+
+    #[test]
+    fn witness_of_our_examples() {
+        for aig_path in common::_get_paths_to_all_our_example_aig_files() {
+            witness_round_trip_test(&aig_path, 10, 10);
+        }
+    }
+
+    #[test]
+    fn witness_with_two_properties() {
+        let aig = AndInverterGraph::from_aig_path(
+            "tests/examples/ours/counter_with_2_bad_assertions.aig",
+        );
+        let fin_state = FiniteStateTransitionSystem::from_aig(&aig, false);
+        let res = BMC::<CaDiCalSolver>::new(false).search(&fin_state, 10, Duration::from_secs(10));
+        let witness = AigerWitness::from_bmc_result(&aig, &fin_state, &res).unwrap();
+        assert_eq!(witness.get_property_indices(), &[0]);
+        assert_eq!(witness.get_initial_latches(), &[Some(false); 3]);
+
+        // the second property only fails one cycle later.
+        let longer = AigerWitness::try_from_cex_str(&format!(
+            "c comment\n1\nb1\n000\n{}\n.\n",
+            "\n".repeat(witness.get_inputs().len())
+        ))
+        .unwrap();
+        assert!(longer.check(&aig));
+        let wrong_property = AigerWitness::try_from_cex_str(&format!(
+            "1\nb1\n000\n{}.\n",
+            "\n".repeat(witness.get_inputs().len())
+        ))
+        .unwrap();
+        assert!(!wrong_property.check(&aig));
+        let wrong_initial_state = AigerWitness::try_from_cex_str(&format!(
+            "1\nb0\n100\n{}.\n",
+            "\n".repeat(witness.get_inputs().len())
+        ))
+        .unwrap();
+        assert!(!wrong_initial_state.check(&aig));
+        // the latches are initialized, so their initial values cannot be unknown.
+        let unknown_initial_state = AigerWitness::try_from_cex_str(&format!(
+            "1\nb0\nx00\n{}.\n",
+            "\n".repeat(witness.get_inputs().len())
+        ))
+        .unwrap();
+        assert!(!unknown_initial_state.check(&aig));
+    }
+
+    #[test]
+    fn witness_parse_errors() {
+        let check = |cex: &str, line: usize, reason: &str| {
+            assert_eq!(
+                AigerWitness::try_from_cex_str(cex).err(),
+                Some(AigParseError::InvalidLine {
+                    line,
+                    reason: reason.to_string()
+                })
+            );
+        };
+        check("", 1, "Witness is empty.");
+        check("1\nb0\n0\n", 4, "Witness does not end with '.'.");
+        check("1\n0\n0\n.\n", 2, "Property '0' should be b<index>.");
+        check("1\nb0\n2\n.\n", 3, "Unexpected character '2' in witness.");
+        check(
+            "c comment\n0\nb0\n.\n",
+            2,
+            "Witness status should be 1, found '0'.",
+        );
+        assert!(matches!(
+            AigerWitness::try_from_cex_path("tests/examples/ours/missing.cex"),
+            Err(AigParseError::UnreadableFile { .. })
+        ));
+    }
+
+    #[test]
+    fn witness_on_hwmcc20_unconstrained() {
+        let probability_of_testing_each_file = 0.05;
+        for aig_path in common::_get_paths_to_hwmcc20_unconstrained() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                witness_round_trip_test(&aig_path, 5, 5);
+            }
+        }
+    }
+
+    #[test]
+    fn witness_on_hwmcc20_constrained() {
+        let probability_of_testing_each_file = 0.02;
+        for aig_path in common::_get_paths_to_hwmcc20_constrained() {
+            if common::_true_with_probability(probability_of_testing_each_file) {
+                witness_round_trip_test(&aig_path, 5, 5);
+            }
+        }
+    }
+}